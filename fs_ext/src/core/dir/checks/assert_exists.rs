@@ -1,5 +1,5 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{fs, io, path::Path},
 };
 
@@ -9,7 +9,7 @@ pub fn assert_exists(path: impl AsRef<Path>) -> io::Result<()> {
 }
 
 fn _assert_exists(path: &Path) -> io::Result<()> {
-    let meta = fs::metadata(path).with_path_context("Failed to access", &path)?;
+    let meta = fs::metadata(path).with_path_context(Operation::Metadata, &path)?;
 
     if !meta.is_dir() {
         return Err(io::Error::new(
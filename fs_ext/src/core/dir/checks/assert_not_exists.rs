@@ -1,5 +1,5 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{fs, io, path::Path},
 };
 
@@ -9,7 +9,7 @@ pub fn assert_not_exists(path: impl AsRef<Path>) -> io::Result<()> {
 }
 
 fn _assert_not_exists(path: &Path) -> io::Result<()> {
-    match fs::metadata(path).with_path_context("Failed to get metadata", path) {
+    match fs::metadata(path).with_path_context(Operation::Metadata, path) {
         Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
         Ok(meta) => Err(io::Error::new(
             io::ErrorKind::AlreadyExists,
@@ -1,5 +1,5 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{fs, io, path::Path},
 };
 
@@ -12,7 +12,7 @@ fn _exists(path: &Path) -> io::Result<bool> {
     match fs::metadata(path) {
         Ok(meta) => Ok(meta.is_dir()),
         Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
-        Err(e) => Err(e).with_path_context("Failed to access", path),
+        Err(e) => Err(e).with_path_context(Operation::Metadata, path),
     }
 }
 
@@ -1,5 +1,5 @@
 use {
-    crate::dir,
+    crate::{FsError, IoResultExt, Operation, dir},
     std::{io, path::Path},
     walkdir::WalkDir,
 };
@@ -15,27 +15,14 @@ fn _size(path: &Path) -> io::Result<u128> {
     dir::assert_exists(path)?;
 
     for entry in WalkDir::new(path) {
-        let entry = entry.map_err(|e| {
-            let kind = e.io_error().map(|ioe| ioe.kind()).unwrap_or(io::ErrorKind::Other);
-            let msg = match e.path() {
-                Some(p) => format!("walk error at '{}': {}", p.display(), e),
-                None => format!("walk error: {}", e),
-            };
-            io::Error::new(kind, msg)
-        })?;
+        let entry = entry.map_err(|e| walk_error(e, path))?;
 
         if entry.file_type().is_file() {
-            let len = entry.metadata().map(|m| m.len()).map_err(|meta_err| {
-                io::Error::new(
-                    meta_err.io_error().map(|ioe| ioe.kind()).unwrap_or(io::ErrorKind::Other),
-                    format!(
-                        "metadata error under '{}' at '{}': {}",
-                        path.display(),
-                        entry.path().display(),
-                        meta_err
-                    ),
-                )
-            })?;
+            let len = entry
+                .metadata()
+                .map(|m| m.len())
+                .map_err(io::Error::from)
+                .with_path_context(Operation::Metadata, entry.path())?;
             total += len as u128;
         }
     }
@@ -43,6 +30,11 @@ fn _size(path: &Path) -> io::Result<u128> {
     Ok(total)
 }
 
+fn walk_error(e: walkdir::Error, root: &Path) -> io::Error {
+    let at = e.path().unwrap_or(root).to_owned();
+    FsError::new(Operation::ReadDir, at, e.into()).into()
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -1,9 +1,9 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{fs, io, path::Path},
 };
 
 #[cfg_attr(test, fs_ext_test_macros::fs_test(rejects_file, rejects_existing_dir, new_dir_ok))]
 pub fn create_new(path: impl AsRef<Path>) -> io::Result<()> {
-    fs::create_dir(&path).with_path_context("failed to create directory", path)
+    fs::create_dir(&path).with_path_context(Operation::CreateDir, path)
 }
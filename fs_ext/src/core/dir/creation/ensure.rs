@@ -1,9 +1,9 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{fs, io, path::Path},
 };
 
 #[cfg_attr(test, fs_ext_test_macros::fs_test(rejects_file, existing_dir_ok, new_dir_ok))]
 pub fn ensure(path: impl AsRef<Path>) -> io::Result<()> {
-    fs::create_dir_all(&path).with_path_context("failed to create directory", path)
+    fs::create_dir_all(&path).with_path_context(Operation::CreateDir, path)
 }
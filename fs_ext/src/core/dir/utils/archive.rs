@@ -0,0 +1,45 @@
+use {
+    crate::{ArchiveFormat, DirQuery},
+    std::{io, path::Path},
+};
+
+pub fn archive(
+    src: impl AsRef<Path>, dst: impl AsRef<Path>, format: ArchiveFormat,
+) -> io::Result<()> {
+    DirQuery::new(src).archive(dst, format)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{dir, testing::assert_dirs_eq},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[test]
+    fn archives_whole_tree() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("src");
+        fs::create_dir_all(src.join("empty"))?;
+        fs::create_dir_all(src.join("sub"))?;
+        fs::write(src.join("sub/a.txt"), b"a")?;
+
+        for format in [ArchiveFormat::Tar, ArchiveFormat::TarGz, ArchiveFormat::Zip] {
+            let archive_path = tmp.path().join(format!("out.{}", format.extension()));
+            if !format.is_enabled() {
+                let err = archive(&src, &archive_path, format).unwrap_err();
+                assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+                continue;
+            }
+            archive(&src, &archive_path, format)?;
+
+            let dst = tmp.path().join(format!("dst-{}", format.extension()));
+            dir::extract(&archive_path, &dst)?;
+            assert!(dst.join("empty").is_dir(), "{format:?}");
+            assert_dirs_eq(&src, &dst);
+        }
+        Ok(())
+    }
+}
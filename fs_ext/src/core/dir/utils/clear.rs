@@ -1,5 +1,5 @@
 use {
-    crate::{IoResultExt, dir},
+    crate::{IoResultExt, Operation, dir},
     std::{fs, io, path::Path},
 };
 
@@ -11,19 +11,18 @@ pub fn clear(path: impl AsRef<Path>) -> io::Result<()> {
 fn _clear(path: &Path) -> io::Result<()> {
     dir::assert_exists(path)?;
 
-    let entries = fs::read_dir(path).with_path_context("failed to read directory", path)?;
+    let entries = fs::read_dir(path).with_path_context(Operation::ReadDir, path)?;
 
     for entry in entries {
-        let entry = entry.with_path_context("failed to read an entry in directory", path)?;
+        let entry = entry.with_path_context(Operation::ReadDir, path)?;
 
         let child = entry.path();
-        let ft = entry.file_type().with_path_context("failed to read file type", &child)?;
+        let ft = entry.file_type().with_path_context(Operation::Metadata, &child)?;
 
         if ft.is_dir() {
-            fs::remove_dir_all(&child)
-                .with_path_context("failed to remove subdirectory", &child)?;
+            fs::remove_dir_all(&child).with_path_context(Operation::RemoveDir, &child)?;
         } else {
-            fs::remove_file(&child).with_path_context("failed to remove file", &child)?;
+            fs::remove_file(&child).with_path_context(Operation::Remove, &child)?;
         }
     }
 
@@ -0,0 +1,242 @@
+use {
+    crate::{ClearOptions, IoResultExt, Operation, dir, file, utils::glob_match},
+    std::{
+        fs, io,
+        path::{Path, PathBuf},
+        time::SystemTime,
+    },
+};
+
+pub fn clear_matching(
+    path: impl AsRef<Path>, options: impl AsRef<ClearOptions>,
+) -> io::Result<Vec<PathBuf>> {
+    _clear_matching(path.as_ref(), options.as_ref())
+}
+
+fn _clear_matching(path: &Path, options: &ClearOptions) -> io::Result<Vec<PathBuf>> {
+    dir::assert_exists(path)?;
+
+    let mut removed = Vec::new();
+    clear_dir(path, Path::new(""), options, SystemTime::now(), &mut removed)?;
+    Ok(removed)
+}
+
+fn clear_dir(
+    dir: &Path, rel: &Path, options: &ClearOptions, now: SystemTime, removed: &mut Vec<PathBuf>,
+) -> io::Result<()> {
+    let entries = fs::read_dir(dir).with_path_context(Operation::ReadDir, dir)?;
+
+    for entry in entries {
+        let entry = entry.with_path_context(Operation::ReadDir, dir)?;
+
+        let child = entry.path();
+        let child_rel = rel.join(entry.file_name());
+        if matches_any(&options.keep, &child_rel) {
+            continue;
+        }
+
+        let ft = entry.file_type().with_path_context(Operation::Metadata, &child)?;
+
+        if ft.is_dir() && options.recursive {
+            clear_dir(&child, &child_rel, options, now, removed)?;
+            continue;
+        }
+
+        if !is_match(&entry, &child_rel, ft.is_dir(), options, now)? {
+            continue;
+        }
+
+        if !options.dry_run {
+            remove(&child, ft.is_dir(), options.trash)?;
+        }
+        removed.push(child);
+    }
+
+    Ok(())
+}
+
+fn is_match(
+    entry: &fs::DirEntry, rel: &Path, is_dir: bool, options: &ClearOptions, now: SystemTime,
+) -> io::Result<bool> {
+    if let Some(filter) = &options.extension_filter
+        && (is_dir || !filter.matches(rel))
+    {
+        return Ok(false);
+    }
+
+    if !options.globs.is_empty() && !matches_any(&options.globs, rel) {
+        return Ok(false);
+    }
+
+    if let Some(age) = options.older_than {
+        let child = entry.path();
+        let modified = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .with_path_context(Operation::Metadata, &child)?;
+
+        match now.duration_since(modified) {
+            Ok(elapsed) if elapsed >= age => {}
+            _ => return Ok(false),
+        }
+    }
+
+    Ok(true)
+}
+
+// Patterns containing '/' match the path relative to the cleared directory;
+// bare patterns match the entry name at any depth.
+fn matches_any(patterns: &[String], rel: &Path) -> bool {
+    let rel_str = rel.to_string_lossy().replace('\\', "/");
+    let name = rel.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+
+    patterns
+        .iter()
+        .any(|p| if p.contains('/') { glob_match(p, &rel_str) } else { glob_match(p, &name) })
+}
+
+fn remove(path: &Path, is_dir: bool, trash: bool) -> io::Result<()> {
+    if trash {
+        file::trash(path)
+    } else if is_dir {
+        fs::remove_dir_all(path).with_path_context(Operation::RemoveDir, path)
+    } else {
+        fs::remove_file(path).with_path_context(Operation::Remove, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::clear_matching,
+        crate::ClearOptions,
+        filetime::FileTime,
+        std::{fs, io, path::Path, time::Duration},
+        tempfile::tempdir,
+    };
+
+    fn age(path: &Path, secs: i64) -> io::Result<()> {
+        let now = FileTime::now();
+        let then = FileTime::from_unix_time(now.unix_seconds() - secs, 0);
+        filetime::set_file_mtime(path, then)
+    }
+
+    #[test]
+    fn no_filters_behaves_like_clear() -> io::Result<()> {
+        let d = tempdir()?;
+        fs::create_dir_all(d.path().join("sub"))?;
+        fs::write(d.path().join("a.txt"), b"a")?;
+        fs::write(d.path().join("sub/b.txt"), b"b")?;
+
+        let removed = clear_matching(d.path(), ClearOptions::new())?;
+
+        assert_eq!(removed.len(), 2);
+        assert!(fs::read_dir(d.path())?.next().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn extension_filter_only_removes_matching_files() -> io::Result<()> {
+        let d = tempdir()?;
+        fs::write(d.path().join("a.log"), b"")?;
+        fs::write(d.path().join("b.LOG"), b"")?;
+        fs::write(d.path().join("keep.txt"), b"")?;
+
+        clear_matching(d.path(), ClearOptions::new().allow_extensions(["log"]))?;
+
+        assert!(!d.path().join("a.log").exists());
+        assert!(!d.path().join("b.LOG").exists());
+        assert!(d.path().join("keep.txt").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn globs_match_names_and_relative_paths() -> io::Result<()> {
+        let d = tempdir()?;
+        fs::create_dir_all(d.path().join("cache/nested"))?;
+        fs::write(d.path().join("x.tmp"), b"")?;
+        fs::write(d.path().join("cache/nested/y.tmp"), b"")?;
+        fs::write(d.path().join("cache/nested/z.dat"), b"")?;
+        fs::write(d.path().join("cache/root.dat"), b"")?;
+
+        let options = ClearOptions::new().glob("*.tmp").glob("cache/*.dat").recursive(true);
+        clear_matching(d.path(), options)?;
+
+        assert!(!d.path().join("x.tmp").exists());
+        assert!(!d.path().join("cache/nested/y.tmp").exists());
+        assert!(!d.path().join("cache/root.dat").exists());
+        assert!(d.path().join("cache/nested/z.dat").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn older_than_spares_recent_entries() -> io::Result<()> {
+        let d = tempdir()?;
+        let old = d.path().join("old.cache");
+        let fresh = d.path().join("fresh.cache");
+        fs::write(&old, b"")?;
+        fs::write(&fresh, b"")?;
+        age(&old, 8 * 24 * 60 * 60)?;
+
+        let week = Duration::from_secs(7 * 24 * 60 * 60);
+        let removed = clear_matching(d.path(), ClearOptions::new().older_than(week))?;
+
+        assert_eq!(removed, vec![old.clone()]);
+        assert!(!old.exists());
+        assert!(fresh.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn keep_list_protects_entries() -> io::Result<()> {
+        let d = tempdir()?;
+        fs::create_dir_all(d.path().join("pinned"))?;
+        fs::write(d.path().join(".gitkeep"), b"")?;
+        fs::write(d.path().join("pinned/data"), b"")?;
+        fs::write(d.path().join("junk"), b"")?;
+
+        clear_matching(d.path(), ClearOptions::new().keep(".gitkeep").keep("pinned"))?;
+
+        assert!(d.path().join(".gitkeep").exists());
+        assert!(d.path().join("pinned/data").exists());
+        assert!(!d.path().join("junk").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn dry_run_reports_without_deleting() -> io::Result<()> {
+        let d = tempdir()?;
+        fs::write(d.path().join("a.tmp"), b"")?;
+        fs::write(d.path().join("b.txt"), b"")?;
+
+        let removed = clear_matching(d.path(), ClearOptions::new().glob("*.tmp").dry_run(true))?;
+
+        assert_eq!(removed, vec![d.path().join("a.tmp")]);
+        assert!(d.path().join("a.tmp").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn non_recursive_removes_matching_directories_whole() -> io::Result<()> {
+        let d = tempdir()?;
+        fs::create_dir_all(d.path().join("cache-1/deep"))?;
+        fs::write(d.path().join("cache-1/deep/f"), b"")?;
+        fs::create_dir_all(d.path().join("src"))?;
+
+        clear_matching(d.path(), ClearOptions::new().glob("cache-*"))?;
+
+        assert!(!d.path().join("cache-1").exists());
+        assert!(d.path().join("src").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn errors_when_path_is_a_file() -> io::Result<()> {
+        let d = tempdir()?;
+        let f = d.path().join("file");
+        fs::write(&f, b"")?;
+
+        assert!(clear_matching(&f, ClearOptions::new()).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use {
+    crate::{CompareOptions, DirDiff, DirQuery, IoResultExt, Operation, SyncCompare, dir, file},
+    filetime::FileTime,
+    std::{
+        collections::BTreeMap,
+        fs, io,
+        path::{Path, PathBuf},
+    },
+};
+
+pub fn compare(
+    a: impl AsRef<Path>, b: impl AsRef<Path>, options: impl AsRef<CompareOptions>,
+) -> io::Result<DirDiff> {
+    _compare(a.as_ref(), b.as_ref(), options.as_ref())
+}
+
+// Paths in the diff are relative to the two roots. An entry that is a file on
+// one side and a directory on the other counts as differing.
+fn _compare(a: &Path, b: &Path, options: &CompareOptions) -> io::Result<DirDiff> {
+    dir::assert_exists(a)?;
+    dir::assert_exists(b)?;
+
+    let left = entries(a, options)?;
+    let mut right = entries(b, options)?;
+    let mut diff = DirDiff::default();
+
+    for (rel, is_dir) in left {
+        match right.remove(&rel) {
+            None => diff.only_in_a.push(rel),
+            Some(other) if other != is_dir => diff.differing.push(rel),
+            Some(_) if is_dir => {}
+            Some(_) => {
+                if !files_match(&a.join(&rel), &b.join(&rel), options.compare)? {
+                    diff.differing.push(rel);
+                }
+            }
+        }
+    }
+    diff.only_in_b.extend(right.into_keys());
+    Ok(diff)
+}
+
+fn entries(root: &Path, options: &CompareOptions) -> io::Result<BTreeMap<PathBuf, bool>> {
+    DirQuery::from_options(root, options.query.clone())
+        .collect()?
+        .into_iter()
+        .map(|path| {
+            let is_dir = path.is_dir();
+            let rel = path.strip_prefix(root).expect("query results live under the root");
+            Ok((rel.to_path_buf(), is_dir))
+        })
+        .collect()
+}
+
+fn files_match(a: &Path, b: &Path, compare: SyncCompare) -> io::Result<bool> {
+    match compare {
+        SyncCompare::Content => file::content_eq(a, b),
+        SyncCompare::SizeAndMtime => {
+            let ma = fs::metadata(a).with_path_context(Operation::Metadata, a)?;
+            let mb = fs::metadata(b).with_path_context(Operation::Metadata, b)?;
+            Ok(ma.len() == mb.len()
+                && FileTime::from_last_modification_time(&ma)
+                    == FileTime::from_last_modification_time(&mb))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::DirQueryOptions, tempfile::tempdir};
+
+    fn pair() -> io::Result<(tempfile::TempDir, PathBuf, PathBuf)> {
+        let tmp = tempdir()?;
+        let a = tmp.path().join("a");
+        let b = tmp.path().join("b");
+        for root in [&a, &b] {
+            fs::create_dir_all(root.join("sub"))?;
+            fs::write(root.join("same.txt"), b"same")?;
+            fs::write(root.join("sub/nested.txt"), b"nested")?;
+        }
+        Ok((tmp, a, b))
+    }
+
+    #[test]
+    fn identical_trees_have_empty_diff() -> io::Result<()> {
+        let (_tmp, a, b) = pair()?;
+        assert!(compare(&a, &b, CompareOptions::default())?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn reports_each_kind_of_difference() -> io::Result<()> {
+        let (_tmp, a, b) = pair()?;
+        fs::write(a.join("only_a.txt"), b"")?;
+        fs::create_dir(b.join("only_b"))?;
+        fs::write(b.join("sub/nested.txt"), b"NESTED")?;
+        fs::remove_file(b.join("same.txt"))?;
+        fs::create_dir(b.join("same.txt"))?;
+
+        let diff = compare(&a, &b, CompareOptions::default())?;
+
+        assert_eq!(diff.only_in_a, vec![PathBuf::from("only_a.txt")]);
+        assert_eq!(diff.only_in_b, vec![PathBuf::from("only_b")]);
+        assert_eq!(
+            diff.differing,
+            vec![PathBuf::from("same.txt"), PathBuf::from("sub/nested.txt")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn honours_query_filters() -> io::Result<()> {
+        let (_tmp, a, b) = pair()?;
+        fs::write(a.join("build.log"), b"a")?;
+        fs::write(b.join("sub/run.log"), b"b")?;
+
+        let options = CompareOptions {
+            query: DirQueryOptions::default().deny_extensions(["log"]),
+            ..Default::default()
+        };
+        assert!(compare(&a, &b, options)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn size_and_mtime_mode_skips_reading_contents() -> io::Result<()> {
+        let (_tmp, a, b) = pair()?;
+        let mtime = FileTime::from_unix_time(1_700_000_000, 0);
+        fs::write(b.join("same.txt"), b"SAME")?;
+        filetime::set_file_mtime(a.join("same.txt"), mtime)?;
+        filetime::set_file_mtime(b.join("same.txt"), mtime)?;
+        filetime::set_file_mtime(a.join("sub/nested.txt"), mtime)?;
+        filetime::set_file_mtime(b.join("sub/nested.txt"), mtime)?;
+
+        let options = CompareOptions { compare: SyncCompare::SizeAndMtime, ..Default::default() };
+        assert!(compare(&a, &b, &options)?.is_empty());
+        assert_eq!(
+            compare(&a, &b, CompareOptions::default())?.differing,
+            vec![PathBuf::from("same.txt")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn errors_when_a_root_is_missing() {
+        let tmp = tempdir().unwrap();
+        let err =
+            compare(tmp.path(), tmp.path().join("missing"), CompareOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    crate::{IoResultExt, dir},
+    crate::{CopyOptions, IoResultExt, Operation, dir, file},
     std::{fs, io, path::Path},
 };
 
@@ -11,27 +11,22 @@ fn _copy_dir_contents(src: &Path, dst: &Path) -> io::Result<()> {
     dir::assert_exists(src)?;
     dir::assert_exists(dst)?;
 
-    let entries = fs::read_dir(src).with_path_context("failed to read source directory", src)?;
+    let entries = fs::read_dir(src).with_path_context(Operation::ReadDir, src)?;
 
     for entry_res in entries {
-        let entry =
-            entry_res.with_path_context("failed to read an entry in source directory", src)?;
+        let entry = entry_res.with_path_context(Operation::ReadDir, src)?;
         let entry_path = entry.path();
 
         let name_os = entry.file_name();
         let dst_path = dst.join(Path::new(&name_os));
 
-        let ft = entry.file_type().with_path_context("failed to read file type", &entry_path)?;
+        let ft = entry.file_type().with_path_context(Operation::Metadata, &entry_path)?;
 
         if ft.is_dir() {
             dir::create_new(&dst_path)?;
             _copy_dir_contents(&entry_path, &dst_path)?;
         } else if ft.is_file() {
-            fs::copy(&entry_path, &dst_path).with_paths_context(
-                "failed to copy",
-                entry_path,
-                dst_path,
-            )?;
+            file::copy_with(&entry_path, &dst_path, CopyOptions::default())?;
         }
     }
 
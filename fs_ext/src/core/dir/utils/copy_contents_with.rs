@@ -0,0 +1,462 @@
+use {
+    crate::{
+        CollisionStrategy, CopyOptions, CopyProgress, IoResultExt, Operation, WriteOptions, dir,
+        file,
+    },
+    std::{
+        fs::{self, File},
+        io::{self, Read, Write},
+        path::{Path, PathBuf},
+    },
+};
+
+pub fn copy_dir_contents_with(
+    src: impl AsRef<Path>, dst: impl AsRef<Path>, options: impl AsRef<WriteOptions>,
+    mut progress: impl FnMut(&CopyProgress),
+) -> io::Result<CopyProgress> {
+    _copy_dir_contents_with(src.as_ref(), dst.as_ref(), options.as_ref(), &mut progress)
+}
+
+fn _copy_dir_contents_with(
+    src: &Path, dst: &Path, options: &WriteOptions, progress: &mut dyn FnMut(&CopyProgress),
+) -> io::Result<CopyProgress> {
+    dir::assert_exists(src)?;
+    dir::assert_exists(dst)?;
+
+    let mut state = CopyProgress::default();
+    tally(src, &mut state)?;
+    copy_entries(src, dst, &options.collision, &mut state, progress)?;
+
+    Ok(state)
+}
+
+fn tally(src: &Path, state: &mut CopyProgress) -> io::Result<()> {
+    let entries = fs::read_dir(src).with_path_context(Operation::ReadDir, src)?;
+
+    for entry_res in entries {
+        let entry = entry_res.with_path_context(Operation::ReadDir, src)?;
+        let entry_path = entry.path();
+
+        let ft = entry.file_type().with_path_context(Operation::Metadata, &entry_path)?;
+
+        if ft.is_dir() {
+            tally(&entry_path, state)?;
+        } else if ft.is_file() {
+            let meta = entry.metadata().with_path_context(Operation::Metadata, &entry_path)?;
+            state.files_total += 1;
+            state.bytes_total += meta.len();
+        }
+    }
+
+    Ok(())
+}
+
+// Files larger than this are copied in steps of this size, with a progress
+// call after each step.
+const PROGRESS_STEP: usize = 1 << 20;
+
+fn copy_entries(
+    src: &Path, dst: &Path, collision: &CollisionStrategy, state: &mut CopyProgress,
+    progress: &mut dyn FnMut(&CopyProgress),
+) -> io::Result<()> {
+    let entries = fs::read_dir(src).with_path_context(Operation::ReadDir, src)?;
+
+    for entry_res in entries {
+        let entry = entry_res.with_path_context(Operation::ReadDir, src)?;
+        let entry_path = entry.path();
+
+        let name_os = entry.file_name();
+        let dst_path = dst.join(Path::new(&name_os));
+
+        let ft = entry.file_type().with_path_context(Operation::Metadata, &entry_path)?;
+
+        if ft.is_dir() {
+            match target(&entry_path, &dst_path, true, collision)? {
+                Some(target) => {
+                    if !target.is_dir() {
+                        dir::create_new(&target)?;
+                    }
+                    copy_entries(&entry_path, &target, collision, state, progress)?;
+                }
+                None => {
+                    let mut subtree = CopyProgress::default();
+                    tally(&entry_path, &mut subtree)?;
+                    state.files_skipped += subtree.files_total;
+                    state.bytes_skipped += subtree.bytes_total;
+                    state.current = entry_path;
+                    progress(state);
+                }
+            }
+        } else if ft.is_file() {
+            let len = entry.metadata().with_path_context(Operation::Metadata, &entry_path)?.len();
+            state.current = entry_path.clone();
+
+            match target(&entry_path, &dst_path, false, collision)? {
+                Some(target) => {
+                    copy_file(&entry_path, &target, state, progress)?;
+                    state.files_copied += 1;
+                }
+                None => {
+                    state.files_skipped += 1;
+                    state.bytes_skipped += len;
+                }
+            }
+
+            progress(state);
+        }
+    }
+
+    Ok(())
+}
+
+// Where `src` goes, or `None` to skip it. A directory merges into an existing
+// directory; anything else already at `dst` is a collision. Symlinks at `dst`
+// are never followed, so nothing is written through them.
+fn target(
+    src: &Path, dst: &Path, src_is_dir: bool, collision: &CollisionStrategy,
+) -> io::Result<Option<PathBuf>> {
+    let existing = match fs::symlink_metadata(dst) {
+        Ok(meta) => meta,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Some(dst.to_path_buf())),
+        Err(e) => return Err(e).with_path_context(Operation::Metadata, dst),
+    };
+
+    if src_is_dir && existing.is_dir() {
+        return Ok(Some(dst.to_path_buf()));
+    }
+
+    match collision {
+        CollisionStrategy::Error => Err(io::Error::from(io::ErrorKind::AlreadyExists))
+            .with_paths_context(Operation::Copy, src, dst),
+        CollisionStrategy::Skip => Ok(None),
+        CollisionStrategy::Overwrite => {
+            if existing.is_dir() {
+                fs::remove_dir_all(dst).with_path_context(Operation::RemoveDir, dst)?;
+            } else {
+                fs::remove_file(dst).with_path_context(Operation::Remove, dst)?;
+            }
+            Ok(Some(dst.to_path_buf()))
+        }
+        CollisionStrategy::Rename(rename_opts) => {
+            rename_opts.unique_path_by(dst, |cand| fs::symlink_metadata(cand).is_ok()).map(Some)
+        }
+    }
+}
+
+fn copy_file(
+    src: &Path, dst: &Path, state: &mut CopyProgress, progress: &mut dyn FnMut(&CopyProgress),
+) -> io::Result<()> {
+    let mut source = File::open(src).with_path_context(Operation::Open, src)?;
+    let meta = source.metadata().with_path_context(Operation::Metadata, src)?;
+    let mut target = File::create(dst).with_path_context(Operation::Create, dst)?;
+
+    if meta.len() > PROGRESS_STEP as u64 {
+        copy_in_steps(&mut source, &mut target, meta.len(), state, progress)
+    } else {
+        file::copy_data(&source, &mut target, &CopyOptions::default())
+            .map(|copied| state.bytes_copied += copied)
+    }
+    .with_paths_context(Operation::Copy, src, dst)?;
+
+    fs::set_permissions(dst, meta.permissions()).with_path_context(Operation::SetPermissions, dst)
+}
+
+// Reports after every step that leaves the file unfinished; the caller
+// reports the finished file.
+fn copy_in_steps(
+    src: &mut File, dst: &mut File, len: u64, state: &mut CopyProgress,
+    progress: &mut dyn FnMut(&CopyProgress),
+) -> io::Result<()> {
+    let mut buf = vec![0; PROGRESS_STEP];
+    let mut done = 0;
+
+    loop {
+        let n = match src.read(&mut buf) {
+            Ok(0) => return Ok(()),
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        dst.write_all(&buf[..n])?;
+
+        state.bytes_copied += n as u64;
+        done += n as u64;
+        if done < len {
+            progress(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::copy_dir_contents_with,
+        crate::{CollisionStrategy, CopyProgress, RenameOptions, WriteOptions},
+        std::{fs, io},
+        tempfile::{TempDir, tempdir},
+    };
+
+    fn with_collision(collision: CollisionStrategy) -> WriteOptions {
+        WriteOptions { collision, ..Default::default() }
+    }
+
+    #[test]
+    fn copies_tree_and_reports_totals() -> io::Result<()> {
+        let src = tempdir()?;
+        let dst = tempdir()?;
+
+        fs::create_dir_all(src.path().join("x/y"))?;
+        fs::write(src.path().join("a.txt"), b"hello")?;
+        fs::write(src.path().join("x/y/b.bin"), [1u8, 2, 3])?;
+
+        let summary =
+            copy_dir_contents_with(src.path(), dst.path(), WriteOptions::default(), |_| {})?;
+
+        assert_eq!(fs::read(dst.path().join("a.txt"))?, b"hello");
+        assert_eq!(fs::read(dst.path().join("x/y/b.bin"))?, vec![1, 2, 3]);
+        assert_eq!(summary.files_total, 2);
+        assert_eq!(summary.files_copied, 2);
+        assert_eq!(summary.bytes_total, 8);
+        assert_eq!(summary.bytes_copied, 8);
+        Ok(())
+    }
+
+    #[test]
+    fn progress_is_called_once_per_file_with_growing_counts() -> io::Result<()> {
+        let src = tempdir()?;
+        let dst = tempdir()?;
+
+        fs::write(src.path().join("one"), b"1")?;
+        fs::write(src.path().join("two"), b"22")?;
+        fs::write(src.path().join("three"), b"333")?;
+
+        let mut seen: Vec<CopyProgress> = Vec::new();
+        copy_dir_contents_with(src.path(), dst.path(), WriteOptions::default(), |p| {
+            seen.push(p.clone())
+        })?;
+
+        assert_eq!(seen.len(), 3);
+        assert!(seen.iter().all(|p| p.files_total == 3 && p.bytes_total == 6));
+        assert_eq!(seen.iter().map(|p| p.files_done()).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(seen.last().unwrap().bytes_done(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn error_strategy_fails_on_existing_file() -> io::Result<()> {
+        let src = tempdir()?;
+        let dst = tempdir()?;
+
+        fs::write(src.path().join("same.txt"), b"SRC")?;
+        fs::write(dst.path().join("same.txt"), b"DST")?;
+
+        let err = copy_dir_contents_with(
+            src.path(),
+            dst.path(),
+            with_collision(CollisionStrategy::Error),
+            |_| {},
+        )
+        .unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read(dst.path().join("same.txt"))?, b"DST");
+        Ok(())
+    }
+
+    #[test]
+    fn skip_strategy_keeps_existing_file() -> io::Result<()> {
+        let src = tempdir()?;
+        let dst = tempdir()?;
+
+        fs::write(src.path().join("same.txt"), b"SRC")?;
+        fs::write(src.path().join("new.txt"), b"NEW")?;
+        fs::write(dst.path().join("same.txt"), b"DST")?;
+
+        let summary = copy_dir_contents_with(
+            src.path(),
+            dst.path(),
+            with_collision(CollisionStrategy::Skip),
+            |_| {},
+        )?;
+
+        assert_eq!(fs::read(dst.path().join("same.txt"))?, b"DST");
+        assert_eq!(fs::read(dst.path().join("new.txt"))?, b"NEW");
+        assert_eq!(summary.files_copied, 1);
+        assert_eq!(summary.files_skipped, 1);
+        assert_eq!(summary.bytes_skipped, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn overwrite_strategy_replaces_existing_file() -> io::Result<()> {
+        let src = tempdir()?;
+        let dst = tempdir()?;
+
+        fs::write(src.path().join("same.txt"), b"SRC")?;
+        fs::write(dst.path().join("same.txt"), b"DST")?;
+
+        copy_dir_contents_with(
+            src.path(),
+            dst.path(),
+            with_collision(CollisionStrategy::Overwrite),
+            |_| {},
+        )?;
+
+        assert_eq!(fs::read(dst.path().join("same.txt"))?, b"SRC");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn overwrite_strategy_replaces_symlinks_instead_of_writing_through() -> io::Result<()> {
+        use std::os::unix::fs::symlink;
+
+        let src = tempdir()?;
+        let dst = tempdir()?;
+        let outside = tempdir()?;
+
+        fs::write(src.path().join("same.txt"), b"SRC")?;
+        fs::create_dir(src.path().join("sub"))?;
+        fs::write(src.path().join("sub/inner.txt"), b"SRC")?;
+        fs::write(outside.path().join("target.txt"), b"OUTSIDE")?;
+        symlink(outside.path().join("target.txt"), dst.path().join("same.txt"))?;
+        symlink(outside.path(), dst.path().join("sub"))?;
+
+        copy_dir_contents_with(
+            src.path(),
+            dst.path(),
+            with_collision(CollisionStrategy::Overwrite),
+            |_| {},
+        )?;
+
+        assert_eq!(fs::read(outside.path().join("target.txt"))?, b"OUTSIDE");
+        assert!(!outside.path().join("inner.txt").exists());
+        assert!(fs::symlink_metadata(dst.path().join("same.txt"))?.is_file());
+        assert_eq!(fs::read(dst.path().join("same.txt"))?, b"SRC");
+        assert!(fs::symlink_metadata(dst.path().join("sub"))?.is_dir());
+        assert_eq!(fs::read(dst.path().join("sub/inner.txt"))?, b"SRC");
+        Ok(())
+    }
+
+    #[test]
+    fn rename_strategy_writes_alongside_existing_file() -> io::Result<()> {
+        let src = tempdir()?;
+        let dst = tempdir()?;
+
+        fs::write(src.path().join("same.txt"), b"SRC")?;
+        fs::write(dst.path().join("same.txt"), b"DST")?;
+
+        copy_dir_contents_with(
+            src.path(),
+            dst.path(),
+            with_collision(CollisionStrategy::Rename(RenameOptions::Counter)),
+            |_| {},
+        )?;
+
+        assert_eq!(fs::read(dst.path().join("same.txt"))?, b"DST");
+        assert_eq!(fs::read(dst.path().join("same_1.txt"))?, b"SRC");
+        Ok(())
+    }
+
+    #[test]
+    fn large_files_report_progress_while_copying() -> io::Result<()> {
+        let src = tempdir()?;
+        let dst = tempdir()?;
+
+        let big = vec![7u8; (5 << 20) / 2];
+        fs::write(src.path().join("big.bin"), &big)?;
+
+        let mut seen: Vec<CopyProgress> = Vec::new();
+        copy_dir_contents_with(src.path(), dst.path(), WriteOptions::default(), |p| {
+            seen.push(p.clone())
+        })?;
+
+        let bytes: Vec<u64> = seen.iter().map(|p| p.bytes_done()).collect();
+        assert_eq!(bytes, vec![1 << 20, 2 << 20, big.len() as u64]);
+        assert_eq!(seen.iter().map(|p| p.files_done()).collect::<Vec<_>>(), vec![0, 0, 1]);
+        assert!(seen.iter().all(|p| p.current == src.path().join("big.bin")));
+        assert_eq!(fs::read(dst.path().join("big.bin"))?, big);
+        Ok(())
+    }
+
+    fn dir_over_file(collision: CollisionStrategy) -> io::Result<(TempDir, TempDir, CopyProgress)> {
+        let src = tempdir()?;
+        let dst = tempdir()?;
+
+        fs::create_dir_all(src.path().join("entry"))?;
+        fs::write(src.path().join("entry/a.txt"), b"aa")?;
+        fs::write(src.path().join("entry/b.txt"), b"bbb")?;
+        fs::write(dst.path().join("entry"), b"DST")?;
+
+        let summary =
+            copy_dir_contents_with(src.path(), dst.path(), with_collision(collision), |_| {})?;
+        Ok((src, dst, summary))
+    }
+
+    #[test]
+    fn dir_over_file_follows_collision_strategy() -> io::Result<()> {
+        let err = dir_over_file(CollisionStrategy::Error).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        let (_src, dst, summary) = dir_over_file(CollisionStrategy::Skip)?;
+        assert_eq!(fs::read(dst.path().join("entry"))?, b"DST");
+        assert_eq!((summary.files_skipped, summary.bytes_skipped), (2, 5));
+        assert_eq!(summary.files_done(), summary.files_total);
+
+        let (_src, dst, _) = dir_over_file(CollisionStrategy::Overwrite)?;
+        assert_eq!(fs::read(dst.path().join("entry/a.txt"))?, b"aa");
+
+        let (_src, dst, _) = dir_over_file(CollisionStrategy::Rename(RenameOptions::Counter))?;
+        assert_eq!(fs::read(dst.path().join("entry"))?, b"DST");
+        assert_eq!(fs::read(dst.path().join("entry_1/b.txt"))?, b"bbb");
+        Ok(())
+    }
+
+    fn file_over_dir(collision: CollisionStrategy) -> io::Result<(TempDir, TempDir, CopyProgress)> {
+        let src = tempdir()?;
+        let dst = tempdir()?;
+
+        fs::write(src.path().join("entry.txt"), b"SRC")?;
+        fs::create_dir_all(dst.path().join("entry.txt"))?;
+        fs::write(dst.path().join("entry.txt/keep"), b"k")?;
+
+        let summary =
+            copy_dir_contents_with(src.path(), dst.path(), with_collision(collision), |_| {})?;
+        Ok((src, dst, summary))
+    }
+
+    #[test]
+    fn file_over_dir_follows_collision_strategy() -> io::Result<()> {
+        let err = file_over_dir(CollisionStrategy::Error).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        let (_src, dst, summary) = file_over_dir(CollisionStrategy::Skip)?;
+        assert!(dst.path().join("entry.txt/keep").exists());
+        assert_eq!(summary.files_skipped, 1);
+
+        let (_src, dst, _) = file_over_dir(CollisionStrategy::Overwrite)?;
+        assert_eq!(fs::read(dst.path().join("entry.txt"))?, b"SRC");
+
+        let (_src, dst, _) = file_over_dir(CollisionStrategy::Rename(RenameOptions::Counter))?;
+        assert!(dst.path().join("entry.txt/keep").exists());
+        assert_eq!(fs::read(dst.path().join("entry_1.txt"))?, b"SRC");
+        Ok(())
+    }
+
+    #[test]
+    fn merges_into_existing_subdirectories() -> io::Result<()> {
+        let src = tempdir()?;
+        let dst = tempdir()?;
+
+        fs::create_dir_all(src.path().join("sub"))?;
+        fs::write(src.path().join("sub/new.txt"), b"new")?;
+        fs::create_dir_all(dst.path().join("sub"))?;
+        fs::write(dst.path().join("sub/old.txt"), b"old")?;
+
+        copy_dir_contents_with(src.path(), dst.path(), WriteOptions::default(), |_| {})?;
+
+        assert_eq!(fs::read(dst.path().join("sub/new.txt"))?, b"new");
+        assert_eq!(fs::read(dst.path().join("sub/old.txt"))?, b"old");
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use {
+    crate::{CopyProgress, PathExt, WriteOptions, dir},
+    std::{io, path::Path},
+};
+
+pub fn copy_with(
+    src: impl AsRef<Path>, dst: impl AsRef<Path>, options: impl AsRef<WriteOptions>,
+    progress: impl FnMut(&CopyProgress),
+) -> io::Result<CopyProgress> {
+    _copy_with(src.as_ref(), dst.as_ref(), options.as_ref(), progress)
+}
+
+fn _copy_with(
+    src: &Path, dst: &Path, options: &WriteOptions, progress: impl FnMut(&CopyProgress),
+) -> io::Result<CopyProgress> {
+    dir::assert_exists(src)?;
+    options.parent.ensure(&dst.parent_or_current())?;
+    dir::ensure(dst)?;
+    dir::copy_dir_contents_with(src, dst, options, progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::copy_with,
+        crate::{CollisionStrategy, ParentPolicy, WriteOptions},
+        std::{fs, io},
+        tempfile::tempdir,
+    };
+
+    #[test]
+    fn creates_dst_and_copies_tree() -> io::Result<()> {
+        let src = tempdir()?;
+        fs::create_dir_all(src.path().join("a/b"))?;
+        fs::write(src.path().join("a/b/file.txt"), b"hello")?;
+
+        let tmp = tempdir()?;
+        let dst = tmp.path().join("copy");
+
+        let mut calls = 0;
+        let summary = copy_with(src.path(), &dst, WriteOptions::default(), |_| calls += 1)?;
+
+        assert_eq!(fs::read(dst.join("a/b/file.txt"))?, b"hello");
+        assert_eq!(summary.files_copied, 1);
+        assert_eq!(calls, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn require_exists_rejects_missing_parent() -> io::Result<()> {
+        let src = tempdir()?;
+        fs::write(src.path().join("a.txt"), b"x")?;
+
+        let tmp = tempdir()?;
+        let dst = tmp.path().join("missing/copy");
+
+        let res = copy_with(src.path(), &dst, WriteOptions::default(), |_| {});
+        assert!(res.is_err());
+        assert!(!dst.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn create_if_missing_builds_parent_chain() -> io::Result<()> {
+        let src = tempdir()?;
+        fs::write(src.path().join("a.txt"), b"x")?;
+
+        let tmp = tempdir()?;
+        let dst = tmp.path().join("missing/copy");
+
+        let options = WriteOptions { parent: ParentPolicy::CreateIfMissing, ..Default::default() };
+        copy_with(src.path(), &dst, options, |_| {})?;
+
+        assert_eq!(fs::read(dst.join("a.txt"))?, b"x");
+        Ok(())
+    }
+
+    #[test]
+    fn copying_twice_with_skip_succeeds() -> io::Result<()> {
+        let src = tempdir()?;
+        fs::create_dir_all(src.path().join("sub"))?;
+        fs::write(src.path().join("sub/x.txt"), b"x")?;
+
+        let tmp = tempdir()?;
+        let dst = tmp.path().join("dst");
+        let options = WriteOptions { collision: CollisionStrategy::Skip, ..Default::default() };
+
+        copy_with(src.path(), &dst, options, |_| {})?;
+        let summary = copy_with(src.path(), &dst, options, |_| {})?;
+
+        assert_eq!(summary.files_copied, 0);
+        assert_eq!(summary.files_skipped, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn errors_when_src_is_a_file() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src_file = tmp.path().join("not_a_dir");
+        fs::write(&src_file, b"x")?;
+
+        let res = copy_with(&src_file, tmp.path().join("dst"), WriteOptions::default(), |_| {});
+        assert!(res.is_err());
+        Ok(())
+    }
+}
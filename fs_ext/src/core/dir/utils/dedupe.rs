@@ -0,0 +1,186 @@
+use {
+    crate::{
+        CopyOptions, DedupeAction, DedupeOptions, DedupeReport, DuplicateGroup, IoResultExt,
+        Operation, PathExt, Reflink, RenameOptions, file,
+    },
+    std::{
+        fs::{self, File},
+        io,
+        path::Path,
+    },
+};
+
+// The first file of each group is kept; every other file is replaced by a link
+// to it or trashed. With `dry_run` the report lists what would happen. Groups
+// may be stale or built by hand, so each file is compared again first and
+// anything that no longer matches is skipped.
+pub fn dedupe(
+    groups: &[DuplicateGroup], options: impl AsRef<DedupeOptions>,
+) -> io::Result<DedupeReport> {
+    _dedupe(groups, options.as_ref())
+}
+
+fn _dedupe(groups: &[DuplicateGroup], options: &DedupeOptions) -> io::Result<DedupeReport> {
+    let mut report = DedupeReport::default();
+
+    for group in groups {
+        let Some((kept, duplicates)) = group.files.split_first() else { continue };
+        if duplicates.is_empty() {
+            continue;
+        }
+
+        let mut any_replaced = false;
+        for duplicate in duplicates {
+            if !still_duplicate(kept, duplicate, group.size)? {
+                report.skipped.push(duplicate.clone());
+                continue;
+            }
+            if !options.dry_run {
+                match options.action {
+                    DedupeAction::HardLink => hard_link(kept, duplicate)?,
+                    DedupeAction::Reflink => reflink(kept, duplicate)?,
+                    DedupeAction::Trash => file::trash(duplicate)?,
+                }
+            }
+            report.replaced.push(duplicate.clone());
+            report.reclaimed_bytes += group.size;
+            any_replaced = true;
+        }
+        if any_replaced {
+            report.kept.push(kept.clone());
+        }
+    }
+    Ok(report)
+}
+
+// A file that has gone missing since the scan counts as changed.
+fn still_duplicate(kept: &Path, duplicate: &Path, size: u64) -> io::Result<bool> {
+    let len = match fs::metadata(duplicate) {
+        Ok(meta) => meta.len(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e).with_path_context(Operation::Metadata, duplicate),
+    };
+    if len != size {
+        return Ok(false);
+    }
+    match file::content_eq(kept, duplicate) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+        res => res,
+    }
+}
+
+// Links are created beside the duplicate and renamed over it, so the
+// duplicate is never missing if linking fails.
+fn hard_link(kept: &Path, duplicate: &Path) -> io::Result<()> {
+    let staged = RenameOptions::Uuid.generate_unique_path(duplicate)?;
+    fs::hard_link(kept, &staged).with_paths_context(Operation::Create, kept, &staged)?;
+
+    fs::rename(&staged, duplicate)
+        .with_paths_context(Operation::Move, &staged, duplicate)
+        .inspect_err(|_| {
+            let _ = fs::remove_file(&staged);
+        })
+}
+
+fn reflink(kept: &Path, duplicate: &Path) -> io::Result<()> {
+    let perms =
+        fs::metadata(duplicate).with_path_context(Operation::Metadata, duplicate)?.permissions();
+    let source = File::open(kept).with_path_context(Operation::Open, kept)?;
+
+    let mut temp = file::temp_in(duplicate.parent_or_current())?;
+    let options = CopyOptions { reflink: Reflink::Always, sparse: false };
+    file::copy_data(&source, temp.as_file_mut(), &options).with_paths_context(
+        Operation::Copy,
+        kept,
+        duplicate,
+    )?;
+    fs::set_permissions(temp.path(), perms)
+        .with_path_context(Operation::SetPermissions, temp.path())?;
+
+    temp.persist(duplicate)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{DirQuery, test_utils::enabled_hash},
+        tempfile::tempdir,
+    };
+
+    fn dupes(root: &Path) -> io::Result<Vec<DuplicateGroup>> {
+        fs::write(root.join("a.bin"), b"payload")?;
+        fs::write(root.join("b.bin"), b"payload")?;
+        fs::write(root.join("c.bin"), b"payload")?;
+        DirQuery::new(root).find_duplicates(enabled_hash())
+    }
+
+    #[test]
+    fn dry_run_reports_without_touching_files() -> io::Result<()> {
+        let dir = tempdir()?;
+        let groups = dupes(dir.path())?;
+
+        let options = DedupeOptions { action: DedupeAction::Trash, dry_run: true };
+        let report = dedupe(&groups, options)?;
+
+        assert_eq!(report.kept, vec![dir.path().join("a.bin")]);
+        assert_eq!(report.replaced, vec![dir.path().join("b.bin"), dir.path().join("c.bin")]);
+        assert_eq!(report.reclaimed_bytes, 14);
+        assert!(dir.path().join("b.bin").exists());
+        assert!(dir.path().join("c.bin").exists());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn hard_links_duplicates_to_the_kept_file() -> io::Result<()> {
+        use std::os::unix::fs::MetadataExt;
+
+        let dir = tempdir()?;
+        let groups = dupes(dir.path())?;
+
+        dedupe(&groups, DedupeOptions::default())?;
+
+        let ino = fs::metadata(dir.path().join("a.bin"))?.ino();
+        assert_eq!(fs::metadata(dir.path().join("b.bin"))?.ino(), ino);
+        assert_eq!(fs::metadata(dir.path().join("c.bin"))?.ino(), ino);
+        assert_eq!(fs::read(dir.path().join("c.bin"))?, b"payload");
+        assert_eq!(fs::read_dir(dir.path())?.count(), 3, "no staged links left behind");
+        assert!(DirQuery::new(dir.path()).find_duplicates(enabled_hash())?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn skips_files_changed_since_the_scan() -> io::Result<()> {
+        let dir = tempdir()?;
+        let groups = dupes(dir.path())?;
+        fs::write(dir.path().join("b.bin"), b"edited!")?;
+        fs::write(dir.path().join("c.bin"), b"edited")?;
+
+        let options = DedupeOptions { action: DedupeAction::Trash, dry_run: false };
+        let report = dedupe(&groups, options)?;
+
+        assert!(report.replaced.is_empty());
+        assert!(report.kept.is_empty());
+        assert_eq!(report.skipped, vec![dir.path().join("b.bin"), dir.path().join("c.bin")]);
+        assert_eq!(fs::read(dir.path().join("b.bin"))?, b"edited!");
+        assert_eq!(fs::read(dir.path().join("c.bin"))?, b"edited");
+        Ok(())
+    }
+
+    #[test]
+    fn reflink_leaves_duplicate_in_place_when_unsupported() -> io::Result<()> {
+        let dir = tempdir()?;
+        let groups = dupes(dir.path())?;
+
+        let options = DedupeOptions { action: DedupeAction::Reflink, dry_run: false };
+        match dedupe(&groups, options) {
+            Ok(_) => {}
+            Err(e) => assert!(e.kind() != io::ErrorKind::NotFound, "{e}"),
+        }
+        assert_eq!(fs::read(dir.path().join("b.bin"))?, b"payload");
+        assert_eq!(fs::read_dir(dir.path())?.count(), 3, "no temp files left behind");
+        Ok(())
+    }
+}
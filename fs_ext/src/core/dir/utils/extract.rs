@@ -0,0 +1,306 @@
+use {
+    crate::{ArchiveFormat, IoResultExt, Operation, PathExt, dir, file},
+    std::{
+        fs::{self, File},
+        io::{self, BufReader},
+        path::{Component, Path},
+    },
+};
+
+pub fn extract(archive: impl AsRef<Path>, dst: impl AsRef<Path>) -> io::Result<()> {
+    _extract(archive.as_ref(), dst.as_ref())
+}
+
+// Entries are unpacked into a sibling temp dir and renamed into place, so a
+// failed or rejected archive never leaves a half-populated `dst` behind.
+fn _extract(archive: &Path, dst: &Path) -> io::Result<()> {
+    let format = ArchiveFormat::from_path(archive)
+        .ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "unrecognized archive extension")
+        })
+        .with_path_context(Operation::Open, archive)?;
+    if !format.is_enabled() {
+        return Err(format.unsupported());
+    }
+    file::assert_exists(archive)?;
+    if dst.exists() {
+        return Err(io::Error::from(io::ErrorKind::AlreadyExists))
+            .with_path_context(Operation::CreateDir, dst);
+    }
+
+    let staging = dir::temp_in(dst.parent_or_current())?;
+    let reader = BufReader::new(File::open(archive).with_path_context(Operation::Open, archive)?);
+    unpack(reader, staging.path(), format).with_path_context(Operation::Read, archive)?;
+    let staged = staging.keep();
+
+    let placed = set_dir_permissions(&staged)
+        .and_then(|()| fs::rename(&staged, dst).with_paths_context(Operation::Move, &staged, dst));
+    if let Err(e) = placed {
+        let _ = fs::remove_dir_all(&staged);
+        return Err(e);
+    }
+    Ok(())
+}
+
+fn unpack(reader: BufReader<File>, dst: &Path, format: ArchiveFormat) -> io::Result<()> {
+    match format {
+        #[cfg(feature = "tar")]
+        ArchiveFormat::Tar => unpack_tar(reader, dst),
+        #[cfg(feature = "tar")]
+        ArchiveFormat::TarGz => unpack_tar(flate2::read::GzDecoder::new(reader), dst),
+        #[cfg(feature = "zip")]
+        ArchiveFormat::Zip => unpack_zip(reader, dst),
+        #[allow(unreachable_patterns)]
+        _ => unreachable!("archive formats are checked with `is_enabled` first"),
+    }
+}
+
+#[cfg(feature = "tar")]
+fn unpack_tar(reader: impl io::Read, dst: &Path) -> io::Result<()> {
+    let mut archive = tar::Archive::new(reader);
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let name = entry.path()?.into_owned();
+        check_entry_name(&name)?;
+        // Link targets are only checked as text, so a chain of links such as
+        // `a -> .` then `a/b -> ..` could otherwise still point outside.
+        check_no_symlinks(dst, &name)?;
+
+        let kind = entry.header().entry_type();
+        if let Some(target) = entry.link_name()? {
+            if kind.is_symlink() {
+                check_link_target(&name, &target)?;
+            } else if kind.is_hard_link() {
+                check_entry_name(&target)?;
+                check_no_symlinks(dst, &target)?;
+            }
+        }
+
+        // `unpack_in` skips entries that would land outside `dst`, including
+        // ones reached through a symlink unpacked earlier; treat that as fatal.
+        if !entry.unpack_in(dst)? {
+            return Err(escapes(&name));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "zip")]
+fn unpack_zip(reader: BufReader<File>, dst: &Path) -> io::Result<()> {
+    let mut archive = zip::ZipArchive::new(reader)?;
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let name = Path::new(entry.name()).to_path_buf();
+        check_entry_name(&name)?;
+        check_no_symlinks(dst, &name)?;
+        let out = dst.join(&name);
+
+        if entry.is_dir() {
+            fs::create_dir_all(&out).with_path_context(Operation::CreateDir, &out)?;
+            continue;
+        }
+        if let Some(parent) = out.parent() {
+            fs::create_dir_all(parent).with_path_context(Operation::CreateDir, parent)?;
+        }
+
+        if entry.is_symlink() {
+            let mut target = String::new();
+            io::Read::read_to_string(&mut entry, &mut target)?;
+            check_link_target(&name, Path::new(&target))?;
+            #[cfg(unix)]
+            std::os::unix::fs::symlink(&target, &out).with_path_context(Operation::Create, &out)?;
+            #[cfg(not(unix))]
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "symlink entries can only be extracted on unix",
+            ));
+            #[cfg(unix)]
+            continue;
+        }
+
+        let mut file = File::create(&out).with_path_context(Operation::Create, &out)?;
+        io::copy(&mut entry, &mut file).with_path_context(Operation::Write, &out)?;
+
+        #[cfg(unix)]
+        if let Some(mode) = entry.unix_mode() {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&out, fs::Permissions::from_mode(mode & 0o777))
+                .with_path_context(Operation::SetPermissions, &out)?;
+        }
+    }
+    Ok(())
+}
+
+fn check_entry_name(name: &Path) -> io::Result<()> {
+    let ok = name.components().all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+    if ok { Ok(()) } else { Err(escapes(name)) }
+}
+
+// Symlink targets are resolved relative to the link's own directory, so `..`
+// is fine as long as it never climbs above the extraction root.
+fn check_link_target(name: &Path, target: &Path) -> io::Result<()> {
+    let mut depth = name.components().filter(|c| matches!(c, Component::Normal(_))).count();
+    depth = depth.saturating_sub(1);
+
+    for component in target.components() {
+        match component {
+            Component::Normal(_) => depth += 1,
+            Component::CurDir => {}
+            Component::ParentDir if depth > 0 => depth -= 1,
+            _ => return Err(escapes(name)),
+        }
+    }
+    Ok(())
+}
+
+// Refuses to write through a symlink placed by an earlier entry.
+#[cfg(any(feature = "tar", feature = "zip"))]
+fn check_no_symlinks(dst: &Path, name: &Path) -> io::Result<()> {
+    let mut current = dst.to_path_buf();
+    for component in name.components() {
+        current.push(component);
+        if fs::symlink_metadata(&current).is_ok_and(|m| m.file_type().is_symlink()) {
+            return Err(escapes(name));
+        }
+    }
+    Ok(())
+}
+
+fn escapes(name: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("archive entry '{}' escapes the destination", name.display()),
+    )
+}
+
+// `TempDir` is created owner-only; the extracted root should look like a
+// regular directory once it's in place.
+fn set_dir_permissions(_path: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(_path, fs::Permissions::from_mode(0o755))
+            .with_path_context(Operation::SetPermissions, _path)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, tempfile::tempdir};
+
+    #[cfg(feature = "tar")]
+    fn raw_tar(path: &Path, name: &[u8], link: Option<&[u8]>) {
+        let mut header = tar::Header::new_old();
+        header.as_old_mut().name[..name.len()].copy_from_slice(name);
+        match link {
+            Some(target) => {
+                header.set_entry_type(tar::EntryType::Symlink);
+                header.as_old_mut().linkname[..target.len()].copy_from_slice(target);
+                header.set_size(0);
+            }
+            None => header.set_size(4),
+        }
+        header.set_mode(0o644);
+        header.set_cksum();
+
+        let mut builder = tar::Builder::new(File::create(path).unwrap());
+        builder.append(&header, &b"evil"[..]).unwrap();
+        builder.finish().unwrap();
+    }
+
+    #[test]
+    fn rejects_unknown_extension() {
+        let tmp = tempdir().unwrap();
+        let archive = tmp.path().join("data.rar");
+        fs::write(&archive, b"").unwrap();
+
+        let err = extract(&archive, tmp.path().join("out")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[cfg(feature = "tar")]
+    #[test]
+    fn rejects_existing_destination() {
+        let tmp = tempdir().unwrap();
+        let archive = tmp.path().join("ok.tar");
+        raw_tar(&archive, b"file.txt", None);
+        let dst = tmp.path().join("out");
+        fs::create_dir(&dst).unwrap();
+
+        let err = extract(&archive, &dst).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    }
+
+    #[cfg(feature = "tar")]
+    #[test]
+    fn rejects_tar_path_traversal() {
+        let tmp = tempdir().unwrap();
+        let archive = tmp.path().join("evil.tar");
+        raw_tar(&archive, b"../evil.txt", None);
+
+        let err = extract(&archive, tmp.path().join("out")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(!tmp.path().join("evil.txt").exists());
+        assert!(!tmp.path().join("out").exists());
+        assert_eq!(fs::read_dir(tmp.path()).unwrap().count(), 1, "staging dir is cleaned up");
+    }
+
+    #[cfg(feature = "tar")]
+    #[test]
+    fn rejects_tar_symlink_escaping_root() {
+        let tmp = tempdir().unwrap();
+        let archive = tmp.path().join("link.tar");
+        raw_tar(&archive, b"nested/link", Some(b"../../outside"));
+
+        let err = extract(&archive, tmp.path().join("out")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[cfg(all(feature = "tar", unix))]
+    #[test]
+    fn rejects_tar_symlink_chain_escaping_root() {
+        let tmp = tempdir().unwrap();
+        let archive = tmp.path().join("chain.tar");
+        let mut builder = tar::Builder::new(File::create(&archive).unwrap());
+        for (name, target) in [("a", "."), ("a/b", "..")] {
+            let mut header = tar::Header::new_gnu();
+            header.set_entry_type(tar::EntryType::Symlink);
+            header.set_size(0);
+            builder.append_link(&mut header, name, target).unwrap();
+        }
+        builder.finish().unwrap();
+
+        let err = extract(&archive, tmp.path().join("out")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(!tmp.path().join("out").exists());
+    }
+
+    #[cfg(feature = "zip")]
+    #[test]
+    fn rejects_zip_path_traversal() {
+        use {std::io::Write, zip::write::SimpleFileOptions};
+
+        let tmp = tempdir().unwrap();
+        let archive = tmp.path().join("evil.zip");
+        let mut zip = zip::ZipWriter::new(File::create(&archive).unwrap());
+        zip.start_file("../evil.txt", SimpleFileOptions::default()).unwrap();
+        zip.write_all(b"evil").unwrap();
+        zip.finish().unwrap();
+
+        let err = extract(&archive, tmp.path().join("out")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(!tmp.path().join("evil.txt").exists());
+        assert!(!tmp.path().join("out").exists());
+    }
+
+    #[test]
+    fn link_targets_are_checked_lexically() {
+        assert!(check_link_target(Path::new("a/b/link"), Path::new("../c")).is_ok());
+        assert!(check_link_target(Path::new("a/link"), Path::new("../c")).is_ok());
+        assert!(check_link_target(Path::new("link"), Path::new("../c")).is_err());
+        assert!(check_link_target(Path::new("a/link"), Path::new("/etc/passwd")).is_err());
+    }
+}
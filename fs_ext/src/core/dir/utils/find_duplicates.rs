@@ -0,0 +1,28 @@
+use {
+    crate::{DirQuery, DuplicateGroup, HashAlgorithm},
+    std::{io, path::Path},
+};
+
+pub fn find_duplicates(
+    path: impl AsRef<Path>, algorithm: HashAlgorithm,
+) -> io::Result<Vec<DuplicateGroup>> {
+    DirQuery::new(path).find_duplicates(algorithm)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_utils::enabled_hash, std::fs, tempfile::tempdir};
+
+    #[test]
+    fn scans_whole_tree() -> io::Result<()> {
+        let dir = tempdir()?;
+        fs::create_dir_all(dir.path().join("a/b"))?;
+        fs::write(dir.path().join("one.txt"), b"dup")?;
+        fs::write(dir.path().join("a/b/two.txt"), b"dup")?;
+
+        let groups = find_duplicates(dir.path(), enabled_hash())?;
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].files.len(), 2);
+        Ok(())
+    }
+}
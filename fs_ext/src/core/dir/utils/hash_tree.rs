@@ -0,0 +1,33 @@
+use {
+    crate::{Digest, DirQuery, HashAlgorithm},
+    std::{io, path::Path},
+};
+
+pub fn hash_tree(path: impl AsRef<Path>, algorithm: HashAlgorithm) -> io::Result<Digest> {
+    DirQuery::new(path).hash_tree(algorithm)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_utils::enabled_hash, std::fs, tempfile::tempdir};
+
+    #[test]
+    fn errors_on_missing_dir() {
+        let dir = tempdir().unwrap();
+        let err = hash_tree(dir.path().join("missing"), enabled_hash()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn empty_dirs_share_a_digest() -> io::Result<()> {
+        let a = tempdir()?;
+        let b = tempdir()?;
+        let c = tempdir()?;
+        fs::write(b.path().join("x"), b"")?;
+
+        let algorithm = enabled_hash();
+        assert_eq!(hash_tree(a.path(), algorithm)?, hash_tree(c.path(), algorithm)?);
+        assert_ne!(hash_tree(a.path(), algorithm)?, hash_tree(b.path(), algorithm)?);
+        Ok(())
+    }
+}
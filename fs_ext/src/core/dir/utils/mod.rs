@@ -1,5 +1,31 @@
+#[cfg(any(feature = "tar", feature = "zip"))]
+mod archive;
 mod clear;
+mod clear_matching;
+mod compare;
 mod copy;
 mod copy_contents;
+mod copy_contents_with;
+mod copy_with;
+#[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+mod dedupe;
+#[cfg(any(feature = "tar", feature = "zip"))]
+mod extract;
+#[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+mod find_duplicates;
+#[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+mod hash_tree;
+mod move_to;
+mod sync;
 
-pub use {clear::clear, copy::copy, copy_contents::copy_dir_contents};
+pub use {
+    clear::clear, clear_matching::clear_matching, compare::compare, copy::copy,
+    copy_contents::copy_dir_contents, copy_contents_with::copy_dir_contents_with,
+    copy_with::copy_with, move_to::move_to, sync::sync,
+};
+
+#[cfg(any(feature = "tar", feature = "zip"))]
+pub use {archive::archive, extract::extract};
+
+#[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+pub use {dedupe::dedupe, find_duplicates::find_duplicates, hash_tree::hash_tree};
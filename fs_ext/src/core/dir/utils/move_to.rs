@@ -0,0 +1,308 @@
+use {
+    crate::{
+        CollisionStrategy, CopyOptions, IoResultExt, Operation, PathExt, RenameOptions,
+        WriteOptions, dir, file,
+    },
+    std::{
+        fs, io,
+        path::{Path, PathBuf},
+    },
+};
+
+pub fn move_to(
+    src: impl AsRef<Path>, dst: impl AsRef<Path>, options: impl AsRef<WriteOptions>,
+) -> io::Result<Option<PathBuf>> {
+    _move_to(src.as_ref(), dst.as_ref(), options.as_ref())
+}
+
+fn _move_to(src: &Path, dst: &Path, options: &WriteOptions) -> io::Result<Option<PathBuf>> {
+    dir::assert_exists(src)?;
+    options.parent.ensure(&dst.parent_or_current())?;
+
+    if !dst.exists() {
+        relocate(src, dst)?;
+        return Ok(Some(dst.to_path_buf()));
+    }
+
+    match &options.collision {
+        CollisionStrategy::Error => Err(io::Error::from(io::ErrorKind::AlreadyExists))
+            .with_paths_context(Operation::Move, src, dst),
+        CollisionStrategy::Skip => Ok(None),
+        CollisionStrategy::Overwrite => {
+            replace(src, dst)?;
+            Ok(Some(dst.to_path_buf()))
+        }
+        CollisionStrategy::Rename(rename_opts) => {
+            let target = rename_opts.generate_unique_path(dst)?;
+            relocate(src, &target)?;
+            Ok(Some(target))
+        }
+    }
+}
+
+// The existing destination is moved aside rather than deleted up front so it
+// can be restored if the move itself fails.
+fn replace(src: &Path, dst: &Path) -> io::Result<()> {
+    let backup = RenameOptions::Uuid.generate_unique_path(dst)?;
+    fs::rename(dst, &backup).with_paths_context(Operation::Move, dst, &backup)?;
+
+    if let Err(e) = relocate(src, dst) {
+        let _ = fs::rename(&backup, dst);
+        return Err(e);
+    }
+
+    if backup.is_dir() {
+        fs::remove_dir_all(&backup).with_path_context(Operation::RemoveDir, &backup)
+    } else {
+        fs::remove_file(&backup).with_path_context(Operation::Remove, &backup)
+    }
+}
+
+fn relocate(src: &Path, dst: &Path) -> io::Result<()> {
+    match fs::rename(src, dst) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => copy_then_remove(src, dst),
+        Err(e) => Err(e).with_paths_context(Operation::Move, src, dst),
+    }
+}
+
+fn copy_then_remove(src: &Path, dst: &Path) -> io::Result<()> {
+    let perms = fs::metadata(src).with_path_context(Operation::Metadata, src)?.permissions();
+
+    let staging = dir::temp_in(dst.parent_or_current())?;
+    stage(src, staging.path())?;
+    let staged = staging.keep();
+
+    let placed = fs::set_permissions(&staged, perms)
+        .with_path_context(Operation::SetPermissions, &staged)
+        .and_then(|()| fs::rename(&staged, dst).with_paths_context(Operation::Move, &staged, dst));
+    if let Err(e) = placed {
+        let _ = fs::remove_dir_all(&staged);
+        return Err(e);
+    }
+
+    fs::remove_dir_all(src).with_path_context(Operation::RemoveDir, src)
+}
+
+// Everything that ends up in `dst` has to be reproduced exactly, since `src`
+// is deleted afterwards; anything the copy can't recreate is an error.
+fn stage(src: &Path, dst: &Path) -> io::Result<()> {
+    let entries = fs::read_dir(src).with_path_context(Operation::ReadDir, src)?;
+
+    for entry_res in entries {
+        let entry = entry_res.with_path_context(Operation::ReadDir, src)?;
+        let entry_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+
+        let ft = entry.file_type().with_path_context(Operation::Metadata, &entry_path)?;
+
+        if ft.is_dir() {
+            dir::create_new(&dst_path)?;
+            stage(&entry_path, &dst_path)?;
+
+            let perms =
+                entry.metadata().with_path_context(Operation::Metadata, &entry_path)?.permissions();
+            fs::set_permissions(&dst_path, perms)
+                .with_path_context(Operation::SetPermissions, &dst_path)?;
+        } else if ft.is_file() {
+            file::copy_with(&entry_path, &dst_path, CopyOptions::default())?;
+        } else if ft.is_symlink() {
+            file::copy_symlink(&entry_path, &dst_path)?;
+        } else {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "special files can't be moved across devices",
+            ))
+            .with_paths_context(Operation::Move, &entry_path, &dst_path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{copy_then_remove, move_to},
+        crate::{CollisionStrategy, ParentPolicy, RenameOptions, WriteOptions},
+        std::{fs, io, path::Path},
+        tempfile::tempdir,
+    };
+
+    fn with_collision(collision: CollisionStrategy) -> WriteOptions {
+        WriteOptions { collision, ..Default::default() }
+    }
+
+    fn make_tree(root: &Path) -> io::Result<()> {
+        fs::create_dir_all(root.join("sub"))?;
+        fs::write(root.join("a.txt"), b"a")?;
+        fs::write(root.join("sub/b.txt"), b"b")?;
+        Ok(())
+    }
+
+    #[test]
+    fn moves_tree_to_new_location() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        make_tree(&src)?;
+
+        let moved = move_to(&src, &dst, WriteOptions::default())?;
+
+        assert_eq!(moved.as_deref(), Some(dst.as_path()));
+        assert!(!src.exists());
+        assert_eq!(fs::read(dst.join("sub/b.txt"))?, b"b");
+        Ok(())
+    }
+
+    #[test]
+    fn error_strategy_rejects_existing_destination() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        make_tree(&src)?;
+        fs::create_dir(&dst)?;
+
+        let err = move_to(&src, &dst, WriteOptions::default()).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert!(src.join("a.txt").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn skip_strategy_returns_none() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        make_tree(&src)?;
+        fs::create_dir(&dst)?;
+
+        assert!(move_to(&src, &dst, with_collision(CollisionStrategy::Skip))?.is_none());
+        assert!(src.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn overwrite_strategy_replaces_non_empty_destination() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        make_tree(&src)?;
+        fs::create_dir(&dst)?;
+        fs::write(dst.join("old.txt"), b"old")?;
+
+        move_to(&src, &dst, with_collision(CollisionStrategy::Overwrite))?;
+
+        assert!(!src.exists());
+        assert!(!dst.join("old.txt").exists());
+        assert_eq!(fs::read(dst.join("a.txt"))?, b"a");
+        assert_eq!(fs::read_dir(tmp.path())?.count(), 1, "backup should be cleaned up");
+        Ok(())
+    }
+
+    #[test]
+    fn rename_strategy_picks_unique_destination() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        make_tree(&src)?;
+        fs::create_dir(&dst)?;
+
+        let opts = with_collision(CollisionStrategy::Rename(RenameOptions::Counter));
+        let moved = move_to(&src, &dst, opts)?.unwrap();
+
+        assert_eq!(moved, tmp.path().join("dst_1"));
+        assert_eq!(fs::read(moved.join("a.txt"))?, b"a");
+        Ok(())
+    }
+
+    #[test]
+    fn parent_policy_is_honoured() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("nested/dst");
+        make_tree(&src)?;
+
+        assert!(move_to(&src, &dst, WriteOptions::default()).is_err());
+
+        let opts = WriteOptions { parent: ParentPolicy::CreateIfMissing, ..Default::default() };
+        move_to(&src, &dst, opts)?;
+        assert_eq!(fs::read(dst.join("a.txt"))?, b"a");
+        Ok(())
+    }
+
+    #[test]
+    fn errors_when_src_is_a_file() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("file");
+        fs::write(&src, b"x")?;
+
+        assert!(move_to(&src, tmp.path().join("dst"), WriteOptions::default()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn copy_fallback_moves_tree_and_removes_source() -> io::Result<()> {
+        let src_root = tempdir()?;
+        let dst_root = tempdir()?;
+        let src = src_root.path().join("src");
+        let dst = dst_root.path().join("dst");
+        make_tree(&src)?;
+
+        copy_then_remove(&src, &dst)?;
+
+        assert!(!src.exists());
+        assert_eq!(fs::read(dst.join("a.txt"))?, b"a");
+        assert_eq!(fs::read(dst.join("sub/b.txt"))?, b"b");
+        assert_eq!(fs::read_dir(dst_root.path())?.count(), 1, "no staging dir left behind");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_fallback_recreates_symlinks() -> io::Result<()> {
+        use std::os::unix::fs::symlink;
+
+        let src_root = tempdir()?;
+        let dst_root = tempdir()?;
+        let src = src_root.path().join("src");
+        let dst = dst_root.path().join("dst");
+        make_tree(&src)?;
+        symlink("a.txt", src.join("rel"))?;
+        symlink("sub", src.join("sub_link"))?;
+        symlink("missing", src.join("dangling"))?;
+
+        copy_then_remove(&src, &dst)?;
+
+        assert!(!src.exists());
+        for name in ["rel", "sub_link", "dangling"] {
+            let link = dst.join(name);
+            assert!(fs::symlink_metadata(&link)?.file_type().is_symlink(), "{name}");
+        }
+        assert_eq!(fs::read(dst.join("rel"))?, b"a");
+        assert_eq!(fs::read(dst.join("sub_link/b.txt"))?, b"b");
+        assert_eq!(fs::read_link(dst.join("dangling"))?.to_str(), Some("missing"));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_fallback_refuses_special_files_and_keeps_source() -> io::Result<()> {
+        let src_root = tempdir()?;
+        let dst_root = tempdir()?;
+        let src = src_root.path().join("src");
+        let dst = dst_root.path().join("dst");
+        make_tree(&src)?;
+        let _listener = std::os::unix::net::UnixListener::bind(src.join("sub/sock"))?;
+
+        let err = copy_then_remove(&src, &dst).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+        assert!(src.join("sub/sock").exists());
+        assert_eq!(fs::read(src.join("a.txt"))?, b"a");
+        assert!(!dst.exists());
+        assert_eq!(fs::read_dir(dst_root.path())?.count(), 0, "staging dir is cleaned up");
+        Ok(())
+    }
+}
@@ -0,0 +1,469 @@
+use {
+    crate::{IoResultExt, Operation, SyncCompare, SyncOptions, SyncReport, dir, file},
+    filetime::FileTime,
+    std::{
+        collections::HashSet,
+        fs::{self, FileType, Metadata},
+        io,
+        path::Path,
+    },
+};
+
+pub fn sync(
+    src: impl AsRef<Path>, dst: impl AsRef<Path>, options: impl AsRef<SyncOptions>,
+) -> io::Result<SyncReport> {
+    _sync(src.as_ref(), dst.as_ref(), options.as_ref())
+}
+
+fn _sync(src: &Path, dst: &Path, options: &SyncOptions) -> io::Result<SyncReport> {
+    dir::assert_exists(src)?;
+    dir::ensure(dst)?;
+
+    let mut report = SyncReport::default();
+    sync_dir(src, dst, options, &mut report)?;
+
+    Ok(report)
+}
+
+fn sync_dir(
+    src: &Path, dst: &Path, options: &SyncOptions, report: &mut SyncReport,
+) -> io::Result<()> {
+    let entries = fs::read_dir(src).with_path_context(Operation::ReadDir, src)?;
+    let mut seen = HashSet::new();
+
+    for entry_res in entries {
+        let entry = entry_res.with_path_context(Operation::ReadDir, src)?;
+        let entry_path = entry.path();
+
+        let name_os = entry.file_name();
+        let dst_path = dst.join(Path::new(&name_os));
+        seen.insert(name_os);
+
+        let ft = entry.file_type().with_path_context(Operation::Metadata, &entry_path)?;
+        let existing = existing_type(&dst_path)?;
+
+        if ft.is_dir() {
+            match existing {
+                Some(dt) if dt.is_dir() => {}
+                other => {
+                    if let Some(dt) = other {
+                        remove_entry(&dst_path, dt, report)?;
+                    }
+                    dir::create_new(&dst_path)?;
+                    report.created_dirs.push(dst_path.clone());
+                }
+            }
+
+            sync_dir(&entry_path, &dst_path, options, report)?;
+
+            let meta = entry.metadata().with_path_context(Operation::Metadata, &entry_path)?;
+            copy_metadata(&meta, &dst_path)?;
+        } else if ft.is_file() {
+            if let Some(dt) = existing.filter(|dt| dt.is_dir()) {
+                remove_entry(&dst_path, dt, report)?;
+            }
+
+            let meta = entry.metadata().with_path_context(Operation::Metadata, &entry_path)?;
+
+            if is_up_to_date(&entry_path, &meta, &dst_path, options.compare)? {
+                report.skipped.push(dst_path);
+            } else {
+                copy_file(&entry_path, &meta, &dst_path)?;
+                report.copied.push(dst_path);
+            }
+        } else if ft.is_symlink() {
+            let target =
+                fs::read_link(&entry_path).with_path_context(Operation::Read, &entry_path)?;
+
+            match existing {
+                Some(dt) if dt.is_symlink() && fs::read_link(&dst_path).ok() == Some(target) => {
+                    report.skipped.push(dst_path);
+                }
+                other => {
+                    if let Some(dt) = other {
+                        remove_entry(&dst_path, dt, report)?;
+                    }
+                    file::copy_symlink(&entry_path, &dst_path)?;
+                    report.copied.push(dst_path);
+                }
+            }
+        } else {
+            // Sockets, FIFOs and device nodes can't be reproduced by copying.
+            report.unsupported.push(entry_path);
+        }
+    }
+
+    if options.delete_extraneous {
+        let entries = fs::read_dir(dst).with_path_context(Operation::ReadDir, dst)?;
+
+        for entry_res in entries {
+            let entry = entry_res.with_path_context(Operation::ReadDir, dst)?;
+            if seen.contains(&entry.file_name()) {
+                continue;
+            }
+
+            let child = entry.path();
+            let ft = entry.file_type().with_path_context(Operation::Metadata, &child)?;
+            remove_entry(&child, ft, report)?;
+        }
+    }
+
+    Ok(())
+}
+
+// The type of whatever is at `path`, without following symlinks.
+fn existing_type(path: &Path) -> io::Result<Option<FileType>> {
+    match fs::symlink_metadata(path) {
+        Ok(meta) => Ok(Some(meta.file_type())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_path_context(Operation::Metadata, path),
+    }
+}
+
+fn remove_entry(path: &Path, ft: FileType, report: &mut SyncReport) -> io::Result<()> {
+    if ft.is_dir() {
+        fs::remove_dir_all(path).with_path_context(Operation::RemoveDir, path)?;
+    } else {
+        fs::remove_file(path).with_path_context(Operation::Remove, path)?;
+    }
+    report.deleted.push(path.to_owned());
+    Ok(())
+}
+
+fn is_up_to_date(
+    src: &Path, src_meta: &Metadata, dst: &Path, compare: SyncCompare,
+) -> io::Result<bool> {
+    let dst_meta = match fs::symlink_metadata(dst) {
+        Ok(meta) => meta,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e).with_path_context(Operation::Metadata, dst),
+    };
+
+    if !dst_meta.is_file() || dst_meta.len() != src_meta.len() {
+        return Ok(false);
+    }
+
+    match compare {
+        SyncCompare::SizeAndMtime => Ok(FileTime::from_last_modification_time(src_meta)
+            == FileTime::from_last_modification_time(&dst_meta)),
+        SyncCompare::Content => file::content_eq(src, dst),
+    }
+}
+
+// Copies through a tempfile next to `dst` so an interrupted sync never leaves a
+// partially written file that a later run would mistake for an up-to-date one.
+fn copy_file(src: &Path, src_meta: &Metadata, dst: &Path) -> io::Result<()> {
+    let parent = dst.parent().unwrap_or(dst);
+
+    let mut temp = file::temp_in(parent)?;
+    temp.copy_from(src)?;
+    copy_metadata(src_meta, temp.path())?;
+    temp.persist(dst)?;
+
+    Ok(())
+}
+
+fn copy_metadata(src_meta: &Metadata, dst: &Path) -> io::Result<()> {
+    let mut perms = src_meta.permissions();
+    // A read-only mirror dir would stop the next run from updating it, so
+    // directories always keep owner write.
+    if src_meta.is_dir() {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            perms.set_mode(perms.mode() | 0o200);
+        }
+        #[cfg(not(unix))]
+        perms.set_readonly(false);
+    }
+    fs::set_permissions(dst, perms).with_path_context(Operation::SetPermissions, dst)?;
+
+    let atime = FileTime::from_last_access_time(src_meta);
+    let mtime = FileTime::from_last_modification_time(src_meta);
+    filetime::set_file_times(dst, atime, mtime).with_path_context(Operation::SetTimes, dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::sync,
+        crate::{SyncCompare, SyncOptions, testing::assert_dirs_eq},
+        filetime::FileTime,
+        std::{fs, io},
+        tempfile::tempdir,
+    };
+
+    #[test]
+    fn copies_tree_into_missing_dst() -> io::Result<()> {
+        let src = tempdir()?;
+        fs::create_dir_all(src.path().join("a/b"))?;
+        fs::write(src.path().join("a/b/file.txt"), b"hello")?;
+        fs::write(src.path().join("root.bin"), [1u8, 2, 3])?;
+
+        let tmp = tempdir()?;
+        let dst = tmp.path().join("mirror");
+
+        let report = sync(src.path(), &dst, SyncOptions::default())?;
+
+        assert_eq!(fs::read(dst.join("a/b/file.txt"))?, b"hello");
+        assert_eq!(fs::read(dst.join("root.bin"))?, vec![1, 2, 3]);
+        assert_dirs_eq(src.path(), &dst);
+        assert_eq!(report.copied.len(), 2);
+        assert_eq!(report.created_dirs.len(), 2);
+        assert!(report.skipped.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn second_run_skips_unchanged_files() -> io::Result<()> {
+        let src = tempdir()?;
+        fs::create_dir_all(src.path().join("sub"))?;
+        fs::write(src.path().join("sub/x.txt"), b"x")?;
+        fs::write(src.path().join("y.txt"), b"y")?;
+
+        let dst = tempdir()?;
+
+        sync(src.path(), dst.path(), SyncOptions::default())?;
+        let report = sync(src.path(), dst.path(), SyncOptions::default())?;
+
+        assert!(report.copied.is_empty(), "nothing should be recopied: {report:?}");
+        assert_eq!(report.skipped.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn recopies_file_when_mtime_differs() -> io::Result<()> {
+        let src = tempdir()?;
+        let dst = tempdir()?;
+        fs::write(src.path().join("a.txt"), b"new")?;
+        fs::write(dst.path().join("a.txt"), b"old")?;
+        filetime::set_file_mtime(dst.path().join("a.txt"), FileTime::from_unix_time(1_000, 0))?;
+
+        let report = sync(src.path(), dst.path(), SyncOptions::default())?;
+
+        assert_eq!(report.copied, vec![dst.path().join("a.txt")]);
+        assert_eq!(fs::read(dst.path().join("a.txt"))?, b"new");
+        Ok(())
+    }
+
+    #[test]
+    fn content_compare_detects_change_with_matching_size_and_mtime() -> io::Result<()> {
+        let src = tempdir()?;
+        let dst = tempdir()?;
+        let mtime = FileTime::from_unix_time(1_000, 0);
+
+        fs::write(src.path().join("a.txt"), b"abc")?;
+        fs::write(dst.path().join("a.txt"), b"xyz")?;
+        filetime::set_file_mtime(src.path().join("a.txt"), mtime)?;
+        filetime::set_file_mtime(dst.path().join("a.txt"), mtime)?;
+
+        let report = sync(src.path(), dst.path(), SyncOptions::default())?;
+        assert_eq!(report.skipped.len(), 1, "size+mtime can't see the change");
+
+        let options = SyncOptions { compare: SyncCompare::Content, ..Default::default() };
+        let report = sync(src.path(), dst.path(), options)?;
+        assert_eq!(report.copied.len(), 1);
+        assert_eq!(fs::read(dst.path().join("a.txt"))?, b"abc");
+        Ok(())
+    }
+
+    #[test]
+    fn resumes_into_partially_populated_dst() -> io::Result<()> {
+        let src = tempdir()?;
+        fs::create_dir_all(src.path().join("sub"))?;
+        fs::write(src.path().join("sub/a.txt"), b"a")?;
+        fs::write(src.path().join("sub/b.txt"), b"b")?;
+
+        let dst = tempdir()?;
+        fs::create_dir_all(dst.path().join("sub"))?;
+
+        let report = sync(src.path(), dst.path(), SyncOptions::default())?;
+
+        assert_eq!(report.copied.len(), 2);
+        assert!(report.created_dirs.is_empty());
+        assert_eq!(fs::read(dst.path().join("sub/b.txt"))?, b"b");
+        Ok(())
+    }
+
+    #[test]
+    fn delete_extraneous_removes_extra_entries() -> io::Result<()> {
+        let src = tempdir()?;
+        fs::write(src.path().join("keep.txt"), b"k")?;
+
+        let dst = tempdir()?;
+        fs::write(dst.path().join("stale.txt"), b"s")?;
+        fs::create_dir_all(dst.path().join("old/nested"))?;
+
+        let report = sync(src.path(), dst.path(), SyncOptions::default())?;
+        assert!(report.deleted.is_empty());
+        assert!(dst.path().join("stale.txt").exists());
+
+        let options = SyncOptions { delete_extraneous: true, ..Default::default() };
+        let report = sync(src.path(), dst.path(), options)?;
+
+        assert_eq!(report.deleted.len(), 2);
+        assert!(!dst.path().join("stale.txt").exists());
+        assert!(!dst.path().join("old").exists());
+        assert!(dst.path().join("keep.txt").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn replaces_entries_whose_type_changed() -> io::Result<()> {
+        let src = tempdir()?;
+        fs::create_dir_all(src.path().join("was_file"))?;
+        fs::write(src.path().join("was_dir"), b"now a file")?;
+
+        let dst = tempdir()?;
+        fs::write(dst.path().join("was_file"), b"file")?;
+        fs::create_dir_all(dst.path().join("was_dir/inner"))?;
+
+        sync(src.path(), dst.path(), SyncOptions::default())?;
+
+        assert!(dst.path().join("was_file").is_dir());
+        assert_eq!(fs::read(dst.path().join("was_dir"))?, b"now a file");
+        Ok(())
+    }
+
+    #[test]
+    fn preserves_mtime() -> io::Result<()> {
+        let src = tempdir()?;
+        let file = src.path().join("a.txt");
+        fs::write(&file, b"a")?;
+        filetime::set_file_mtime(&file, FileTime::from_unix_time(1_234_567, 0))?;
+
+        let dst = tempdir()?;
+        sync(src.path(), dst.path(), SyncOptions::default())?;
+
+        let meta = fs::metadata(dst.path().join("a.txt"))?;
+        assert_eq!(FileTime::from_last_modification_time(&meta).unix_seconds(), 1_234_567);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn preserves_permissions() -> io::Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let src = tempdir()?;
+        let file = src.path().join("run.sh");
+        fs::write(&file, b"#!/bin/sh")?;
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o750))?;
+
+        let dst = tempdir()?;
+        sync(src.path(), dst.path(), SyncOptions::default())?;
+
+        let mode = fs::metadata(dst.path().join("run.sh"))?.permissions().mode();
+        assert_eq!(mode & 0o777, 0o750);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn read_only_source_dirs_stay_writable_in_the_mirror() -> io::Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let src = tempdir()?;
+        let locked = src.path().join("locked");
+        fs::create_dir(&locked)?;
+        fs::write(locked.join("a.txt"), b"one")?;
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o555))?;
+
+        let dst = tempdir()?;
+        sync(src.path(), dst.path(), SyncOptions::default())?;
+
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o755))?;
+        fs::write(locked.join("a.txt"), b"two!")?;
+        fs::write(locked.join("b.txt"), b"new")?;
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o555))?;
+
+        let report = sync(src.path(), dst.path(), SyncOptions::default());
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o755))?;
+        assert_eq!(report?.copied.len(), 2);
+
+        let mode = fs::metadata(dst.path().join("locked"))?.permissions().mode();
+        assert_eq!(mode & 0o777, 0o755);
+        assert_eq!(fs::read(dst.path().join("locked/a.txt"))?, b"two!");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn mirrors_symlinks_as_links() -> io::Result<()> {
+        use std::os::unix::fs::symlink;
+
+        let src = tempdir()?;
+        fs::create_dir(src.path().join("real"))?;
+        fs::write(src.path().join("real/a.txt"), b"a")?;
+        symlink("real", src.path().join("dir_link"))?;
+        symlink("real/a.txt", src.path().join("file_link"))?;
+
+        let dst = tempdir()?;
+        let report = sync(src.path(), dst.path(), SyncOptions::default())?;
+        assert_eq!(report.copied.len(), 3);
+
+        for name in ["dir_link", "file_link"] {
+            let link = dst.path().join(name);
+            assert!(fs::symlink_metadata(&link)?.file_type().is_symlink(), "{name}");
+            assert_eq!(fs::read_link(&link)?, fs::read_link(src.path().join(name))?);
+        }
+
+        let report = sync(src.path(), dst.path(), SyncOptions::default())?;
+        assert!(report.copied.is_empty(), "{report:?}");
+
+        fs::remove_file(src.path().join("file_link"))?;
+        symlink("real", src.path().join("file_link"))?;
+        let report = sync(src.path(), dst.path(), SyncOptions::default())?;
+        assert_eq!(report.copied, vec![dst.path().join("file_link")]);
+        assert_eq!(fs::read_link(dst.path().join("file_link"))?.to_str(), Some("real"));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn does_not_write_through_a_symlink_replaced_by_a_dir() -> io::Result<()> {
+        use std::os::unix::fs::symlink;
+
+        let outside = tempdir()?;
+        let src = tempdir()?;
+        fs::create_dir(src.path().join("sub"))?;
+        fs::write(src.path().join("sub/a.txt"), b"a")?;
+
+        let dst = tempdir()?;
+        symlink(outside.path(), dst.path().join("sub"))?;
+
+        sync(src.path(), dst.path(), SyncOptions::default())?;
+
+        assert!(fs::symlink_metadata(dst.path().join("sub"))?.is_dir());
+        assert!(!outside.path().join("a.txt").exists());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reports_special_files_as_unsupported() -> io::Result<()> {
+        let src = tempdir()?;
+        let socket = src.path().join("sock");
+        let _listener = std::os::unix::net::UnixListener::bind(&socket)?;
+        fs::write(src.path().join("a.txt"), b"a")?;
+
+        let dst = tempdir()?;
+        let report = sync(src.path(), dst.path(), SyncOptions::default())?;
+
+        assert_eq!(report.unsupported, vec![socket]);
+        assert_eq!(report.copied.len(), 1);
+        assert!(!dst.path().join("sock").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn errors_when_src_is_a_file() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src_file = tmp.path().join("not_a_dir");
+        fs::write(&src_file, b"x")?;
+
+        let dst = tempdir()?;
+        assert!(sync(&src_file, dst.path(), SyncOptions::default()).is_err());
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
-use std::{fs, io, path::Path};
+use {
+    crate::{IoResultExt, Operation},
+    std::{fs, io, path::Path},
+};
 
 #[cfg_attr(test, fs_ext_test_macros::fs_test(rejects_missing_path, rejects_dir, existing_file_ok))]
 pub fn assert_exists(path: impl AsRef<Path>) -> io::Result<()> {
@@ -6,9 +9,7 @@ pub fn assert_exists(path: impl AsRef<Path>) -> io::Result<()> {
 }
 
 fn _assert_exists(path: &Path) -> io::Result<()> {
-    let meta = fs::metadata(path).map_err(|e| {
-        io::Error::new(e.kind(), format!("Failed to access '{}': {e}", path.display()))
-    })?;
+    let meta = fs::metadata(path).with_path_context(Operation::Metadata, path)?;
 
     if !meta.is_file() {
         return Err(io::Error::new(
@@ -1,4 +1,7 @@
-use std::{fs, io, path::Path};
+use {
+    crate::{IoResultExt, Operation},
+    std::{fs, io, path::Path},
+};
 
 #[cfg_attr(test, fs_ext_test_macros::fs_test(rejects_dir, rejects_existing_file))]
 pub fn assert_not_exists(path: impl AsRef<Path>) -> io::Result<()> {
@@ -26,8 +29,6 @@ fn _assert_not_exists(path: &Path) -> io::Result<()> {
         )),
 
         // Any other access error
-        Err(e) => {
-            Err(io::Error::new(e.kind(), format!("Failed to access '{}': {e}", path.display())))
-        }
+        Err(e) => Err(e).with_path_context(Operation::Metadata, path),
     }
 }
@@ -1,5 +1,5 @@
 use {
-    crate::PathExt,
+    crate::{IoResultExt, Operation, PathExt},
     std::{fs, io, path::Path},
 };
 
@@ -11,18 +11,14 @@ pub fn assert_readable(path: impl AsRef<Path>) -> io::Result<()> {
 fn _assert_readable(path: &Path) -> io::Result<()> {
     path.assert_file()?;
 
-    match fs::File::open(path) {
-        Ok(_) => Ok(()),
-        Err(e) => {
-            Err(io::Error::new(e.kind(), format!("File '{}' is not readable: {e}", path.display())))
-        }
-    }
+    fs::File::open(path).map(drop).with_path_context(Operation::Open, path)
 }
 
 #[cfg(test)]
 mod tests {
     use {
         super::assert_readable,
+        crate::{FsError, Operation},
         std::{fs, io},
         tempfile::tempdir,
     };
@@ -53,5 +49,8 @@ mod tests {
 
         let err = assert_readable(&file_path).unwrap_err();
         assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        let fs_err = FsError::downcast_ref(&err).expect("structured error");
+        assert_eq!(fs_err.operation(), Operation::Open);
+        assert_eq!(fs_err.path(), file_path);
     }
 }
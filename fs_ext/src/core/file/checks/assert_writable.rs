@@ -1,5 +1,5 @@
 use {
-    crate::PathExt,
+    crate::{IoResultExt, Operation, PathExt},
     std::{fs, io, path::Path},
 };
 
@@ -11,18 +11,14 @@ pub fn assert_writable(path: impl AsRef<Path>) -> io::Result<()> {
 fn _assert_writable(path: &Path) -> io::Result<()> {
     path.is_file_strict()?;
 
-    match fs::OpenOptions::new().write(true).open(path) {
-        Ok(_) => Ok(()),
-        Err(e) => {
-            Err(io::Error::new(e.kind(), format!("File '{}' is not writable: {e}", path.display())))
-        }
-    }
+    fs::OpenOptions::new().write(true).open(path).map(drop).with_path_context(Operation::Open, path)
 }
 
 #[cfg(test)]
 mod tests {
     use {
         super::assert_writable,
+        crate::{FsError, Operation},
         std::{fs, io},
         tempfile::tempdir,
     };
@@ -53,7 +49,8 @@ mod tests {
 
         let err = assert_writable(&file_path).unwrap_err();
         assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
-        let msg = err.to_string();
-        assert!(msg.contains("is not writable"), "msg={msg}");
+        let fs_err = FsError::downcast_ref(&err).expect("structured error");
+        assert_eq!(fs_err.operation(), Operation::Open);
+        assert_eq!(fs_err.path(), file_path);
     }
 }
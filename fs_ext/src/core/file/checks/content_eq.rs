@@ -0,0 +1,85 @@
+use {
+    crate::{IoResultExt, Operation},
+    std::{
+        fs::File,
+        io::{self, BufReader, Read},
+        path::Path,
+    },
+};
+
+pub fn content_eq(a: impl AsRef<Path>, b: impl AsRef<Path>) -> io::Result<bool> {
+    _content_eq(a.as_ref(), b.as_ref())
+}
+
+fn _content_eq(a: &Path, b: &Path) -> io::Result<bool> {
+    let fa = File::open(a).with_path_context(Operation::Open, a)?;
+    let fb = File::open(b).with_path_context(Operation::Open, b)?;
+
+    let len_a = fa.metadata().with_path_context(Operation::Metadata, a)?.len();
+    let len_b = fb.metadata().with_path_context(Operation::Metadata, b)?.len();
+    if len_a != len_b {
+        return Ok(false);
+    }
+
+    let mut ra = BufReader::new(fa);
+    let mut rb = BufReader::new(fb);
+    let mut buf_a = [0u8; 8192];
+    let mut buf_b = [0u8; 8192];
+
+    loop {
+        let n = ra.read(&mut buf_a).with_path_context(Operation::Read, a)?;
+        if n == 0 {
+            let m = rb.read(&mut buf_b[..1]).with_path_context(Operation::Read, b)?;
+            return Ok(m == 0);
+        }
+
+        match rb.read_exact(&mut buf_b[..n]) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(false),
+            Err(e) => return Err(e).with_path_context(Operation::Read, b),
+        }
+
+        if buf_a[..n] != buf_b[..n] {
+            return Ok(false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::content_eq, std::fs, std::io, tempfile::tempdir};
+
+    #[test]
+    fn equal_files_compare_equal() -> io::Result<()> {
+        let dir = tempdir()?;
+        let big = vec![42u8; 20_000];
+        fs::write(dir.path().join("a"), &big)?;
+        fs::write(dir.path().join("b"), &big)?;
+
+        assert!(content_eq(dir.path().join("a"), dir.path().join("b"))?);
+        Ok(())
+    }
+
+    #[test]
+    fn detects_late_difference_and_size_mismatch() -> io::Result<()> {
+        let dir = tempdir()?;
+        let mut big = vec![42u8; 20_000];
+        fs::write(dir.path().join("a"), &big)?;
+        *big.last_mut().unwrap() = 0;
+        fs::write(dir.path().join("b"), &big)?;
+        fs::write(dir.path().join("c"), &big[..100])?;
+
+        assert!(!content_eq(dir.path().join("a"), dir.path().join("b"))?);
+        assert!(!content_eq(dir.path().join("a"), dir.path().join("c"))?);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("a"), b"x").unwrap();
+
+        let err = content_eq(dir.path().join("a"), dir.path().join("missing")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
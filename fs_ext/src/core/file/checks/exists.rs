@@ -1,4 +1,7 @@
-use std::{fs, io, path::Path};
+use {
+    crate::{IoResultExt, Operation},
+    std::{fs, io, path::Path},
+};
 
 #[cfg_attr(test, fs_ext_test_macros::fs_test(existing_file_ok))]
 pub fn exists(path: impl AsRef<Path>) -> io::Result<bool> {
@@ -9,9 +12,7 @@ fn _exists(path: &Path) -> io::Result<bool> {
     match fs::metadata(path) {
         Ok(meta) => Ok(meta.is_file()),
         Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
-        Err(e) => {
-            Err(io::Error::new(e.kind(), format!("Failed to access '{}': {e}", path.display())))
-        }
+        Err(e) => Err(e).with_path_context(Operation::Metadata, path),
     }
 }
 
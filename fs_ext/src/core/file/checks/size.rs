@@ -1,5 +1,5 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{fs, io, path::Path},
 };
 
@@ -9,7 +9,7 @@ pub fn size(path: impl AsRef<Path>) -> io::Result<u64> {
 }
 
 fn _size(path: &Path) -> io::Result<u64> {
-    let meta = fs::metadata(path).with_path_context("Failed to get metadata", path)?;
+    let meta = fs::metadata(path).with_path_context(Operation::Metadata, path)?;
 
     if !meta.is_file() {
         return Err(io::Error::new(
@@ -0,0 +1,105 @@
+use {
+    crate::{CodecError, Compression, FsError, Operation},
+    std::{
+        io::{self, BufRead, Write},
+        path::Path,
+    },
+};
+
+pub(crate) fn decoder<'a, R>(
+    reader: R, compression: Compression,
+) -> io::Result<Box<dyn BufRead + Send + 'a>>
+where
+    R: BufRead + Send + 'a,
+{
+    match compression {
+        Compression::None => Ok(Box::new(reader)),
+        #[cfg(feature = "gzip")]
+        Compression::Gzip => {
+            Ok(Box::new(io::BufReader::new(flate2::bufread::MultiGzDecoder::new(reader))))
+        }
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => Ok(Box::new(io::BufReader::new(zstd::Decoder::with_buffer(reader)?))),
+        #[allow(unreachable_patterns)]
+        disabled => Err(unsupported(disabled)),
+    }
+}
+
+pub(crate) enum Encoder<W: Write> {
+    Plain(W),
+    #[cfg(feature = "gzip")]
+    Gzip(flate2::write::GzEncoder<W>),
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::Encoder<'static, W>),
+}
+
+impl<W: Write> Encoder<W> {
+    pub(crate) fn new(writer: W, compression: Compression) -> io::Result<Self> {
+        match compression {
+            Compression::None => Ok(Encoder::Plain(writer)),
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => Ok(Encoder::Gzip(flate2::write::GzEncoder::new(
+                writer,
+                flate2::Compression::default(),
+            ))),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Ok(Encoder::Zstd(zstd::Encoder::new(writer, 0)?)),
+            #[allow(unreachable_patterns)]
+            disabled => Err(unsupported(disabled)),
+        }
+    }
+
+    // Writes the compressed stream's trailer; dropping an encoder without
+    // finishing it leaves a truncated file.
+    pub(crate) fn finish(self) -> io::Result<W> {
+        let mut writer = match self {
+            Encoder::Plain(w) => w,
+            #[cfg(feature = "gzip")]
+            Encoder::Gzip(e) => e.finish()?,
+            #[cfg(feature = "zstd")]
+            Encoder::Zstd(e) => e.finish()?,
+        };
+        writer.flush()?;
+        Ok(writer)
+    }
+}
+
+impl<W: Write> Write for Encoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Encoder::Plain(w) => w.write(buf),
+            #[cfg(feature = "gzip")]
+            Encoder::Gzip(e) => e.write(buf),
+            #[cfg(feature = "zstd")]
+            Encoder::Zstd(e) => e.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Encoder::Plain(w) => w.flush(),
+            #[cfg(feature = "gzip")]
+            Encoder::Gzip(e) => e.flush(),
+            #[cfg(feature = "zstd")]
+            Encoder::Zstd(e) => e.flush(),
+        }
+    }
+}
+
+// Decoded input has no file offset to point back to, so only the path is
+// attached to parse errors.
+pub(crate) fn with_read_context(e: CodecError, path: &Path) -> CodecError {
+    match e {
+        CodecError::Deserialize(e) => CodecError::Deserialize(e.with_path(path)),
+        CodecError::Io(e) => CodecError::Io(FsError::new(Operation::Read, path, e).into()),
+        other => other,
+    }
+}
+
+fn unsupported(compression: Compression) -> io::Error {
+    let name = format!("{compression:?}").to_ascii_lowercase();
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("{name}-compressed data requires the `{name}` feature"),
+    )
+}
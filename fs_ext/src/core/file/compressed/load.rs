@@ -0,0 +1,53 @@
+use {
+    super::codec::with_read_context,
+    crate::{CodecError, Format, file::compressed},
+    serde::de::DeserializeOwned,
+    std::path::Path,
+};
+
+pub fn load<T, F>(path: impl AsRef<Path>) -> Result<T, CodecError>
+where
+    F: Format,
+    T: DeserializeOwned,
+{
+    let path = path.as_ref();
+    F::from_reader(compressed::open(path)?).map_err(|e| with_read_context(e, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::formats::Json,
+        serde::{Deserialize, Serialize},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Entry {
+        id: u32,
+    }
+
+    #[test]
+    fn loads_plain_files_too() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("entry.json");
+        fs::write(&path, r#"{"id":1}"#).unwrap();
+
+        assert_eq!(load::<Entry, Json>(&path).unwrap(), Entry { id: 1 });
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn parse_errors_name_the_compressed_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("entry.json.gz");
+        crate::file::compressed::write(&path, "{\"id\":").unwrap();
+
+        let Err(CodecError::Deserialize(e)) = load::<Entry, Json>(&path) else {
+            panic!("expected deserialize error")
+        };
+        assert_eq!(e.diagnostic().path.as_deref(), Some(path.as_path()));
+    }
+}
@@ -0,0 +1,68 @@
+use {
+    super::codec::with_read_context,
+    crate::{CodecError, Compression, FormatRegistry, file::compressed},
+    serde::de::DeserializeOwned,
+    std::path::{Path, PathBuf},
+};
+
+pub fn load_auto<T>(path: impl AsRef<Path>) -> Result<T, CodecError>
+where
+    T: DeserializeOwned,
+{
+    let path = path.as_ref();
+    let handler = FormatRegistry::global().resolve(&inner_path(path))?;
+    handler.read(&mut compressed::open(path)?).map_err(|e| with_read_context(e, path))
+}
+
+// `cache.json.gz` is resolved as `cache.json`.
+pub(crate) fn inner_path(path: &Path) -> PathBuf {
+    match Compression::from_path(path) {
+        Compression::None => path.to_owned(),
+        _ => path.with_extension(""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde::{Deserialize, Serialize},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Cache {
+        hits: u64,
+    }
+
+    #[test]
+    fn strips_the_compression_extension_to_pick_the_format() {
+        assert_eq!(inner_path(Path::new("a/cache.json.gz")), Path::new("a/cache.json"));
+        assert_eq!(inner_path(Path::new("a/cache.toml.ZST")), Path::new("a/cache.toml"));
+        assert_eq!(inner_path(Path::new("a/cache.json")), Path::new("a/cache.json"));
+    }
+
+    #[test]
+    fn loads_by_inner_extension() {
+        let dir = tempdir().unwrap();
+        let plain = dir.path().join("cache.toml");
+        fs::write(&plain, "hits = 2\n").unwrap();
+        assert_eq!(load_auto::<Cache>(&plain).unwrap(), Cache { hits: 2 });
+
+        let name = if cfg!(feature = "gzip") { "cache.json.gz" } else { "cache.json.zst" };
+        let path = dir.path().join(name);
+        compressed::save_auto(&path, &Cache { hits: 9 }).unwrap();
+        assert_eq!(load_auto::<Cache>(&path).unwrap(), Cache { hits: 9 });
+    }
+
+    #[test]
+    fn unknown_inner_extension_is_rejected() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("cache.unknown.gz");
+        fs::write(&path, "").unwrap();
+
+        let err = load_auto::<Cache>(&path).unwrap_err();
+        assert!(err.to_string().contains("unsupported extension 'unknown'"), "{err}");
+    }
+}
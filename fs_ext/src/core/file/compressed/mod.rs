@@ -0,0 +1,18 @@
+mod codec;
+mod load;
+mod load_auto;
+mod open;
+mod overwrite;
+mod read_bytes;
+mod read_string;
+mod save;
+mod save_auto;
+mod stream_bytes;
+mod stream_lines;
+mod write;
+
+pub use {
+    load::load, load_auto::load_auto, open::open, overwrite::overwrite, read_bytes::read_bytes,
+    read_string::read_string, save::save, save_auto::save_auto, stream_bytes::stream_bytes,
+    stream_lines::stream_lines, write::write,
+};
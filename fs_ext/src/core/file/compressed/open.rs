@@ -0,0 +1,70 @@
+use {
+    super::codec::decoder,
+    crate::{Compression, FsError, IoResultExt, Operation},
+    std::{
+        fs::File,
+        io::{self, BufRead, BufReader},
+        path::Path,
+    },
+};
+
+pub fn open(path: impl AsRef<Path>) -> io::Result<Box<dyn BufRead + Send>> {
+    _open(path.as_ref())
+}
+
+// The extension decides when it names a codec; otherwise the first bytes do,
+// so plain files read through unchanged.
+fn _open(path: &Path) -> io::Result<Box<dyn BufRead + Send>> {
+    let file = File::open(path).with_path_context(Operation::Open, path)?;
+    let mut reader = BufReader::new(file);
+
+    let compression = match Compression::from_path(path) {
+        Compression::None => {
+            Compression::from_magic(reader.fill_buf().with_path_context(Operation::Read, path)?)
+        }
+        by_extension => by_extension,
+    };
+
+    decoder(reader, compression).map_err(|e| FsError::new(Operation::Open, path, e).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::{fs, io::Read},
+        tempfile::tempdir,
+    };
+
+    #[test]
+    fn plain_files_read_through() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("plain.txt");
+        fs::write(&path, "hello").unwrap();
+
+        let mut out = String::new();
+        open(&path).unwrap().read_to_string(&mut out).unwrap();
+        assert_eq!(out, "hello");
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn compressed_content_is_sniffed_without_extension() {
+        let dir = tempdir().unwrap();
+        let gz = dir.path().join("data.gz");
+        crate::file::compressed::write(&gz, "sniffed").unwrap();
+        let renamed = dir.path().join("data.bin");
+        fs::rename(&gz, &renamed).unwrap();
+
+        let mut out = String::new();
+        open(&renamed).unwrap().read_to_string(&mut out).unwrap();
+        assert_eq!(out, "sniffed");
+    }
+
+    #[test]
+    fn missing_file_is_an_open_error() {
+        let dir = tempdir().unwrap();
+        let err = open(dir.path().join("missing.gz")).err().unwrap();
+        assert_eq!(FsError::downcast_ref(&err).unwrap().operation(), Operation::Open);
+    }
+}
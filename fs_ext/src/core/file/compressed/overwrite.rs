@@ -0,0 +1,57 @@
+use {
+    super::codec::Encoder,
+    crate::{BoxError, Compression, file},
+    std::{
+        error,
+        io::{self, BufWriter, Write},
+        path::Path,
+    },
+};
+
+pub fn overwrite<F, T, E>(path: impl AsRef<Path>, write_fn: F) -> io::Result<T>
+where
+    E: Into<Box<dyn error::Error + Send + Sync>>,
+    F: FnOnce(&mut dyn Write) -> Result<T, E>,
+{
+    let path = path.as_ref();
+    let compression = Compression::from_path(path);
+
+    file::atomic::overwrite(path, |file| -> Result<T, BoxError> {
+        let mut encoder = Encoder::new(BufWriter::new(file), compression)?;
+        let val = write_fn(&mut encoder).map_err(Into::into)?;
+        encoder.finish()?;
+        Ok(val)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_utils::CustomError, std::fs, tempfile::tempdir};
+
+    #[test]
+    fn writes_plain_files_without_a_compression_extension() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("out.txt");
+
+        overwrite(&path, |w| w.write_all(b"plain")).unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), b"plain");
+    }
+
+    #[test]
+    fn failed_write_keeps_the_previous_file() {
+        let dir = tempdir().unwrap();
+        let name = if cfg!(feature = "gzip") { "out.log.gz" } else { "out.log.zst" };
+        let path = dir.path().join(name);
+        crate::file::compressed::write(&path, "old").unwrap();
+
+        let err = overwrite(&path, |w| {
+            w.write_all(b"partial").unwrap();
+            Err::<(), _>(CustomError("boom"))
+        })
+        .unwrap_err();
+
+        assert!(err.to_string().contains("boom"));
+        assert_eq!(crate::file::compressed::read_string(&path).unwrap(), "old");
+    }
+}
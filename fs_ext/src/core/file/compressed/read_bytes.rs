@@ -0,0 +1,40 @@
+use {
+    crate::{IoResultExt, Operation, file::compressed},
+    std::{io, io::Read, path::Path},
+};
+
+#[cfg_attr(test, fs_ext_test_macros::fs_test(rejects_missing_path, rejects_dir))]
+pub fn read_bytes(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    _read_bytes(path.as_ref())
+}
+
+fn _read_bytes(path: &Path) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    compressed::open(path)?.read_to_end(&mut bytes).with_path_context(Operation::Read, path)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::read_bytes, std::fs, tempfile::tempdir};
+
+    #[test]
+    fn reads_plain_files_unchanged() {
+        let dir = tempdir().unwrap();
+        let plain = dir.path().join("a.bin");
+        fs::write(&plain, [1, 2, 3]).unwrap();
+
+        assert_eq!(read_bytes(&plain).unwrap(), [1, 2, 3]);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn decompresses_zstd_by_extension() {
+        let dir = tempdir().unwrap();
+        let zst = dir.path().join("a.bin.zst");
+        crate::file::compressed::write(&zst, [4, 5, 6]).unwrap();
+
+        assert_ne!(fs::read(&zst).unwrap(), [4, 5, 6]);
+        assert_eq!(read_bytes(&zst).unwrap(), [4, 5, 6]);
+    }
+}
@@ -0,0 +1,41 @@
+use {
+    crate::{IoResultExt, Operation, file::compressed},
+    std::{io, io::Read, path::Path},
+};
+
+#[cfg_attr(test, fs_ext_test_macros::fs_test(rejects_missing_path, rejects_dir))]
+pub fn read_string(path: impl AsRef<Path>) -> io::Result<String> {
+    _read_string(path.as_ref())
+}
+
+fn _read_string(path: &Path) -> io::Result<String> {
+    let mut s = String::new();
+    compressed::open(path)?.read_to_string(&mut s).with_path_context(Operation::Read, path)?;
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::read_string, std::fs, tempfile::tempdir};
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn decompresses_gzip_by_extension() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("app.log.gz");
+        crate::file::compressed::write(&path, "line 1\nline 2\n").unwrap();
+
+        assert_eq!(&fs::read(&path).unwrap()[..2], [0x1f, 0x8b]);
+        assert_eq!(read_string(&path).unwrap(), "line 1\nline 2\n");
+    }
+
+    #[test]
+    fn invalid_utf8_is_invalid_data() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("bad.txt");
+        fs::write(&path, [0xff, 0xfe, 0x00]).unwrap();
+
+        let err = read_string(&path).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,41 @@
+use {
+    crate::{CodecError, Format, file::compressed},
+    serde::Serialize,
+    std::path::Path,
+};
+
+pub fn save<T, F>(path: impl AsRef<Path>, model: T) -> Result<(), CodecError>
+where
+    F: Format,
+    T: Serialize,
+{
+    compressed::overwrite(path, |w| F::to_writer(w, model)).map_err(CodecError::from_io)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{file, formats::Yaml},
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Entry {
+        id: u32,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn roundtrips_with_load() {
+        let dir = tempdir().unwrap();
+        let name = if cfg!(feature = "zstd") { "entry.yaml.zst" } else { "entry.yaml.gz" };
+        let path = dir.path().join(name);
+        let entry = Entry { id: 3, tags: vec!["a".into()] };
+
+        save::<_, Yaml>(&path, &entry).unwrap();
+
+        assert_eq!(file::compressed::load::<Entry, Yaml>(&path).unwrap(), entry);
+    }
+}
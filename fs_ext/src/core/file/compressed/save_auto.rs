@@ -0,0 +1,45 @@
+use {
+    super::load_auto::inner_path,
+    crate::{CodecError, FormatRegistry, file::compressed},
+    serde::Serialize,
+    std::path::Path,
+};
+
+pub fn save_auto<T>(path: impl AsRef<Path>, model: &T) -> Result<(), CodecError>
+where
+    T: Serialize,
+{
+    let path = path.as_ref();
+    let handler = FormatRegistry::global().resolve(&inner_path(path))?;
+    compressed::overwrite(path, |w| handler.write(w, model)).map_err(CodecError::from_io)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{Compression, Format, formats::Toml},
+        serde::{Deserialize, Serialize},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Cache {
+        hits: u64,
+    }
+
+    #[test]
+    fn writes_the_inner_format_compressed() {
+        let dir = tempdir().unwrap();
+        let name = if cfg!(feature = "zstd") { "cache.toml.zst" } else { "cache.toml.gz" };
+        let path = dir.path().join(name);
+
+        save_auto(&path, &Cache { hits: 4 }).unwrap();
+
+        let raw = fs::read(&path).unwrap();
+        assert_eq!(Compression::from_magic(&raw), Compression::from_path(&path));
+        let text = compressed::read_string(&path).unwrap();
+        assert_eq!(Toml::parse_str::<Cache>(&text).unwrap(), Cache { hits: 4 });
+    }
+}
@@ -0,0 +1,61 @@
+use {
+    crate::{FsError, Operation, file::compressed},
+    std::{
+        io::{self, Read},
+        iter,
+        path::Path,
+    },
+};
+
+pub fn stream_bytes(
+    path: impl AsRef<Path>, chunk_size: usize,
+) -> io::Result<impl Iterator<Item = io::Result<Vec<u8>>>> {
+    if chunk_size == 0 {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "chunk_size must be > 0"));
+    }
+
+    let path = path.as_ref().to_owned();
+    let mut reader = compressed::open(&path)?;
+
+    // Decoders hand out short reads, so each chunk is filled up to `chunk_size`.
+    Ok(iter::from_fn(move || {
+        let mut chunk = Vec::with_capacity(chunk_size);
+        match reader.by_ref().take(chunk_size as u64).read_to_end(&mut chunk) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(chunk)),
+            Err(e) => Some(Err(FsError::new(Operation::Read, &path, e).into())),
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::stream_bytes,
+        std::{fs, io},
+        tempfile::tempdir,
+    };
+
+    #[test]
+    fn zero_chunk_size_is_invalid() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("data.bin");
+        fs::write(&file, b"abc").unwrap();
+
+        let err = stream_bytes(&file, 0).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn yields_full_chunks_of_decompressed_data() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("data.bin.zst");
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        crate::file::compressed::write(&file, &data).unwrap();
+
+        let chunks: Vec<Vec<u8>> = stream_bytes(&file, 4096).unwrap().map(Result::unwrap).collect();
+        assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), [4096, 4096, 1808]);
+        assert_eq!(chunks.concat(), data);
+    }
+}
@@ -0,0 +1,41 @@
+use {
+    crate::file::compressed,
+    std::{io, io::BufRead, path::Path},
+};
+
+pub fn stream_lines(
+    path: impl AsRef<Path>,
+) -> io::Result<impl Iterator<Item = io::Result<String>>> {
+    Ok(compressed::open(path)?.lines())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::stream_lines, std::fs, tempfile::tempdir};
+
+    #[test]
+    fn streams_plain_lines() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("data.txt");
+        fs::write(&file, "alpha\nbeta\n").unwrap();
+
+        let lines: Result<Vec<_>, _> = stream_lines(&file).unwrap().collect();
+        assert_eq!(lines.unwrap(), vec!["alpha", "beta"]);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn streams_concatenated_gzip_members() {
+        let dir = tempdir().unwrap();
+        let (a, b) = (dir.path().join("a.gz"), dir.path().join("b.gz"));
+        crate::file::compressed::write(&a, "one\ntwo\n").unwrap();
+        crate::file::compressed::write(&b, "three\n").unwrap();
+
+        // Rotated logs are often `cat`-ed together; every member must be read.
+        let joined = dir.path().join("all.log.gz");
+        fs::write(&joined, [fs::read(&a).unwrap(), fs::read(&b).unwrap()].concat()).unwrap();
+
+        let lines: Result<Vec<_>, _> = stream_lines(&joined).unwrap().collect();
+        assert_eq!(lines.unwrap(), vec!["one", "two", "three"]);
+    }
+}
@@ -0,0 +1,31 @@
+use {
+    crate::file::compressed,
+    std::{io, path::Path},
+};
+
+pub fn write(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    compressed::overwrite(path, |w| w.write_all(contents.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::file, std::fs, tempfile::tempdir};
+
+    #[test]
+    fn roundtrips_through_every_enabled_codec() {
+        let dir = tempdir().unwrap();
+        let text = "repeated line\n".repeat(100);
+
+        for name in ["plain.txt", "a.txt.gz", "a.txt.zst"] {
+            let path = dir.path().join(name);
+            if !crate::Compression::from_path(&path).is_enabled() {
+                continue;
+            }
+            write(&path, &text).unwrap();
+            assert_eq!(file::compressed::read_string(&path).unwrap(), text, "{name}");
+            if name != "plain.txt" {
+                assert!(fs::metadata(&path).unwrap().len() < text.len() as u64, "{name}");
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
-use std::{
-    fs::{File, OpenOptions},
-    io,
-    path::Path,
+use {
+    crate::{IoResultExt, Operation},
+    std::{
+        fs::{File, OpenOptions},
+        io,
+        path::Path,
+    },
 };
 
 #[cfg_attr(test, fs_ext_test_macros::fs_test(rejects_dir, rejects_existing_file))]
@@ -10,9 +13,11 @@ pub fn create_new(path: impl AsRef<Path>) -> io::Result<File> {
 }
 
 fn _create_new(path: &Path) -> io::Result<File> {
-    OpenOptions::new().write(true).create_new(true).open(path).map_err(|e| {
-        io::Error::new(e.kind(), format!("Failed to create file at '{}': {e}", path.display()))
-    })
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .with_path_context(Operation::Create, path)
 }
 
 #[cfg(test)]
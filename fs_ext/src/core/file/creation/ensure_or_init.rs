@@ -1,7 +1,10 @@
-use std::{
-    fs::{File, OpenOptions},
-    io::{self, Write},
-    path::Path,
+use {
+    crate::{IoResultExt, Operation},
+    std::{
+        fs::{File, OpenOptions},
+        io::{self, Write},
+        path::Path,
+    },
 };
 
 pub fn ensure_or_init(path: impl AsRef<Path>, content: impl AsRef<[u8]>) -> io::Result<File> {
@@ -12,26 +15,17 @@ fn _ensure_or_init(path: &Path, content: &[u8]) -> io::Result<File> {
     match OpenOptions::new().write(true).open(path) {
         Ok(file) => Ok(file),
         Err(e) if e.kind() == io::ErrorKind::NotFound => {
-            let mut file = OpenOptions::new().write(true).create(true).open(path).map_err(|e| {
-                io::Error::new(
-                    e.kind(),
-                    format!("Failed to create file at '{}': {e}", path.display()),
-                )
-            })?;
-
-            file.write_all(content.as_ref()).map_err(|e| {
-                io::Error::new(
-                    e.kind(),
-                    format!("Failed to write to file at '{}': {e}", path.display()),
-                )
-            })?;
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .open(path)
+                .with_path_context(Operation::Create, path)?;
+
+            file.write_all(content.as_ref()).with_path_context(Operation::Write, path)?;
 
             Ok(file)
         }
-        Err(e) => Err(io::Error::new(
-            e.kind(),
-            format!("Failed to open file at '{}': {e}", path.display()),
-        )),
+        Err(e) => Err(e).with_path_context(Operation::Open, path),
     }
 }
 
@@ -1,7 +1,10 @@
-use std::{
-    fs::{File, OpenOptions},
-    io::{self, Write},
-    path::Path,
+use {
+    crate::{IoResultExt, Operation},
+    std::{
+        fs::{File, OpenOptions},
+        io::{self, Write},
+        path::Path,
+    },
 };
 
 pub fn ensure_or_init_with<F, C>(path: impl AsRef<Path>, content_fn: F) -> io::Result<File>
@@ -18,27 +21,18 @@ where
             let content = content_fn();
             let bytes = content.as_ref();
 
-            let mut file = OpenOptions::new().write(true).create(true).open(path).map_err(|e| {
-                io::Error::new(
-                    e.kind(),
-                    format!("Failed to create file at '{}': {e}", path.display()),
-                )
-            })?;
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .open(path)
+                .with_path_context(Operation::Create, path)?;
 
-            file.write_all(bytes).map_err(|e| {
-                io::Error::new(
-                    e.kind(),
-                    format!("Failed to write to file at '{}': {e}", path.display()),
-                )
-            })?;
+            file.write_all(bytes).with_path_context(Operation::Write, path)?;
 
             Ok(file)
         }
 
-        Err(e) => Err(io::Error::new(
-            e.kind(),
-            format!("Failed to open file at '{}': {e}", path.display()),
-        )),
+        Err(e) => Err(e).with_path_context(Operation::Open, path),
     }
 }
 
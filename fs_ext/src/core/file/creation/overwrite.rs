@@ -1,7 +1,10 @@
-use std::{
-    fs::{File, OpenOptions},
-    io,
-    path::Path,
+use {
+    crate::{IoResultExt, Operation},
+    std::{
+        fs::{File, OpenOptions},
+        io,
+        path::Path,
+    },
 };
 
 #[cfg_attr(test, fs_ext_test_macros::fs_test(existing_file_ok, rejects_dir, new_file_ok))]
@@ -10,12 +13,12 @@ pub fn overwrite(path: impl AsRef<Path>) -> io::Result<File> {
 }
 
 fn _overwrite(path: &Path) -> io::Result<File> {
-    OpenOptions::new().write(true).create(true).truncate(true).open(path).map_err(|e| {
-        io::Error::new(
-            e.kind(),
-            format!("Failed to open or overwrite file at '{}': {e}", path.display()),
-        )
-    })
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+        .with_path_context(Operation::Write, path)
 }
 
 #[cfg(test)]
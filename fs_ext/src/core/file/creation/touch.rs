@@ -1,4 +1,5 @@
 use {
+    crate::{IoResultExt, Operation},
     filetime::FileTime,
     std::{
         fs::{File, OpenOptions},
@@ -14,20 +15,14 @@ pub fn touch(path: impl AsRef<Path>) -> io::Result<File> {
 }
 
 fn _touch(path: &Path) -> io::Result<File> {
-    let file = OpenOptions::new().write(true).create(true).open(path).map_err(|e| {
-        io::Error::new(
-            e.kind(),
-            format!("Failed to open or create file at '{}': {e}", path.display()),
-        )
-    })?;
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .open(path)
+        .with_path_context(Operation::Create, path)?;
 
     let now = FileTime::from_system_time(SystemTime::now());
-    filetime::set_file_times(path, now, now).map_err(|e| {
-        io::Error::new(
-            e.kind(),
-            format!("Failed to update file atime & mtime for '{}': {e}", path.display()),
-        )
-    })?;
+    filetime::set_file_times(path, now, now).with_path_context(Operation::SetTimes, path)?;
 
     Ok(file)
 }
@@ -0,0 +1,231 @@
+use {
+    super::in_place::edit_in_place,
+    crate::{CodecError, Format, SerializeError, formats::Json},
+    serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{MapAccess, SeqAccess, Visitor},
+        ser::{SerializeMap, SerializeSeq},
+    },
+    serde_json::{Value, ser::PrettyFormatter},
+    std::{collections::HashSet, fmt, path::Path},
+};
+
+pub fn edit_json<R>(
+    path: impl AsRef<Path>, f: impl FnOnce(&mut Value) -> R,
+) -> Result<R, CodecError> {
+    edit_in_place(path.as_ref(), |src| {
+        let mut value = Json::parse_str::<Value>(src)?;
+        let order = Json::parse_str::<KeyOrder>(src)?;
+        let ret = f(&mut value);
+
+        // Keep the document's indentation unit (or compactness) and trailing newline.
+        let ordered = Ordered(&value, &order);
+        let mut out = Vec::new();
+        match indent_unit(src) {
+            Some(indent) => {
+                let mut ser = serde_json::Serializer::with_formatter(
+                    &mut out,
+                    PrettyFormatter::with_indent(indent.as_bytes()),
+                );
+                ordered.serialize(&mut ser).map_err(SerializeError::Json)?;
+            }
+            None => serde_json::to_writer(&mut out, &ordered).map_err(SerializeError::Json)?,
+        }
+        if src.ends_with('\n') {
+            out.push(b'\n');
+        }
+
+        let out = String::from_utf8(out).map_err(|e| SerializeError::other("JSON", e))?;
+        Ok((out, ret))
+    })
+}
+
+// The key order of every object in the source. `Value`'s map is sorted unless
+// something in the build enables `serde_json/preserve_order`, so the order is
+// kept on the side instead of relying on that feature.
+#[derive(Debug, Default)]
+enum KeyOrder {
+    #[default]
+    Scalar,
+    Object(Vec<(String, KeyOrder)>),
+    Array(Vec<KeyOrder>),
+}
+
+impl<'de> Deserialize<'de> for KeyOrder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(KeyOrderVisitor)
+    }
+}
+
+struct KeyOrderVisitor;
+
+impl<'de> Visitor<'de> for KeyOrderVisitor {
+    type Value = KeyOrder;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<KeyOrder, E> {
+        Ok(KeyOrder::Scalar)
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<KeyOrder, E> {
+        Ok(KeyOrder::Scalar)
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<KeyOrder, E> {
+        Ok(KeyOrder::Scalar)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<KeyOrder, E> {
+        Ok(KeyOrder::Scalar)
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<KeyOrder, E> {
+        Ok(KeyOrder::Scalar)
+    }
+
+    fn visit_unit<E>(self) -> Result<KeyOrder, E> {
+        Ok(KeyOrder::Scalar)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<KeyOrder, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(KeyOrder::Array(items))
+    }
+
+    // A repeated key keeps its first position, like `preserve_order` does.
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<KeyOrder, A::Error> {
+        let mut keys: Vec<(String, KeyOrder)> = Vec::new();
+        while let Some((key, order)) = map.next_entry::<String, KeyOrder>()? {
+            match keys.iter_mut().find(|(k, _)| *k == key) {
+                Some(slot) => slot.1 = order,
+                None => keys.push((key, order)),
+            }
+        }
+        Ok(KeyOrder::Object(keys))
+    }
+}
+
+// Writes the keys that were in the source in their original order, then any
+// keys the edit added in the map's own order.
+struct Ordered<'a>(&'a Value, &'a KeyOrder);
+
+impl Serialize for Ordered<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match (self.0, self.1) {
+            (Value::Object(map), KeyOrder::Object(keys)) => {
+                let mut out = serializer.serialize_map(Some(map.len()))?;
+                for (key, order) in keys {
+                    if let Some(value) = map.get(key) {
+                        out.serialize_entry(key, &Ordered(value, order))?;
+                    }
+                }
+                let known: HashSet<&str> = keys.iter().map(|(k, _)| k.as_str()).collect();
+                for (key, value) in map.iter().filter(|(k, _)| !known.contains(k.as_str())) {
+                    out.serialize_entry(key, value)?;
+                }
+                out.end()
+            }
+            (Value::Array(items), KeyOrder::Array(orders)) => {
+                let mut out = serializer.serialize_seq(Some(items.len()))?;
+                for (i, item) in items.iter().enumerate() {
+                    let order = orders.get(i).unwrap_or(&KeyOrder::Scalar);
+                    out.serialize_element(&Ordered(item, order))?;
+                }
+                out.end()
+            }
+            (value, _) => value.serialize(serializer),
+        }
+    }
+}
+
+// The first indented line sits one level deep, so its leading whitespace is
+// the unit. Documents without any line breaks are kept compact.
+fn indent_unit(src: &str) -> Option<&str> {
+    src.lines().skip(1).find_map(|line| {
+        let content = line.trim_start();
+        let indent = &line[..line.len() - content.len()];
+        (!content.is_empty() && !indent.is_empty()).then_some(indent)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json, std::fs, tempfile::tempdir};
+
+    #[test]
+    fn preserves_key_order_and_indentation() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("package.json");
+        fs::write(
+            &p,
+            "{\n    \"name\": \"demo\",\n    \"version\": \"1.0.0\",\n    \"deps\": {}\n}\n",
+        )
+        .unwrap();
+
+        edit_json(&p, |v| {
+            v["version"] = json!("1.1.0");
+            v["deps"]["serde"] = json!("1");
+        })
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&p).unwrap(),
+            "{\n    \"name\": \"demo\",\n    \"version\": \"1.1.0\",\n    \"deps\": {\n        \"serde\": \"1\"\n    }\n}\n"
+        );
+    }
+
+    #[test]
+    fn keeps_compact_documents_compact() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("c.json");
+        fs::write(&p, r#"{"b":1,"a":2}"#).unwrap();
+
+        let old = edit_json(&p, |v| v["a"].take()).unwrap();
+
+        assert_eq!(old, json!(2));
+        assert_eq!(fs::read_to_string(&p).unwrap(), r#"{"b":1,"a":null}"#);
+    }
+
+    #[test]
+    fn tab_indentation_is_kept() {
+        assert_eq!(indent_unit("{\n\t\"a\": 1\n}"), Some("\t"));
+        assert_eq!(indent_unit("{\n\n  \"a\": [\n    1\n  ]\n}"), Some("  "));
+        assert_eq!(indent_unit("[1, 2]"), None);
+    }
+
+    #[test]
+    fn keeps_source_order_of_nested_keys_and_appends_new_ones() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("order.json");
+        fs::write(&p, r#"{"z":1,"list":[{"y":1,"b":2}],"a":{"q":1,"c":2}}"#).unwrap();
+
+        edit_json(&p, |v| {
+            v["list"][0]["a"] = json!(0);
+            v["a"].as_object_mut().unwrap().remove("q");
+            v["m"] = json!(true);
+        })
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&p).unwrap(),
+            r#"{"z":1,"list":[{"y":1,"b":2,"a":0}],"a":{"c":2},"m":true}"#
+        );
+    }
+
+    #[test]
+    fn repeated_keys_are_written_once() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("dup.json");
+        fs::write(&p, r#"{"b":1,"a":2,"b":3}"#).unwrap();
+
+        edit_json(&p, |_| {}).unwrap();
+
+        assert_eq!(fs::read_to_string(&p).unwrap(), r#"{"b":3,"a":2}"#);
+    }
+}
@@ -0,0 +1,87 @@
+use {
+    super::in_place::edit_in_place,
+    crate::{CodecError, DeserializeError, Diagnostic},
+    std::path::Path,
+    toml_edit::DocumentMut,
+};
+
+pub fn edit_toml<R>(
+    path: impl AsRef<Path>, f: impl FnOnce(&mut DocumentMut) -> R,
+) -> Result<R, CodecError> {
+    edit_in_place(path.as_ref(), |src| {
+        let mut doc = src.parse::<DocumentMut>().map_err(|e| {
+            let offset = e.span().map_or(0, |span| span.start);
+            let diagnostic = Diagnostic::from_offset("TOML", src, offset, e.message().trim_end());
+            DeserializeError::other(diagnostic, e)
+        })?;
+
+        let ret = f(&mut doc);
+        Ok((doc.to_string(), ret))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::fs, tempfile::tempdir, toml_edit::value};
+
+    const SRC: &str = "\
+# Service settings
+[server]
+host = \"localhost\"   # bind address
+port = 8080
+
+[client]
+retries = 3
+";
+
+    #[test]
+    fn preserves_comments_order_and_formatting() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("app.toml");
+        fs::write(&p, SRC).unwrap();
+
+        let old = edit_toml(&p, |doc| {
+            let old = doc["server"]["port"].as_integer();
+            doc["server"]["port"] = value(9090);
+            doc["client"]["timeout"] = value(30);
+            old
+        })
+        .unwrap();
+
+        assert_eq!(old, Some(8080));
+        assert_eq!(
+            fs::read_to_string(&p).unwrap(),
+            "\
+# Service settings
+[server]
+host = \"localhost\"   # bind address
+port = 9090
+
+[client]
+retries = 3
+timeout = 30
+"
+        );
+    }
+
+    #[test]
+    fn invalid_toml_is_reported_with_path_and_file_is_untouched() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("bad.toml");
+        fs::write(&p, "a = 1\nb = \n").unwrap();
+
+        let Err(CodecError::Deserialize(e)) = edit_toml(&p, |_| ()) else {
+            panic!("expected deserialize error")
+        };
+        assert_eq!(e.diagnostic().line, Some(2));
+        assert_eq!(e.diagnostic().path.as_deref(), Some(p.as_path()));
+        assert_eq!(fs::read_to_string(&p).unwrap(), "a = 1\nb = \n");
+    }
+
+    #[test]
+    fn missing_file_is_an_io_error() {
+        let dir = tempdir().unwrap();
+        let err = edit_toml(dir.path().join("missing.toml"), |_| ()).unwrap_err();
+        assert!(matches!(err, CodecError::Io(_)));
+    }
+}
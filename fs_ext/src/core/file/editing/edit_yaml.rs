@@ -0,0 +1,63 @@
+use {
+    super::in_place::edit_in_place,
+    crate::{CodecError, Format, SerializeError, formats::Yaml},
+    serde_yaml::Value,
+    std::path::Path,
+};
+
+pub fn edit_yaml<R>(
+    path: impl AsRef<Path>, f: impl FnOnce(&mut Value) -> R,
+) -> Result<R, CodecError> {
+    edit_in_place(path.as_ref(), |src| {
+        let mut value = Yaml::parse_str::<Value>(src)?;
+        let ret = f(&mut value);
+
+        // Mappings keep their key order; of the comments, only the leading
+        // header block (and a `---` marker) survives re-serialization.
+        let header: String = src
+            .lines()
+            .take_while(|l| {
+                let l = l.trim();
+                l.is_empty() || l.starts_with('#') || l.starts_with('%') || l == "---"
+            })
+            .map(|l| format!("{l}\n"))
+            .collect();
+
+        let body = serde_yaml::to_string(&value).map_err(SerializeError::Yaml)?;
+        Ok((header + &body, ret))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::fs, tempfile::tempdir};
+
+    #[test]
+    fn preserves_key_order_and_header_comment() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("ci.yaml");
+        fs::write(&p, "# CI settings\n---\nname: build\nzeta: 1\nalpha:\n  - a\n").unwrap();
+
+        edit_yaml(&p, |v| {
+            v["zeta"] = Value::from(2);
+            v["alpha"].as_sequence_mut().unwrap().push("b".into());
+        })
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&p).unwrap(),
+            "# CI settings\n---\nname: build\nzeta: 2\nalpha:\n- a\n- b\n"
+        );
+    }
+
+    #[test]
+    fn invalid_yaml_leaves_file_untouched() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("bad.yaml");
+        fs::write(&p, "a: [1, 2\n").unwrap();
+
+        let err = edit_yaml(&p, |_| ()).unwrap_err();
+        assert!(matches!(err, CodecError::Deserialize(_)));
+        assert_eq!(fs::read_to_string(&p).unwrap(), "a: [1, 2\n");
+    }
+}
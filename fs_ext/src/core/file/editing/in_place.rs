@@ -0,0 +1,30 @@
+use {
+    crate::{CodecError, file},
+    std::{
+        io::{Read, Seek, SeekFrom, Write},
+        path::Path,
+    },
+};
+
+// Reads the current text, lets `edit` produce the replacement, and swaps it in
+// through `atomic::update` so a failed edit leaves the original untouched.
+pub(crate) fn edit_in_place<R>(
+    path: &Path, edit: impl FnOnce(&str) -> Result<(String, R), CodecError>,
+) -> Result<R, CodecError> {
+    file::atomic::update(path, |file| {
+        let mut src = String::new();
+        file.seek(SeekFrom::Start(0))?;
+        file.read_to_string(&mut src)?;
+
+        let (out, ret) = edit(&src)?;
+
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(out.as_bytes())?;
+        Ok::<_, CodecError>(ret)
+    })
+    .map_err(|e| match CodecError::from_io(e) {
+        CodecError::Deserialize(e) => CodecError::Deserialize(e.with_path(path)),
+        other => other,
+    })
+}
@@ -0,0 +1,6 @@
+mod edit_json;
+mod edit_toml;
+mod edit_yaml;
+mod in_place;
+
+pub use {edit_json::edit_json, edit_toml::edit_toml, edit_yaml::edit_yaml};
@@ -0,0 +1,74 @@
+use {
+    super::hasher::{CHUNK_SIZE, Hasher},
+    crate::{Digest, HashAlgorithm, file},
+    std::{io, path::Path},
+};
+
+pub fn hash(path: impl AsRef<Path>, algorithm: HashAlgorithm) -> io::Result<Digest> {
+    _hash(path.as_ref(), algorithm)
+}
+
+fn _hash(path: &Path, algorithm: HashAlgorithm) -> io::Result<Digest> {
+    let mut hasher = Hasher::new(algorithm)?;
+    for chunk in file::stream_bytes(path, CHUNK_SIZE)? {
+        hasher.update(&chunk?);
+    }
+    Ok(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_utils::enabled_hash, std::fs, tempfile::tempdir};
+
+    fn hash_of(content: &[u8], algorithm: HashAlgorithm) -> io::Result<String> {
+        let dir = tempdir()?;
+        let path = dir.path().join("data.bin");
+        fs::write(&path, content)?;
+        Ok(hash(&path, algorithm)?.to_hex())
+    }
+
+    #[cfg(feature = "sha256")]
+    #[test]
+    fn sha256_matches_reference() -> io::Result<()> {
+        assert_eq!(
+            hash_of(b"abc", HashAlgorithm::Sha256)?,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn blake3_matches_reference() -> io::Result<()> {
+        assert_eq!(
+            hash_of(b"", HashAlgorithm::Blake3)?,
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "xxhash")]
+    #[test]
+    fn xxh3_matches_reference() -> io::Result<()> {
+        assert_eq!(hash_of(b"", HashAlgorithm::Xxh3)?, "2d06800538d394c2");
+        Ok(())
+    }
+
+    #[test]
+    fn spans_multiple_chunks() -> io::Result<()> {
+        let algorithm = enabled_hash();
+        let big = vec![7u8; CHUNK_SIZE * 2 + 3];
+        let mut hasher = Hasher::new(algorithm)?;
+        hasher.update(&big);
+
+        assert_eq!(hash_of(&big, algorithm)?, hasher.finalize().to_hex());
+        Ok(())
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        let dir = tempdir().unwrap();
+        let err = hash(dir.path().join("missing"), enabled_hash()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,58 @@
+use {
+    crate::{Digest, HashAlgorithm},
+    std::io,
+};
+
+pub(crate) const CHUNK_SIZE: usize = 64 * 1024;
+
+// The blake3 and xxh3 states are large enough that boxing them keeps the enum
+// cheap to move around.
+pub(crate) enum Hasher {
+    #[cfg(feature = "sha256")]
+    Sha256(sha2::Sha256),
+    #[cfg(feature = "blake3")]
+    Blake3(Box<blake3::Hasher>),
+    #[cfg(feature = "xxhash")]
+    Xxh3(Box<xxhash_rust::xxh3::Xxh3>),
+}
+
+impl Hasher {
+    pub(crate) fn new(algorithm: HashAlgorithm) -> io::Result<Self> {
+        match algorithm {
+            #[cfg(feature = "sha256")]
+            HashAlgorithm::Sha256 => Ok(Hasher::Sha256(<sha2::Sha256 as sha2::Digest>::new())),
+            #[cfg(feature = "blake3")]
+            HashAlgorithm::Blake3 => Ok(Hasher::Blake3(Box::new(blake3::Hasher::new()))),
+            #[cfg(feature = "xxhash")]
+            HashAlgorithm::Xxh3 => Ok(Hasher::Xxh3(Box::new(xxhash_rust::xxh3::Xxh3::new()))),
+            #[allow(unreachable_patterns)]
+            disabled => Err(disabled.unsupported()),
+        }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        match self {
+            #[cfg(feature = "sha256")]
+            Hasher::Sha256(h) => sha2::Digest::update(h, bytes),
+            #[cfg(feature = "blake3")]
+            Hasher::Blake3(h) => {
+                h.update(bytes);
+            }
+            #[cfg(feature = "xxhash")]
+            Hasher::Xxh3(h) => h.update(bytes),
+        }
+    }
+
+    // xxh3 is emitted big-endian, matching `xxhsum` output.
+    pub(crate) fn finalize(self) -> Digest {
+        let (algorithm, bytes) = match self {
+            #[cfg(feature = "sha256")]
+            Hasher::Sha256(h) => (HashAlgorithm::Sha256, sha2::Digest::finalize(h).to_vec()),
+            #[cfg(feature = "blake3")]
+            Hasher::Blake3(h) => (HashAlgorithm::Blake3, h.finalize().as_bytes().to_vec()),
+            #[cfg(feature = "xxhash")]
+            Hasher::Xxh3(h) => (HashAlgorithm::Xxh3, h.digest().to_be_bytes().to_vec()),
+        };
+        Digest { algorithm, bytes }
+    }
+}
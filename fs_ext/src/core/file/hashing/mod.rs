@@ -0,0 +1,11 @@
+mod hash;
+pub(crate) mod hasher;
+mod read_checksums;
+mod verify;
+mod verify_checksums;
+mod write_checksums;
+
+pub use {
+    hash::hash, read_checksums::read_checksums, verify::verify, verify_checksums::verify_checksums,
+    write_checksums::write_checksums,
+};
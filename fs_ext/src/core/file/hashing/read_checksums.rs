@@ -0,0 +1,75 @@
+use {
+    crate::{Digest, HashAlgorithm, IoResultExt, Operation, file},
+    std::{
+        io,
+        path::{Path, PathBuf},
+    },
+};
+
+pub fn read_checksums(
+    path: impl AsRef<Path>, algorithm: HashAlgorithm,
+) -> io::Result<Vec<(PathBuf, Digest)>> {
+    _read_checksums(path.as_ref(), algorithm)
+}
+
+fn _read_checksums(path: &Path, algorithm: HashAlgorithm) -> io::Result<Vec<(PathBuf, Digest)>> {
+    let content = file::read_string(path)?;
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| parse_line(line, algorithm).ok_or_else(|| malformed(i + 1)))
+        .collect::<io::Result<_>>()
+        .with_path_context(Operation::Read, path)
+}
+
+// `<hex>  <name>` in text mode, `<hex> *<name>` in binary mode.
+fn parse_line(line: &str, algorithm: HashAlgorithm) -> Option<(PathBuf, Digest)> {
+    let (hex, rest) = line.split_once(' ')?;
+    let name = rest.strip_prefix(' ').or_else(|| rest.strip_prefix('*'))?;
+    if name.is_empty() {
+        return None;
+    }
+    let digest = Digest::from_hex(algorithm, hex).ok()?;
+    Some((PathBuf::from(name), digest))
+}
+
+fn malformed(line: usize) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("malformed checksum entry on line {line}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_utils::enabled_hash, std::fs, tempfile::tempdir};
+
+    #[test]
+    fn parses_text_and_binary_entries() -> io::Result<()> {
+        let dir = tempdir()?;
+        let algorithm = enabled_hash();
+        let a = "ab".repeat(algorithm.digest_len());
+        let b = "01".repeat(algorithm.digest_len());
+        let sums = dir.path().join("SUMS");
+        fs::write(&sums, format!("# generated\n{a}  dist/app.tar.gz\n\n{b} *notes file.txt\n"))?;
+
+        let entries = read_checksums(&sums, algorithm)?;
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].0, PathBuf::from("dist/app.tar.gz"));
+        assert_eq!(entries[0].1.to_hex(), a);
+        assert_eq!(entries[1].0, PathBuf::from("notes file.txt"));
+        assert_eq!(entries[1].1.to_hex(), b);
+        Ok(())
+    }
+
+    #[test]
+    fn reports_malformed_line() -> io::Result<()> {
+        let dir = tempdir()?;
+        let sums = dir.path().join("SUMS");
+        fs::write(&sums, "not-a-digest  file.txt\n")?;
+
+        let err = read_checksums(&sums, enabled_hash()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("line 1"), "{err}");
+        Ok(())
+    }
+}
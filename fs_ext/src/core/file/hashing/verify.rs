@@ -0,0 +1,27 @@
+use {
+    crate::{Digest, file},
+    std::{io, path::Path},
+};
+
+pub fn verify(path: impl AsRef<Path>, expected: &Digest) -> io::Result<bool> {
+    Ok(file::hash(path, expected.algorithm)?.bytes == expected.bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_utils::enabled_hash, std::fs, tempfile::tempdir};
+
+    #[test]
+    fn detects_changed_content() -> io::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("download.bin");
+        fs::write(&path, b"payload")?;
+
+        let expected = file::hash(&path, enabled_hash())?;
+        assert!(verify(&path, &expected)?);
+
+        fs::write(&path, b"tampered")?;
+        assert!(!verify(&path, &expected)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use {
+    crate::{HashAlgorithm, PathExt, file},
+    std::{
+        io,
+        path::{Path, PathBuf},
+    },
+};
+
+// Names are resolved against the checksum file's directory, like `sha256sum -c`
+// run from there. Returns the entries whose content no longer matches.
+pub fn verify_checksums(
+    path: impl AsRef<Path>, algorithm: HashAlgorithm,
+) -> io::Result<Vec<PathBuf>> {
+    _verify_checksums(path.as_ref(), algorithm)
+}
+
+fn _verify_checksums(path: &Path, algorithm: HashAlgorithm) -> io::Result<Vec<PathBuf>> {
+    let base = path.parent_or_current();
+    let mut mismatched = Vec::new();
+
+    for (name, expected) in file::read_checksums(path, algorithm)? {
+        if !file::verify(base.join(&name), &expected)? {
+            mismatched.push(name);
+        }
+    }
+    Ok(mismatched)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_utils::enabled_hash, std::fs, tempfile::tempdir};
+
+    #[test]
+    fn lists_mismatched_entries() -> io::Result<()> {
+        let dir = tempdir()?;
+        let algorithm = enabled_hash();
+        fs::create_dir(dir.path().join("dist"))?;
+        fs::write(dir.path().join("dist/a.bin"), b"a")?;
+        fs::write(dir.path().join("dist/b.bin"), b"b")?;
+
+        let entries = ["dist/a.bin", "dist/b.bin"]
+            .into_iter()
+            .map(|n| Ok((PathBuf::from(n), file::hash(dir.path().join(n), algorithm)?)))
+            .collect::<io::Result<Vec<_>>>()?;
+        let sums = dir.path().join("SHA256SUMS");
+        file::write_checksums(&sums, &entries)?;
+        assert!(verify_checksums(&sums, algorithm)?.is_empty());
+
+        fs::write(dir.path().join("dist/b.bin"), b"changed")?;
+        assert_eq!(verify_checksums(&sums, algorithm)?, vec![PathBuf::from("dist/b.bin")]);
+        Ok(())
+    }
+}
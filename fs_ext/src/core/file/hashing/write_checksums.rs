@@ -0,0 +1,39 @@
+use {
+    crate::{Digest, file},
+    std::{
+        io::{self, BufWriter, Write},
+        path::{Path, PathBuf},
+    },
+};
+
+pub fn write_checksums(path: impl AsRef<Path>, entries: &[(PathBuf, Digest)]) -> io::Result<()> {
+    file::atomic::overwrite(path, |file| {
+        let mut writer = BufWriter::new(file);
+        for (name, digest) in entries {
+            writeln!(writer, "{digest}  {}", name.to_string_lossy().replace('\\', "/"))?;
+        }
+        writer.flush()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_utils::enabled_hash, std::fs, tempfile::tempdir};
+
+    #[test]
+    fn roundtrips_through_read_checksums() -> io::Result<()> {
+        let dir = tempdir()?;
+        let algorithm = enabled_hash();
+        fs::write(dir.path().join("a.txt"), b"a")?;
+        let entries =
+            vec![(PathBuf::from("a.txt"), file::hash(dir.path().join("a.txt"), algorithm)?)];
+
+        let sums = dir.path().join("SUMS");
+        write_checksums(&sums, &entries)?;
+
+        let content = fs::read_to_string(&sums)?;
+        assert_eq!(content, format!("{}  a.txt\n", entries[0].1));
+        assert_eq!(file::read_checksums(&sums, algorithm)?, entries);
+        Ok(())
+    }
+}
@@ -1,10 +1,7 @@
 use {
-    crate::{
-        CodecError, Format,
-        formats::{Json, Toml, Yaml},
-    },
+    crate::{CodecError, FormatRegistry},
     serde::de::DeserializeOwned,
-    std::{io, path::Path},
+    std::path::Path,
 };
 
 pub fn load_auto<T>(path: impl AsRef<Path>) -> Result<T, CodecError>
@@ -12,31 +9,9 @@ where
     T: DeserializeOwned,
 {
     let path = path.as_ref();
-
-    let ext =
-        path.extension().and_then(|s| s.to_str()).map(|s| s.to_ascii_lowercase()).ok_or_else(
-            || {
-                CodecError::from(io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    format!("path '{}' has no extension", path.display()),
-                ))
-            },
-        )?;
-
-    match ext.as_str() {
-        "toml" => Toml::load::<T>(path),
-        "json" => Json::load::<T>(path),
-        "yaml" | "yml" => Yaml::load::<T>(path),
-
-        other => Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            format!(
-                "unsupported extension '{other}' for '{}'; expected one of: toml, json, yaml/yml",
-                path.display()
-            ),
-        )
-        .into()),
-    }
+    // Resolve under the lock, but release it before touching the filesystem.
+    let handler = FormatRegistry::global().resolve(path)?;
+    handler.load(path)
 }
 
 #[cfg(test)]
@@ -124,7 +99,7 @@ mod tests {
     #[test]
     fn error_on_unsupported_extension() {
         let dir = tempdir().unwrap();
-        let p = dir.path().join("bad.ini");
+        let p = dir.path().join("bad.xyz");
         fs::write(&p, "id=8\nname=theta\n").unwrap();
 
         let err = load_auto::<Demo>(&p).unwrap_err();
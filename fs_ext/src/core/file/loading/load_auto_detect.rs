@@ -0,0 +1,241 @@
+use {
+    crate::{
+        CodecError, DeserializeError, Detected, DetectedBy, Format, FormatRegistry, file, formats,
+    },
+    serde::de::DeserializeOwned,
+    std::path::Path,
+};
+
+type Parser<T> = fn(&str) -> Result<T, DeserializeError>;
+
+pub fn load_auto_detect<T>(path: impl AsRef<Path>) -> Result<Detected<T>, CodecError>
+where
+    T: DeserializeOwned,
+{
+    let path = path.as_ref();
+
+    let handler = FormatRegistry::global().resolve(path);
+    if let Ok(handler) = handler {
+        let value = handler.load(path)?;
+        return Ok(Detected { value, format: handler.name(), by: DetectedBy::Extension });
+    }
+
+    let src = file::read_string(path)?;
+    let mut first_err = None;
+
+    for (format, parse) in candidates::<T>(&src) {
+        match parse(&src) {
+            Ok(value) => return Ok(Detected { value, format, by: DetectedBy::Content }),
+            Err(e) => {
+                first_err.get_or_insert(e);
+            }
+        }
+    }
+
+    // Report the failure of the most likely format rather than the last one tried.
+    Err(first_err.expect("at least one candidate format").with_path(path).into())
+}
+
+// Orders the text formats by how well `src` matches each one's telltale
+// syntax. Unambiguous openers (`{`, a JSON array, `---`, `(`) restrict the
+// candidates; otherwise the remaining formats follow as try-parse fallbacks.
+fn candidates<T>(src: &str) -> Vec<(&'static str, Parser<T>)>
+where
+    T: DeserializeOwned,
+{
+    let all: Vec<(&'static str, Parser<T>)> = vec![
+        ("JSON", formats::Json::parse_str::<T>),
+        ("TOML", formats::Toml::parse_str::<T>),
+        ("YAML", formats::Yaml::parse_str::<T>),
+        #[cfg(feature = "json5")]
+        ("JSON5", formats::Json5::parse_str::<T>),
+        #[cfg(feature = "ron")]
+        ("RON", formats::Ron::parse_str::<T>),
+        #[cfg(feature = "ini")]
+        ("INI", formats::Ini::parse_str::<T>),
+        #[cfg(feature = "dotenv")]
+        ("dotenv", formats::Dotenv::parse_str::<T>),
+    ];
+
+    let (hints, exclusive) = hints(src);
+    let rank = |name: &str| hints.iter().position(|h| *h == name).unwrap_or(hints.len());
+
+    let mut ordered = all;
+    ordered.sort_by_key(|(name, _)| rank(name));
+    if exclusive && ordered.iter().any(|(name, _)| hints.contains(name)) {
+        ordered.retain(|(name, _)| hints.contains(name));
+    }
+    ordered
+}
+
+fn hints(src: &str) -> (&'static [&'static str], bool) {
+    let text = src.trim_start_matches('\u{feff}').trim_start();
+
+    if text.starts_with("---") || text.starts_with("%YAML") {
+        return (&["YAML"], true);
+    }
+    if text.starts_with('{') {
+        return (&["JSON", "JSON5"], true);
+    }
+    if text.starts_with('(') {
+        return (&["RON"], true);
+    }
+
+    let Some(line) = text.lines().map(str::trim).find(|l| {
+        !l.is_empty() && !l.starts_with('#') && !l.starts_with(';') && !l.starts_with("//")
+    }) else {
+        return (&[], false);
+    };
+
+    if line.starts_with('[') {
+        return match is_table_header(line) {
+            true => (&["TOML", "INI"], false),
+            false => (&["JSON", "JSON5"], true),
+        };
+    }
+
+    let line = line.strip_prefix("export ").unwrap_or(line);
+    if let Some((key, _)) = line.split_once('=') {
+        let env_style = !key.ends_with(' ')
+            && key.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+        let order: &[_] = match env_style {
+            true => &["dotenv", "TOML", "INI"],
+            false => &["TOML", "INI", "dotenv"],
+        };
+        return (order, false);
+    }
+    if line.split_once(':').is_some_and(|(key, _)| !key.contains(['"', '{', '['])) {
+        return (&["YAML"], false);
+    }
+    (&[], false)
+}
+
+// `[server]`, `[[bin]]`, `[a.b."c d"]` (optionally with a trailing comment),
+// as opposed to a JSON array like `[1, 2]` or `["a"]`.
+fn is_table_header(line: &str) -> bool {
+    let line = line.split_once('#').map_or(line, |(head, _)| head).trim_end();
+    let inner = line
+        .strip_prefix("[[")
+        .and_then(|l| l.strip_suffix("]]"))
+        .or_else(|| line.strip_prefix('[').and_then(|l| l.strip_suffix(']')));
+
+    inner.is_some_and(|name| {
+        let name = name.trim();
+        !name.is_empty()
+            && !name.starts_with('"')
+            && name.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ' ' | '"'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde::{Deserialize, Serialize},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+    }
+
+    fn demo(id: u32) -> Demo {
+        Demo { id, name: "alpha".into() }
+    }
+
+    fn detect(file_name: &str, content: &str) -> Result<Detected<Demo>, CodecError> {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join(file_name);
+        fs::write(&p, content).unwrap();
+        load_auto_detect(&p)
+    }
+
+    #[test]
+    fn uses_extension_when_known() {
+        let got = detect("d.yaml", "id: 1\nname: alpha\n").unwrap();
+        assert_eq!(got, Detected { value: demo(1), format: "YAML", by: DetectedBy::Extension });
+    }
+
+    #[test]
+    fn sniffs_json_without_extension() {
+        let got = detect("Appfile", r#"{ "id": 2, "name": "alpha" }"#).unwrap();
+        assert_eq!(got, Detected { value: demo(2), format: "JSON", by: DetectedBy::Content });
+    }
+
+    #[test]
+    fn sniffs_toml_in_unknown_extension() {
+        let got = detect("app.conf", "# settings\nid = 3\nname = \"alpha\"\n").unwrap();
+        assert_eq!((got.value, got.format), (demo(3), "TOML"));
+    }
+
+    #[test]
+    fn sniffs_toml_table_header_over_json_array() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Wrapper {
+            app: Demo,
+        }
+
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("config");
+        fs::write(&p, "[app]\nid = 4\nname = \"alpha\"\n").unwrap();
+
+        let got: Detected<Wrapper> = load_auto_detect(&p).unwrap();
+        assert_eq!((got.value.app, got.format), (demo(4), "TOML"));
+    }
+
+    #[test]
+    fn sniffs_yaml_document() {
+        let got = detect("settings", "---\nid: 5\nname: alpha\n").unwrap();
+        assert_eq!((got.value, got.format), (demo(5), "YAML"));
+
+        let got = detect("settings.cfg", "id: 6\nname: alpha\n").unwrap();
+        assert_eq!((got.value, got.format), (demo(6), "YAML"));
+    }
+
+    #[cfg(feature = "dotenv")]
+    #[test]
+    fn sniffs_env_style_assignments() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[allow(non_snake_case)]
+        struct Env {
+            APP_ID: u32,
+            APP_NAME: String,
+        }
+
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("Envfile");
+        fs::write(&p, "APP_ID=9\nAPP_NAME=alpha beta\n").unwrap();
+
+        let got: Detected<Env> = load_auto_detect(&p).unwrap();
+        assert_eq!(got.format, "dotenv");
+        assert_eq!(got.value, Env { APP_ID: 9, APP_NAME: "alpha beta".into() });
+    }
+
+    #[test]
+    fn reports_error_of_most_likely_format() {
+        let err = detect("broken", "{ \"id\": 7, \"name\": }").unwrap_err();
+        let CodecError::Deserialize(e) = err else { panic!("expected deserialize error") };
+
+        assert_eq!(e.diagnostic().format, "JSON");
+        assert!(e.diagnostic().path.as_deref().is_some_and(|p| p.ends_with("broken")));
+    }
+
+    #[test]
+    fn known_extension_does_not_fall_back() {
+        let err = detect("d.json", "id = 8\nname = \"alpha\"\n").unwrap_err();
+        assert!(matches!(err, CodecError::Deserialize(DeserializeError::Json(_))));
+    }
+
+    #[test]
+    fn table_header_detection() {
+        assert!(is_table_header("[server]"));
+        assert!(is_table_header("[[bin]] # entries"));
+        assert!(is_table_header("[a.b.\"c d\"]"));
+        assert!(!is_table_header("[1, 2]"));
+        assert!(!is_table_header("[\"a\"]"));
+        assert!(!is_table_header("[]"));
+    }
+}
@@ -0,0 +1,52 @@
+use {
+    crate::{CodecError, FormatRegistry},
+    serde::de::DeserializeOwned,
+    std::path::Path,
+};
+
+pub fn load_auto_with<T>(registry: &FormatRegistry, path: impl AsRef<Path>) -> Result<T, CodecError>
+where
+    T: DeserializeOwned,
+{
+    registry.load(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::formats::Json,
+        serde::{Deserialize, Serialize},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+    }
+
+    #[test]
+    fn loads_with_custom_mapping() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("d.data");
+        fs::write(&p, r#"{ "id": 1, "name": "alpha" }"#).unwrap();
+
+        let mut registry = FormatRegistry::empty();
+        registry.register::<Json>(["data"]);
+
+        let got: Demo = load_auto_with(&registry, &p).expect("load via registry");
+        assert_eq!(got, Demo { id: 1, name: "alpha".into() });
+    }
+
+    #[test]
+    fn error_when_extension_not_registered() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("d.json");
+        fs::write(&p, r#"{ "id": 1, "name": "alpha" }"#).unwrap();
+
+        let err = load_auto_with::<Demo>(&FormatRegistry::empty(), &p).unwrap_err();
+        assert!(matches!(err, CodecError::Io(_)));
+    }
+}
@@ -0,0 +1,51 @@
+use {
+    crate::{CodecError, Layer, Layered, LayeredOptions, file},
+    serde::de::DeserializeOwned,
+};
+
+pub fn load_layered<T>(
+    layers: impl IntoIterator<Item = impl Into<Layer>>,
+) -> Result<Layered<T>, CodecError>
+where
+    T: DeserializeOwned,
+{
+    file::load_layered_with(layers, LayeredOptions::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::LayerSource,
+        serde::Deserialize,
+        std::{fs, io},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Cfg {
+        name: String,
+        port: u16,
+    }
+
+    #[test]
+    fn later_layers_override_earlier_ones_across_formats() {
+        let dir = tempdir().unwrap();
+        let (defaults, local) = (dir.path().join("defaults.toml"), dir.path().join("local.yaml"));
+        fs::write(&defaults, "name = \"svc\"\nport = 80\n").unwrap();
+        fs::write(&local, "port: 8080\n").unwrap();
+
+        let got: Layered<Cfg> = load_layered([&defaults, &local]).unwrap();
+
+        assert_eq!(got.value, Cfg { name: "svc".into(), port: 8080 });
+        assert_eq!(got.source("name"), Some(&LayerSource::File(defaults)));
+        assert_eq!(got.source("port"), Some(&LayerSource::File(local)));
+    }
+
+    #[test]
+    fn missing_required_layer_is_an_error() {
+        let dir = tempdir().unwrap();
+        let err = load_layered::<Cfg>([dir.path().join("missing.toml")]).unwrap_err();
+        assert!(matches!(err, CodecError::Io(e) if e.kind() == io::ErrorKind::NotFound));
+    }
+}
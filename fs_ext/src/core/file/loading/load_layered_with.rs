@@ -0,0 +1,287 @@
+use {
+    crate::{
+        CodecError, DeserializeError, Diagnostic, FormatRegistry, Layer, LayerSource, Layered,
+        LayeredOptions,
+    },
+    serde::de::DeserializeOwned,
+    serde_json::{Map, Value},
+    std::{collections::BTreeMap, env, ffi::OsString},
+};
+
+type Sources = BTreeMap<String, LayerSource>;
+
+pub fn load_layered_with<T>(
+    layers: impl IntoIterator<Item = impl Into<Layer>>, options: impl AsRef<LayeredOptions>,
+) -> Result<Layered<T>, CodecError>
+where
+    T: DeserializeOwned,
+{
+    let layers: Vec<Layer> = layers.into_iter().map(Into::into).collect();
+    _load_layered_with(&layers, options.as_ref(), utf8_vars(env::vars_os()))
+}
+
+// `env::vars` panics on the first non-UTF-8 variable, prefixed or not; such
+// variables can't name a field or hold a value here, so they're skipped.
+fn utf8_vars(
+    vars: impl IntoIterator<Item = (OsString, OsString)>,
+) -> impl Iterator<Item = (String, String)> {
+    vars.into_iter()
+        .filter_map(|(name, raw)| Some((name.into_string().ok()?, raw.into_string().ok()?)))
+}
+
+fn _load_layered_with<T: DeserializeOwned>(
+    layers: &[Layer], options: &LayeredOptions, vars: impl IntoIterator<Item = (String, String)>,
+) -> Result<Layered<T>, CodecError> {
+    let mut merged = Value::Object(Map::new());
+    let mut sources = Sources::new();
+    let mut skipped = Vec::new();
+
+    for layer in layers {
+        if layer.optional && !layer.path.exists() {
+            skipped.push(layer.path.clone());
+            continue;
+        }
+        let handler = FormatRegistry::global().resolve(&layer.path)?;
+        let doc: Value = handler.load(&layer.path)?;
+        merge(&mut merged, doc, "", &LayerSource::File(layer.path.clone()), &mut sources);
+    }
+
+    if let Some(prefix) = &options.env_prefix {
+        let prefix = format!("{prefix}__");
+        let mut overrides: Vec<_> = vars
+            .into_iter()
+            .filter_map(|(name, raw)| Some((name.strip_prefix(&prefix)?.to_owned(), name, raw)))
+            .collect();
+        // Shallower keys first, so `APP__DB__HOST` still applies after `APP__DB`.
+        overrides.sort_by_key(|(rest, ..)| (rest.matches("__").count(), rest.clone()));
+
+        for (rest, name, raw) in overrides {
+            let segments: Vec<&str> = rest.split("__").collect();
+            if segments.iter().any(|s| s.is_empty()) {
+                continue;
+            }
+            apply_env(&mut merged, &segments, &raw, &LayerSource::Env(name), &mut sources);
+        }
+    }
+
+    let value = T::deserialize(merged).map_err(|e| {
+        let diagnostic = Diagnostic::new("layered config", "", None, e.to_string());
+        DeserializeError::other(diagnostic, e)
+    })?;
+
+    Ok(Layered { value, sources, skipped })
+}
+
+// Objects merge key by key; anything else replaces what was there, along with
+// the provenance of everything underneath it.
+fn merge(base: &mut Value, layer: Value, key: &str, source: &LayerSource, sources: &mut Sources) {
+    match (base, layer) {
+        (Value::Object(base), Value::Object(layer)) => {
+            for (k, v) in layer {
+                let child = join(key, &k);
+                merge(base.entry(k).or_insert(Value::Null), v, &child, source, sources);
+            }
+        }
+        (base, layer) => {
+            forget(sources, key);
+            record(&layer, key, source, sources);
+            *base = layer;
+        }
+    }
+}
+
+fn apply_env(
+    root: &mut Value, segments: &[&str], raw: &str, source: &LayerSource, sources: &mut Sources,
+) {
+    let mut node = root;
+    let mut key = String::new();
+
+    for segment in segments {
+        if !node.is_object() {
+            forget(sources, &key);
+            *node = Value::Object(Map::new());
+        }
+        let map = node.as_object_mut().expect("node was just made an object");
+        // Reuse the document's spelling (`maxConns`) when the variable matches it.
+        let name = map
+            .keys()
+            .find(|k| k.eq_ignore_ascii_case(segment))
+            .cloned()
+            .unwrap_or_else(|| segment.to_lowercase());
+        key = join(&key, &name);
+        node = map.entry(name).or_insert(Value::Null);
+    }
+
+    // Strings stay strings; everything else is read as a JSON scalar when it parses.
+    let value = match node {
+        Value::String(_) => Value::String(raw.to_owned()),
+        _ => serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_owned())),
+    };
+    merge(node, value, &key, source, sources);
+}
+
+fn record(value: &Value, key: &str, source: &LayerSource, sources: &mut Sources) {
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map {
+                record(v, &join(key, k), source, sources);
+            }
+        }
+        _ => {
+            sources.insert(key.to_owned(), source.clone());
+        }
+    }
+}
+
+fn forget(sources: &mut Sources, key: &str) {
+    if key.is_empty() {
+        sources.clear();
+        return;
+    }
+    let nested = format!("{key}.");
+    sources.retain(|k, _| k != key && !k.starts_with(&nested));
+}
+
+fn join(key: &str, child: &str) -> String {
+    if key.is_empty() { child.to_owned() } else { format!("{key}.{child}") }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde::Deserialize,
+        std::{fs, path::PathBuf},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Cfg {
+        name: String,
+        server: Server,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Server {
+        host: String,
+        port: u16,
+        max_conns: u32,
+        tags: Vec<String>,
+    }
+
+    fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    fn write(dir: &std::path::Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn deep_merges_and_tracks_sources() {
+        let dir = tempdir().unwrap();
+        let defaults = write(
+            dir.path(),
+            "defaults.toml",
+            "name = \"svc\"\n[server]\nhost = \"0.0.0.0\"\nport = 80\nmaxConns = 10\ntags = [\"a\"]\n",
+        );
+        let config = write(dir.path(), "config.json", r#"{ "server": { "port": 8080 } }"#);
+        let local = Layer::optional(dir.path().join("config.local.yaml"));
+
+        let got: Layered<Cfg> = _load_layered_with(
+            &[defaults.clone().into(), config.clone().into(), local.clone()],
+            &LayeredOptions::default(),
+            env(&[]),
+        )
+        .unwrap();
+
+        assert_eq!(got.value.server.port, 8080);
+        assert_eq!(got.value.server.host, "0.0.0.0");
+        assert_eq!(got.source("server.port"), Some(&LayerSource::File(config)));
+        assert_eq!(got.source("server.host"), Some(&LayerSource::File(defaults.clone())));
+        assert_eq!(got.source("server.tags"), Some(&LayerSource::File(defaults)));
+        assert_eq!(got.skipped, vec![local.path]);
+    }
+
+    #[test]
+    fn env_overrides_use_prefix_and_double_underscores() {
+        let dir = tempdir().unwrap();
+        let defaults = write(
+            dir.path(),
+            "defaults.yaml",
+            "name: '1'\nserver:\n  host: localhost\n  port: 80\n  maxConns: 10\n  tags: []\n",
+        );
+        let options = LayeredOptions { env_prefix: Some("APP".into()) };
+
+        let got: Layered<Cfg> = _load_layered_with(
+            &[defaults.into()],
+            &options,
+            env(&[
+                ("APP__NAME", "42"),
+                ("APP__SERVER__PORT", "9000"),
+                ("APP__SERVER__MAXCONNS", "64"),
+                ("APP__SERVER__TAGS", r#"["x","y"]"#),
+                ("OTHER__SERVER__PORT", "1"),
+                ("APP__", "ignored"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(got.value.name, "42");
+        assert_eq!(got.value.server.port, 9000);
+        assert_eq!(got.value.server.max_conns, 64);
+        assert_eq!(got.value.server.tags, ["x", "y"]);
+        assert_eq!(
+            got.source("server.maxConns"),
+            Some(&LayerSource::Env("APP__SERVER__MAXCONNS".into()))
+        );
+    }
+
+    #[test]
+    fn replacing_a_table_with_a_scalar_drops_nested_sources() {
+        let dir = tempdir().unwrap();
+        let a = write(dir.path(), "a.json", r#"{ "db": { "host": "h", "port": 1 } }"#);
+        let b = write(dir.path(), "b.json", r#"{ "db": "sqlite://mem" }"#);
+
+        let got: Layered<Value> =
+            _load_layered_with(&[a.into(), b.clone().into()], &LayeredOptions::default(), env(&[]))
+                .unwrap();
+
+        assert_eq!(got.value, serde_json::json!({ "db": "sqlite://mem" }));
+        assert_eq!(got.sources.len(), 1);
+        assert_eq!(got.source("db"), Some(&LayerSource::File(b)));
+    }
+
+    #[test]
+    fn type_mismatch_after_merge_is_a_deserialize_error() {
+        let dir = tempdir().unwrap();
+        let a = write(dir.path(), "a.toml", "name = \"svc\"\n");
+
+        let err = _load_layered_with::<Cfg>(&[a.into()], &LayeredOptions::default(), env(&[]))
+            .unwrap_err();
+
+        let CodecError::Deserialize(e) = err else { panic!("expected deserialize error") };
+        assert!(matches!(e, DeserializeError::Other { .. }), "{e:?}");
+        assert_eq!(e.diagnostic().format, "layered config");
+        assert!(e.diagnostic().message.contains("server"), "{}", e.diagnostic().message);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_variables_are_skipped() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let bad = OsString::from_vec(vec![0xff, 0xfe]);
+        let vars = [
+            (bad.clone(), OsString::from("x")),
+            (OsString::from("APP__NAME"), bad),
+            (OsString::from("APP__PORT"), OsString::from("80")),
+        ];
+
+        let kept: Vec<_> = utf8_vars(vars).collect();
+        assert_eq!(kept, [("APP__PORT".to_owned(), "80".to_owned())]);
+    }
+}
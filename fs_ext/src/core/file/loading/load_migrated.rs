@@ -0,0 +1,75 @@
+use {
+    super::load_versioned::read_versioned,
+    crate::{CodecError, Format, Versioned, file},
+    std::path::Path,
+};
+
+pub fn load_migrated<T, F>(path: impl AsRef<Path>) -> Result<T, CodecError>
+where
+    T: Versioned,
+    F: Format,
+{
+    let path = path.as_ref();
+    let (model, found) = read_versioned::<T, F>(path)?;
+
+    if found < T::VERSION {
+        file::save_versioned::<T, F>(path, &model)?;
+    }
+    Ok(model)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{Migrations, formats::Toml},
+        serde::{Deserialize, Serialize},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Cfg {
+        port: u16,
+        host: String,
+    }
+
+    impl Versioned for Cfg {
+        const VERSION: u32 = 1;
+        const VERSION_KEY: &'static str = "schema";
+
+        fn migrations() -> Migrations {
+            Migrations::new().step(0, |doc| {
+                doc["host"] = "localhost".into();
+                Ok(())
+            })
+        }
+    }
+
+    #[test]
+    fn rewrites_old_files_in_the_new_version() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("cfg.toml");
+        fs::write(&path, "port = 80\n").unwrap();
+
+        let got: Cfg = load_migrated::<_, Toml>(&path).unwrap();
+
+        assert_eq!(got, Cfg { port: 80, host: "localhost".into() });
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "schema = 1\nport = 80\nhost = \"localhost\"\n"
+        );
+    }
+
+    #[test]
+    fn current_files_are_left_alone() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("cfg.toml");
+        let src = "# hand-written\nschema = 1\nport = 1\nhost = \"h\"\n";
+        fs::write(&path, src).unwrap();
+
+        load_migrated::<Cfg, Toml>(&path).unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), src);
+    }
+}
@@ -27,7 +27,7 @@ where
                 Ok(())
             })?;
 
-            F::parse_str::<T>(str).map_err(CodecError::Deserialize)
+            F::parse_str::<T>(str).map_err(|e| CodecError::Deserialize(e.with_path(path)))
         }
 
         Err(e) => Err(e),
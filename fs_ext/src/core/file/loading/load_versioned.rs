@@ -0,0 +1,158 @@
+use {
+    crate::{CodecError, DeserializeError, Diagnostic, Format, MigrationError, Versioned},
+    serde_json::Value,
+    std::path::Path,
+};
+
+pub fn load_versioned<T, F>(path: impl AsRef<Path>) -> Result<T, CodecError>
+where
+    T: Versioned,
+    F: Format,
+{
+    read_versioned::<T, F>(path.as_ref()).map(|(model, _)| model)
+}
+
+// Loads `path`, runs the migration chain up to `T::VERSION`, and returns the
+// model along with the version the file was stored in.
+pub(crate) fn read_versioned<T, F>(path: &Path) -> Result<(T, u32), CodecError>
+where
+    T: Versioned,
+    F: Format,
+{
+    let mut doc: Value = F::load(path)?;
+
+    let found = match doc.as_object_mut().map(|map| map.remove(T::VERSION_KEY)) {
+        Some(None) => 0,
+        Some(Some(v)) => v.as_u64().and_then(|v| u32::try_from(v).ok()).ok_or_else(|| {
+            MigrationError::InvalidVersion { path: path.to_owned(), key: T::VERSION_KEY }
+        })?,
+        None => {
+            return Err(MigrationError::InvalidVersion {
+                path: path.to_owned(),
+                key: T::VERSION_KEY,
+            }
+            .into());
+        }
+    };
+
+    if found > T::VERSION {
+        let (path, supported) = (path.to_owned(), T::VERSION);
+        return Err(MigrationError::TooNew { path, found, supported }.into());
+    }
+    T::migrations().apply(path, &mut doc, found, T::VERSION)?;
+
+    let model = T::deserialize(doc).map_err(|e| {
+        let mut diagnostic = Diagnostic::new("versioned model", "", None, e.to_string());
+        diagnostic.path = Some(path.to_owned());
+        DeserializeError::other(diagnostic, e)
+    })?;
+
+    Ok((model, found))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{Migrations, formats::Json},
+        serde::{Deserialize, Serialize},
+        serde_json::json,
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Settings {
+        name: String,
+        retries: u32,
+    }
+
+    impl Versioned for Settings {
+        const VERSION: u32 = 2;
+
+        fn migrations() -> Migrations {
+            Migrations::new()
+                .step(0, |doc| {
+                    doc["retries"] = json!(1);
+                    Ok(())
+                })
+                .step(1, |doc| {
+                    let user = doc.as_object_mut().unwrap().remove("user").ok_or("missing user")?;
+                    doc["name"] = user;
+                    Ok(())
+                })
+        }
+    }
+
+    fn write(contents: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("settings.json");
+        fs::write(&path, contents).unwrap();
+        (dir, path)
+    }
+
+    #[test]
+    fn upgrades_step_by_step_from_an_unversioned_file() {
+        let (_dir, path) = write(r#"{ "user": "ana" }"#);
+
+        let got: Settings = load_versioned::<_, Json>(&path).unwrap();
+
+        assert_eq!(got, Settings { name: "ana".into(), retries: 1 });
+        assert_eq!(fs::read_to_string(&path).unwrap(), r#"{ "user": "ana" }"#);
+    }
+
+    #[test]
+    fn current_version_loads_without_migrating() {
+        let (_dir, path) = write(r#"{ "version": 2, "name": "bo", "retries": 5 }"#);
+
+        let (got, found) = read_versioned::<Settings, Json>(&path).unwrap();
+        assert_eq!((got, found), (Settings { name: "bo".into(), retries: 5 }, 2));
+    }
+
+    #[test]
+    fn newer_files_are_rejected() {
+        let (_dir, path) = write(r#"{ "version": 3, "name": "bo", "retries": 5 }"#);
+
+        let err = load_versioned::<Settings, Json>(&path).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                CodecError::Migration(MigrationError::TooNew { found: 3, supported: 2, .. })
+            ),
+            "{err:?}"
+        );
+        assert!(err.to_string().contains("newer than the supported version 2"));
+    }
+
+    #[test]
+    fn failing_step_reports_its_version() {
+        let (_dir, path) = write(r#"{ "version": 1, "retries": 5 }"#);
+
+        let err = load_versioned::<Settings, Json>(&path).unwrap_err();
+        let CodecError::Migration(MigrationError::Failed { from, source, .. }) = err else {
+            panic!("expected failed migration, got {err:?}")
+        };
+        assert_eq!((from, source.to_string()), (1, "missing user".to_owned()));
+    }
+
+    #[test]
+    fn non_integer_version_is_invalid() {
+        let (_dir, path) = write(r#"{ "version": "2", "name": "bo", "retries": 5 }"#);
+
+        let err = load_versioned::<Settings, Json>(&path).unwrap_err();
+        assert!(matches!(err, CodecError::Migration(MigrationError::InvalidVersion { .. })));
+    }
+
+    #[test]
+    fn mismatch_after_migrating_is_a_deserialize_error() {
+        let (_dir, path) = write(r#"{ "version": 2, "name": "bo", "retries": "many" }"#);
+
+        let err = load_versioned::<Settings, Json>(&path).unwrap_err();
+
+        let CodecError::Deserialize(e) = err else { panic!("expected deserialize error") };
+        assert!(matches!(e, DeserializeError::Other { .. }), "{e:?}");
+        assert_eq!(e.diagnostic().format, "versioned model");
+        assert_eq!(e.diagnostic().path.as_deref(), Some(path.as_path()));
+    }
+}
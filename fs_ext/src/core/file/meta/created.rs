@@ -1,5 +1,5 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{fs, io, path::Path, time::SystemTime},
 };
 
@@ -9,7 +9,7 @@ pub fn created(path: impl AsRef<Path>) -> io::Result<SystemTime> {
 
 #[cfg_attr(test, fs_ext_test_macros::fs_test(rejects_missing_path, rejects_dir))]
 fn _created(path: &Path) -> io::Result<SystemTime> {
-    let meta = fs::metadata(path).with_path_context("Failed to get metadata", path)?;
+    let meta = fs::metadata(path).with_path_context(Operation::Metadata, path)?;
 
     if !meta.is_file() {
         return Err(io::Error::new(
@@ -18,12 +18,7 @@ fn _created(path: &Path) -> io::Result<SystemTime> {
         ));
     }
 
-    meta.created().map_err(|e| {
-        io::Error::new(
-            e.kind(),
-            format!("Failed to get creation time for '{}': {e}", path.display()),
-        )
-    })
+    meta.created().with_path_context(Operation::Metadata, path)
 }
 
 #[cfg(test)]
@@ -1,5 +1,5 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{fs, fs::FileType, io, path::Path},
 };
 
@@ -9,7 +9,7 @@ pub fn file_type(path: impl AsRef<Path>) -> io::Result<FileType> {
 }
 
 fn _file_type(path: &Path) -> io::Result<FileType> {
-    let meta = fs::metadata(path).with_path_context("Failed to get metadata", path)?;
+    let meta = fs::metadata(path).with_path_context(Operation::Metadata, path)?;
 
     if !meta.is_file() {
         return Err(io::Error::new(
@@ -1,5 +1,5 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{fs, io, path::Path, time::SystemTime},
 };
 
@@ -9,7 +9,7 @@ pub fn last_modified(path: impl AsRef<Path>) -> io::Result<SystemTime> {
 }
 
 fn _last_modified(path: &Path) -> io::Result<SystemTime> {
-    let meta = fs::metadata(path).with_path_context("Failed to get metadata", path)?;
+    let meta = fs::metadata(path).with_path_context(Operation::Metadata, path)?;
 
     if !meta.is_file() {
         return Err(io::Error::new(
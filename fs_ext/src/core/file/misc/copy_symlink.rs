@@ -0,0 +1,58 @@
+use {
+    crate::{IoResultExt, Operation},
+    std::{fs, io, path::Path},
+};
+
+// Recreates the link itself rather than what it points to. The target is kept
+// verbatim, so a relative link resolves against `dst`'s parent.
+pub(crate) fn copy_symlink(src: &Path, dst: &Path) -> io::Result<()> {
+    let target = fs::read_link(src).with_path_context(Operation::Read, src)?;
+
+    #[cfg(unix)]
+    let res = std::os::unix::fs::symlink(&target, dst);
+    #[cfg(windows)]
+    let res = if fs::metadata(src).is_ok_and(|meta| meta.is_dir()) {
+        std::os::windows::fs::symlink_dir(&target, dst)
+    } else {
+        std::os::windows::fs::symlink_file(&target, dst)
+    };
+    #[cfg(not(any(unix, windows)))]
+    let res = Err(io::Error::new(io::ErrorKind::Unsupported, "symlinks are not supported"));
+
+    res.with_paths_context(Operation::Copy, src, dst)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use {
+        super::copy_symlink,
+        std::{fs, io, os::unix::fs::symlink},
+        tempfile::tempdir,
+    };
+
+    #[test]
+    fn recreates_link_with_same_target() -> io::Result<()> {
+        let tmp = tempdir()?;
+        fs::write(tmp.path().join("target.txt"), b"t")?;
+        symlink("target.txt", tmp.path().join("link"))?;
+
+        copy_symlink(&tmp.path().join("link"), &tmp.path().join("copy"))?;
+
+        let copy = tmp.path().join("copy");
+        assert!(fs::symlink_metadata(&copy)?.file_type().is_symlink());
+        assert_eq!(fs::read_link(&copy)?, fs::read_link(tmp.path().join("link"))?);
+        assert_eq!(fs::read(copy)?, b"t");
+        Ok(())
+    }
+
+    #[test]
+    fn copies_dangling_links() -> io::Result<()> {
+        let tmp = tempdir()?;
+        symlink("missing", tmp.path().join("link"))?;
+
+        copy_symlink(&tmp.path().join("link"), &tmp.path().join("copy"))?;
+
+        assert_eq!(fs::read_link(tmp.path().join("copy"))?.to_str(), Some("missing"));
+        Ok(())
+    }
+}
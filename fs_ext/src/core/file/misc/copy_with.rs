@@ -0,0 +1,313 @@
+use {
+    crate::{CopyOptions, IoResultExt, Operation, PathExt},
+    std::{
+        fs::{self, File},
+        io,
+        path::Path,
+    },
+};
+
+pub fn copy_with(
+    src: impl AsRef<Path>, dst: impl AsRef<Path>, options: impl AsRef<CopyOptions>,
+) -> io::Result<u64> {
+    _copy_with(src.as_ref(), dst.as_ref(), options.as_ref())
+}
+
+fn _copy_with(src: &Path, dst: &Path, options: &CopyOptions) -> io::Result<u64> {
+    src.assert_file()?;
+
+    let source = File::open(src).with_path_context(Operation::Open, src)?;
+    let perms = source.metadata().with_path_context(Operation::Metadata, src)?.permissions();
+
+    let mut target = File::create(dst).with_path_context(Operation::Create, dst)?;
+    let copied =
+        copy_data(&source, &mut target, options).with_paths_context(Operation::Copy, src, dst)?;
+
+    fs::set_permissions(dst, perms).with_path_context(Operation::SetPermissions, dst)?;
+    Ok(copied)
+}
+
+// `dst` is expected to be empty; its cursor is left untouched.
+#[cfg(target_os = "linux")]
+pub(crate) fn copy_data(src: &File, dst: &mut File, options: &CopyOptions) -> io::Result<u64> {
+    use crate::Reflink;
+
+    let len = src.metadata()?.len();
+
+    if options.reflink != Reflink::Never {
+        match linux::reflink(src, dst) {
+            Ok(()) => return Ok(len),
+            Err(e) if options.reflink == Reflink::Always => return Err(e),
+            Err(_) => {}
+        }
+    }
+
+    let segments = if options.sparse { linux::data_segments(src, len)? } else { vec![(0, len)] };
+    for (start, end) in segments {
+        linux::copy_range(src, dst, start, end)?;
+    }
+
+    // Extending the length rather than writing zeros keeps any trailing hole sparse.
+    dst.set_len(len)?;
+    Ok(len)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn copy_data(src: &File, dst: &mut File, options: &CopyOptions) -> io::Result<u64> {
+    use {
+        crate::Reflink,
+        std::io::{Seek, SeekFrom},
+    };
+
+    if options.reflink == Reflink::Always {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "reflink copies are only supported on Linux",
+        ));
+    }
+
+    let pos = dst.stream_position()?;
+    let mut reader = src;
+    let copied = io::copy(&mut reader, dst)?;
+    dst.seek(SeekFrom::Start(pos))?;
+    Ok(copied)
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::{
+        fs::File,
+        io,
+        os::{fd::AsRawFd, unix::fs::FileExt},
+    };
+
+    pub fn reflink(src: &File, dst: &File) -> io::Result<()> {
+        // SAFETY: both descriptors are open for the duration of the call.
+        let ret = unsafe { libc::ioctl(dst.as_raw_fd(), libc::FICLONE, src.as_raw_fd()) };
+        if ret == -1 { Err(io::Error::last_os_error()) } else { Ok(()) }
+    }
+
+    // Returns the `[start, end)` ranges of `file` that hold data. Filesystems
+    // without hole reporting treat the whole file as a single data range.
+    pub fn data_segments(file: &File, len: u64) -> io::Result<Vec<(u64, u64)>> {
+        let fd = file.as_raw_fd();
+        let mut segments = Vec::new();
+        let mut pos = 0u64;
+
+        while pos < len {
+            // SAFETY: `fd` is a valid descriptor; lseek only moves its offset.
+            let data = unsafe { libc::lseek(fd, pos as libc::off_t, libc::SEEK_DATA) };
+            if data < 0 {
+                let err = io::Error::last_os_error();
+                if err.raw_os_error() == Some(libc::ENXIO) {
+                    break;
+                }
+                segments.push((pos, len));
+                break;
+            }
+
+            // SAFETY: as above.
+            let hole = unsafe { libc::lseek(fd, data, libc::SEEK_HOLE) };
+            if hole < 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            let (start, end) = (data as u64, (hole as u64).min(len));
+            if start >= len {
+                break;
+            }
+            segments.push((start, end));
+            pos = end;
+        }
+
+        Ok(segments)
+    }
+
+    pub fn copy_range(src: &File, dst: &File, start: u64, end: u64) -> io::Result<()> {
+        let mut off_in = start as libc::loff_t;
+        let mut off_out = start as libc::loff_t;
+
+        while (off_in as u64) < end {
+            let remaining = (end - off_in as u64) as usize;
+            // SAFETY: the offsets are valid for writes and both descriptors are open.
+            let ret = unsafe {
+                libc::copy_file_range(
+                    src.as_raw_fd(),
+                    &mut off_in,
+                    dst.as_raw_fd(),
+                    &mut off_out,
+                    remaining,
+                    0,
+                )
+            };
+
+            // The source shrank since its length was read; stopping here would
+            // leave the tail as zeros once the caller sets the length.
+            if ret == 0 {
+                return Err(truncated());
+            }
+            if ret < 0 {
+                let err = io::Error::last_os_error();
+                return match err.raw_os_error() {
+                    Some(libc::EXDEV | libc::ENOSYS | libc::EOPNOTSUPP | libc::EINVAL) => {
+                        copy_range_buffered(src, dst, off_in as u64, end)
+                    }
+                    _ => Err(err),
+                };
+            }
+        }
+
+        Ok(())
+    }
+
+    fn copy_range_buffered(src: &File, dst: &File, start: u64, end: u64) -> io::Result<()> {
+        let mut buf = vec![0u8; 128 * 1024];
+        let mut pos = start;
+
+        while pos < end {
+            let want = buf.len().min((end - pos) as usize);
+            let n = src.read_at(&mut buf[..want], pos)?;
+            if n == 0 {
+                return Err(truncated());
+            }
+            dst.write_all_at(&buf[..n], pos)?;
+            pos += n as u64;
+        }
+
+        Ok(())
+    }
+
+    fn truncated() -> io::Error {
+        io::Error::new(io::ErrorKind::UnexpectedEof, "source file shrank while copying")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::copy_with,
+        crate::{CopyOptions, Reflink},
+        std::{
+            fs::{self, File},
+            io::{self, Seek, SeekFrom, Write},
+        },
+        tempfile::tempdir,
+    };
+
+    #[test]
+    fn copies_contents_and_returns_length() -> io::Result<()> {
+        let dir = tempdir()?;
+        let src = dir.path().join("src.bin");
+        let dst = dir.path().join("dst.bin");
+        let data: Vec<u8> = (0..300_000u32).map(|i| (i % 251) as u8).collect();
+        fs::write(&src, &data)?;
+
+        let n = copy_with(&src, &dst, CopyOptions::default())?;
+
+        assert_eq!(n, data.len() as u64);
+        assert_eq!(fs::read(&dst)?, data);
+        Ok(())
+    }
+
+    #[test]
+    fn overwrites_existing_destination() -> io::Result<()> {
+        let dir = tempdir()?;
+        let src = dir.path().join("src.txt");
+        let dst = dir.path().join("dst.txt");
+        fs::write(&src, b"short")?;
+        fs::write(&dst, b"much longer existing content")?;
+
+        copy_with(&src, &dst, CopyOptions::default())?;
+
+        assert_eq!(fs::read(&dst)?, b"short");
+        Ok(())
+    }
+
+    #[test]
+    fn copies_empty_file() -> io::Result<()> {
+        let dir = tempdir()?;
+        let src = dir.path().join("empty");
+        let dst = dir.path().join("copy");
+        File::create(&src)?;
+
+        assert_eq!(copy_with(&src, &dst, CopyOptions::default())?, 0);
+        assert_eq!(fs::read(&dst)?, b"");
+        Ok(())
+    }
+
+    #[test]
+    fn sparse_file_keeps_holes_and_content() -> io::Result<()> {
+        let dir = tempdir()?;
+        let src = dir.path().join("sparse.img");
+        let dst = dir.path().join("copy.img");
+
+        let mut f = File::create(&src)?;
+        f.write_all(b"head")?;
+        f.seek(SeekFrom::Start(8 * 1024 * 1024))?;
+        f.write_all(b"middle")?;
+        f.set_len(16 * 1024 * 1024)?;
+        drop(f);
+
+        let options = CopyOptions { reflink: Reflink::Never, sparse: true };
+        copy_with(&src, &dst, options)?;
+
+        let copied = fs::read(&dst)?;
+        assert_eq!(copied.len(), 16 * 1024 * 1024);
+        assert_eq!(&copied[..4], b"head");
+        assert_eq!(&copied[8 * 1024 * 1024..8 * 1024 * 1024 + 6], b"middle");
+        assert!(copied[4..8 * 1024 * 1024].iter().all(|&b| b == 0));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            let src_blocks = fs::metadata(&src)?.blocks();
+            let dst_blocks = fs::metadata(&dst)?.blocks();
+            assert!(dst_blocks <= src_blocks + 64, "copy should stay sparse: {dst_blocks} blocks");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dense_copy_matches_content() -> io::Result<()> {
+        let dir = tempdir()?;
+        let src = dir.path().join("src.bin");
+        let dst = dir.path().join("dst.bin");
+        fs::write(&src, b"dense data")?;
+
+        let options = CopyOptions { reflink: Reflink::Never, sparse: false };
+        copy_with(&src, &dst, options)?;
+
+        assert_eq!(fs::read(&dst)?, b"dense data");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn preserves_permissions() -> io::Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir()?;
+        let src = dir.path().join("script.sh");
+        let dst = dir.path().join("copy.sh");
+        fs::write(&src, b"#!/bin/sh")?;
+        fs::set_permissions(&src, fs::Permissions::from_mode(0o750))?;
+
+        copy_with(&src, &dst, CopyOptions::default())?;
+
+        assert_eq!(fs::metadata(&dst)?.permissions().mode() & 0o777, 0o750);
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn source_shrinking_mid_copy_is_an_error() -> io::Result<()> {
+        let dir = tempdir()?;
+        fs::write(dir.path().join("src.bin"), b"0123456789")?;
+        let src = File::open(dir.path().join("src.bin"))?;
+        let dst = File::create(dir.path().join("dst.bin"))?;
+
+        let err = super::linux::copy_range(&src, &dst, 0, 20).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        Ok(())
+    }
+}
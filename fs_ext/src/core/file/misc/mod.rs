@@ -1,4 +1,8 @@
 mod append;
+mod copy_symlink;
+mod copy_with;
+mod move_to;
 mod open;
 
-pub use {append::append, open::open};
+pub use {append::append, copy_with::copy_with, move_to::move_to, open::open};
+pub(crate) use {copy_symlink::copy_symlink, copy_with::copy_data};
@@ -0,0 +1,209 @@
+use {
+    crate::{CollisionStrategy, IoResultExt, Operation, PathExt, WriteOptions, file},
+    std::{
+        fs, io,
+        path::{Path, PathBuf},
+    },
+};
+
+pub fn move_to(
+    src: impl AsRef<Path>, dst: impl AsRef<Path>, options: impl AsRef<WriteOptions>,
+) -> io::Result<Option<PathBuf>> {
+    _move_to(src.as_ref(), dst.as_ref(), options.as_ref())
+}
+
+fn _move_to(src: &Path, dst: &Path, options: &WriteOptions) -> io::Result<Option<PathBuf>> {
+    src.assert_file()?;
+    options.parent.ensure(&dst.parent_or_current())?;
+
+    let target = if dst.exists() {
+        match &options.collision {
+            CollisionStrategy::Error => {
+                return Err(io::Error::from(io::ErrorKind::AlreadyExists)).with_paths_context(
+                    Operation::Move,
+                    src,
+                    dst,
+                );
+            }
+            CollisionStrategy::Skip => return Ok(None),
+            CollisionStrategy::Overwrite => dst.to_path_buf(),
+            CollisionStrategy::Rename(rename_opts) => rename_opts.generate_unique_path(dst)?,
+        }
+    } else {
+        dst.to_path_buf()
+    };
+
+    match fs::rename(src, &target) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            let overwrite = options.collision == CollisionStrategy::Overwrite;
+            copy_then_remove(src, &target, overwrite)?;
+        }
+        Err(e) => return Err(e).with_paths_context(Operation::Move, src, &target),
+    }
+
+    Ok(Some(target))
+}
+
+fn copy_then_remove(src: &Path, dst: &Path, overwrite: bool) -> io::Result<()> {
+    let perms = fs::metadata(src).with_path_context(Operation::Metadata, src)?.permissions();
+
+    let mut temp = file::temp_in(dst.parent_or_current())?;
+    temp.copy_from(src)?;
+    fs::set_permissions(temp.path(), perms)
+        .with_path_context(Operation::SetPermissions, temp.path())?;
+
+    if overwrite {
+        temp.persist(dst)?;
+    } else {
+        temp.persist_new(dst)?;
+    }
+
+    fs::remove_file(src).with_path_context(Operation::Remove, src)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{copy_then_remove, move_to},
+        crate::{CollisionStrategy, ParentPolicy, RenameOptions, WriteOptions},
+        std::{fs, io},
+        tempfile::tempdir,
+    };
+
+    fn with_collision(collision: CollisionStrategy) -> WriteOptions {
+        WriteOptions { collision, ..Default::default() }
+    }
+
+    #[test]
+    fn moves_file_to_new_location() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("a.txt");
+        let dst = tmp.path().join("b.txt");
+        fs::write(&src, b"hello")?;
+
+        let moved = move_to(&src, &dst, WriteOptions::default())?;
+
+        assert_eq!(moved.as_deref(), Some(dst.as_path()));
+        assert!(!src.exists());
+        assert_eq!(fs::read(&dst)?, b"hello");
+        Ok(())
+    }
+
+    #[test]
+    fn error_strategy_rejects_existing_destination() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("a.txt");
+        let dst = tmp.path().join("b.txt");
+        fs::write(&src, b"SRC")?;
+        fs::write(&dst, b"DST")?;
+
+        let err = move_to(&src, &dst, WriteOptions::default()).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert!(src.exists());
+        assert_eq!(fs::read(&dst)?, b"DST");
+        Ok(())
+    }
+
+    #[test]
+    fn skip_strategy_leaves_both_files() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("a.txt");
+        let dst = tmp.path().join("b.txt");
+        fs::write(&src, b"SRC")?;
+        fs::write(&dst, b"DST")?;
+
+        let moved = move_to(&src, &dst, with_collision(CollisionStrategy::Skip))?;
+
+        assert!(moved.is_none());
+        assert!(src.exists());
+        assert_eq!(fs::read(&dst)?, b"DST");
+        Ok(())
+    }
+
+    #[test]
+    fn overwrite_strategy_replaces_destination() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("a.txt");
+        let dst = tmp.path().join("b.txt");
+        fs::write(&src, b"SRC")?;
+        fs::write(&dst, b"DST")?;
+
+        move_to(&src, &dst, with_collision(CollisionStrategy::Overwrite))?;
+
+        assert!(!src.exists());
+        assert_eq!(fs::read(&dst)?, b"SRC");
+        Ok(())
+    }
+
+    #[test]
+    fn rename_strategy_picks_unique_destination() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("a.txt");
+        let dst = tmp.path().join("b.txt");
+        fs::write(&src, b"SRC")?;
+        fs::write(&dst, b"DST")?;
+
+        let opts = with_collision(CollisionStrategy::Rename(RenameOptions::Counter));
+        let moved = move_to(&src, &dst, opts)?.unwrap();
+
+        assert_eq!(moved, tmp.path().join("b_1.txt"));
+        assert_eq!(fs::read(&moved)?, b"SRC");
+        assert_eq!(fs::read(&dst)?, b"DST");
+        Ok(())
+    }
+
+    #[test]
+    fn parent_policy_is_honoured() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("a.txt");
+        let dst = tmp.path().join("nested/dir/b.txt");
+        fs::write(&src, b"x")?;
+
+        assert!(move_to(&src, &dst, WriteOptions::default()).is_err());
+
+        let opts = WriteOptions { parent: ParentPolicy::CreateIfMissing, ..Default::default() };
+        move_to(&src, &dst, opts)?;
+        assert_eq!(fs::read(&dst)?, b"x");
+        Ok(())
+    }
+
+    #[test]
+    fn errors_when_src_is_a_directory() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let dst = tmp.path().join("b");
+
+        assert!(move_to(tmp.path(), &dst, WriteOptions::default()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn copy_fallback_moves_contents_and_removes_source() -> io::Result<()> {
+        let src_dir = tempdir()?;
+        let dst_dir = tempdir()?;
+        let src = src_dir.path().join("a.txt");
+        let dst = dst_dir.path().join("a.txt");
+        fs::write(&src, b"payload")?;
+
+        copy_then_remove(&src, &dst, false)?;
+
+        assert!(!src.exists());
+        assert_eq!(fs::read(&dst)?, b"payload");
+        Ok(())
+    }
+
+    #[test]
+    fn copy_fallback_without_overwrite_keeps_existing_destination() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let src = tmp.path().join("a.txt");
+        let dst = tmp.path().join("b.txt");
+        fs::write(&src, b"SRC")?;
+        fs::write(&dst, b"DST")?;
+
+        assert!(copy_then_remove(&src, &dst, false).is_err());
+        assert!(src.exists());
+        assert_eq!(fs::read(&dst)?, b"DST");
+        Ok(())
+    }
+}
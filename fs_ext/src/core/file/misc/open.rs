@@ -1,5 +1,5 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{
         fs::{File, OpenOptions},
         io,
@@ -10,11 +10,7 @@ use {
 #[cfg_attr(test, fs_ext_test_macros::fs_test(rejects_missing_path, rejects_dir))]
 pub fn open(path: impl AsRef<Path>) -> io::Result<File> {
     let path = path.as_ref();
-    OpenOptions::new()
-        .write(true)
-        .read(true)
-        .open(path)
-        .with_path_context("failed to open file", path)
+    OpenOptions::new().write(true).read(true).open(path).with_path_context(Operation::Open, path)
 }
 
 #[cfg(test)]
@@ -1,6 +1,12 @@
 pub mod atomic;
 mod checks;
+#[cfg(any(feature = "gzip", feature = "zstd"))]
+pub mod compressed;
 mod creation;
+#[cfg(feature = "edit")]
+mod editing;
+#[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+pub(crate) mod hashing;
 mod loading;
 pub mod meta;
 mod misc;
@@ -15,3 +21,9 @@ pub use {
     checks::*, creation::*, loading::*, misc::*, reading::*, removal::*, saving::*, streaming::*,
     temp::*,
 };
+
+#[cfg(feature = "edit")]
+pub use editing::*;
+
+#[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+pub use hashing::*;
@@ -1,4 +1,7 @@
-use std::{fs, io, path::Path};
+use {
+    crate::{IoResultExt, Operation},
+    std::{fs, io, path::Path},
+};
 
 #[cfg_attr(test, fs_ext_test_macros::fs_test(rejects_missing_path, rejects_dir))]
 pub fn read_bytes(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
@@ -6,9 +9,7 @@ pub fn read_bytes(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
 }
 
 fn _read_bytes(path: &Path) -> io::Result<Vec<u8>> {
-    fs::read(path).map_err(|e| {
-        io::Error::new(e.kind(), format!("Failed to read file '{}': {e}", path.display()))
-    })
+    fs::read(path).with_path_context(Operation::Read, path)
 }
 
 #[cfg(test)]
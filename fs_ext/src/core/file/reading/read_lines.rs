@@ -1,5 +1,5 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{
         fs::File,
         io::{self, BufRead, BufReader},
@@ -13,13 +13,10 @@ pub fn read_lines(path: impl AsRef<Path>) -> io::Result<Vec<String>> {
 }
 
 fn _read_lines(path: &Path) -> io::Result<Vec<String>> {
-    let file = File::open(path).with_path_context("failed to open file", path)?;
+    let file = File::open(path).with_path_context(Operation::Open, path)?;
 
     let reader = BufReader::new(file);
-    reader
-        .lines()
-        .collect::<Result<Vec<_>, _>>()
-        .with_path_context("failed to read lines from", path)
+    reader.lines().collect::<Result<Vec<_>, _>>().with_path_context(Operation::Read, path)
 }
 
 #[cfg(test)]
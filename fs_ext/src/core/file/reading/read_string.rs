@@ -1,5 +1,5 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{fs, io, path::Path},
 };
 
@@ -9,7 +9,7 @@ pub fn read_string(path: impl AsRef<Path>) -> io::Result<String> {
 }
 
 fn _read_string(path: &Path) -> io::Result<String> {
-    fs::read_to_string(path).with_path_context("failed to read file as string", path)
+    fs::read_to_string(path).with_path_context(Operation::Read, path)
 }
 
 #[cfg(test)]
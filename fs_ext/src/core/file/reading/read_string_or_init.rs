@@ -1,4 +1,7 @@
-use std::{fs, io, path::Path};
+use {
+    crate::{IoResultExt, Operation},
+    std::{fs, io, path::Path},
+};
 
 pub fn read_string_or_init(
     path: impl AsRef<Path>, contents: impl AsRef<[u8]>,
@@ -19,20 +22,12 @@ fn _read_string_or_init(path: &Path, contents: &[u8]) -> io::Result<String> {
                     )
                 })?;
 
-            fs::write(path, contents).map_err(|e| {
-                io::Error::new(
-                    e.kind(),
-                    format!("Failed to write default content to '{}': {e}", path.display()),
-                )
-            })?;
+            fs::write(path, contents).with_path_context(Operation::Write, path)?;
 
             Ok(contents_string)
         }
 
-        Err(e) => Err(io::Error::new(
-            e.kind(),
-            format!("Failed to read file '{}' as string: {e}", path.display()),
-        )),
+        Err(e) => Err(e).with_path_context(Operation::Read, path),
     }
 }
 
@@ -1,4 +1,7 @@
-use std::{fs, io, path::Path};
+use {
+    crate::{IoResultExt, Operation},
+    std::{fs, io, path::Path},
+};
 
 pub fn read_string_or_init_with<F, C>(path: impl AsRef<Path>, contents_fn: F) -> io::Result<String>
 where
@@ -22,20 +25,12 @@ where
                     )
                 })?;
 
-            fs::write(path, bytes).map_err(|e| {
-                io::Error::new(
-                    e.kind(),
-                    format!("Failed to write default content to '{}': {e}", path.display()),
-                )
-            })?;
+            fs::write(path, bytes).with_path_context(Operation::Write, path)?;
 
             Ok(contents_string)
         }
 
-        Err(e) => Err(io::Error::new(
-            e.kind(),
-            format!("Failed to read file '{}' as string: {e}", path.display()),
-        )),
+        Err(e) => Err(e).with_path_context(Operation::Read, path),
     }
 }
 
@@ -1,5 +1,5 @@
 use {
-    crate::{IoResultExt, PathExt},
+    crate::{IoResultExt, Operation, PathExt},
     std::{fs, io, path::Path},
 };
 
@@ -7,15 +7,15 @@ use {
 pub fn remove(path: impl AsRef<Path>) -> io::Result<()> {
     let path = path.as_ref();
     path.assert_file()?;
-    fs::remove_file(path).with_path_context("failed to remove", path)
+    fs::remove_file(path).with_path_context(Operation::Remove, path)
 }
 
 pub fn trash(path: impl AsRef<Path>) -> io::Result<()> {
     let path = path.as_ref();
 
-    trash::delete(path).map_err(|e| {
-        io::Error::new(io::ErrorKind::Other, format!("Failed to trash '{}': {}", path.display(), e))
-    })
+    trash::delete(path)
+        .map_err(io::Error::other)
+        .with_path_context(Operation::Trash, path)
 }
 
 pub fn trash_or_remove(path: impl AsRef<Path>) -> io::Result<()> {
@@ -1,10 +1,7 @@
 use {
-    crate::{
-        CodecError, Format,
-        formats::{Json, Toml, Yaml},
-    },
+    crate::{CodecError, FormatRegistry},
     serde::Serialize,
-    std::{io, path::Path},
+    std::path::Path,
 };
 
 pub fn save_auto<T>(path: impl AsRef<Path>, model: &T) -> Result<(), CodecError>
@@ -12,38 +9,18 @@ where
     T: Serialize,
 {
     let path = path.as_ref();
-
-    let ext =
-        path.extension().and_then(|s| s.to_str()).map(|s| s.to_ascii_lowercase()).ok_or_else(
-            || {
-                CodecError::from(io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    format!("path '{}' has no extension", path.display()),
-                ))
-            },
-        )?;
-
-    match ext.as_str() {
-        "toml" => Toml::save(path, model),
-        "json" => Json::save(path, model),
-        "yaml" | "yml" => Yaml::save(path, model),
-
-        other => Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            format!(
-                "unsupported extension '{other}' for '{}'; expected one of: toml, json, yaml/yml",
-                path.display()
-            ),
-        )
-        .into()),
-    }
+    let handler = FormatRegistry::global().resolve(path)?;
+    handler.save(path, model)
 }
 
 #[cfg(test)]
 mod tests {
     use {
         super::*,
-        crate::formats::{Json, Toml, Yaml},
+        crate::{
+            Format,
+            formats::{Json, Toml, Yaml},
+        },
         serde::{Deserialize, Serialize},
         tempfile::tempdir,
     };
@@ -138,12 +115,12 @@ mod tests {
     #[test]
     fn error_on_unsupported_extension() {
         let dir = tempdir().unwrap();
-        let p = dir.path().join("bad.ini");
+        let p = dir.path().join("bad.xyz");
         let v = Demo { id: 9, name: "iota".into() };
 
         let err = save_auto(&p, &v).unwrap_err();
         let msg = err.to_string();
-        assert!(msg.contains("unsupported extension 'ini'"), "{msg}");
+        assert!(msg.contains("unsupported extension 'xyz'"), "{msg}");
     }
 
     #[test]
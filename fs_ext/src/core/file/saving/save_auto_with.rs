@@ -0,0 +1,44 @@
+use {
+    crate::{CodecError, FormatRegistry},
+    serde::Serialize,
+    std::path::Path,
+};
+
+pub fn save_auto_with<T>(
+    registry: &FormatRegistry, path: impl AsRef<Path>, model: &T,
+) -> Result<(), CodecError>
+where
+    T: Serialize,
+{
+    registry.save(path, model)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{Format, formats::Yaml},
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+    }
+
+    #[test]
+    fn saves_with_custom_mapping() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("d.conf");
+        let v = Demo { id: 2, name: "beta".into() };
+
+        let mut registry = FormatRegistry::empty();
+        registry.register::<Yaml>(["conf"]);
+
+        save_auto_with(&registry, &p, &v).expect("save via registry");
+        let got: Demo = Yaml::load(&p).expect("load yaml");
+        assert_eq!(got, v);
+    }
+}
@@ -0,0 +1,75 @@
+use {
+    crate::{CodecError, Format, SerializeError, Versioned},
+    serde_yaml::{Mapping, Value},
+    std::path::Path,
+};
+
+pub fn save_versioned<T, F>(path: impl AsRef<Path>, model: &T) -> Result<(), CodecError>
+where
+    T: Versioned,
+    F: Format,
+{
+    // `serde_yaml::Mapping` keeps insertion order, so the version leads and the
+    // model's fields follow in declaration order.
+    let fields = match serde_yaml::to_value(model).map_err(SerializeError::Yaml)? {
+        Value::Mapping(fields) => fields,
+        _ => {
+            let reason = "versioned models must serialize to a map";
+            return Err(SerializeError::other("versioned model", reason).into());
+        }
+    };
+
+    let mut doc = Mapping::with_capacity(fields.len() + 1);
+    doc.insert(T::VERSION_KEY.into(), T::VERSION.into());
+    doc.extend(fields.into_iter().filter(|(k, _)| k.as_str() != Some(T::VERSION_KEY)));
+
+    F::save(path, &doc)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{file, formats::Json},
+        serde::{Deserialize, Serialize},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct State {
+        zeta: u32,
+        alpha: Vec<String>,
+    }
+
+    impl Versioned for State {
+        const VERSION: u32 = 4;
+    }
+
+    #[test]
+    fn writes_the_version_first_and_roundtrips() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        let state = State { zeta: 1, alpha: vec!["a".into()] };
+
+        save_versioned::<_, Json>(&path, &state).unwrap();
+
+        let text = fs::read_to_string(&path).unwrap();
+        let (v, z, a) = (text.find("version"), text.find("zeta"), text.find("alpha"));
+        assert!(v < z && z < a, "{text}");
+        assert_eq!(file::load_versioned::<State, Json>(&path).unwrap(), state);
+    }
+
+    #[test]
+    fn non_map_models_are_rejected() {
+        #[derive(Serialize, Deserialize)]
+        struct Count(u32);
+        impl Versioned for Count {
+            const VERSION: u32 = 1;
+        }
+
+        let dir = tempdir().unwrap();
+        let err = save_versioned::<_, Json>(dir.path().join("n.json"), &Count(3)).unwrap_err();
+        assert!(matches!(err, CodecError::Serialize(SerializeError::Other { .. })));
+    }
+}
@@ -1,7 +1,10 @@
-use std::{
-    fs::File,
-    io::{self, BufReader, Read},
-    path::Path,
+use {
+    crate::{IoResultExt, Operation},
+    std::{
+        fs::File,
+        io::{self, BufReader, Read},
+        path::Path,
+    },
 };
 
 pub fn stream_bytes(
@@ -12,9 +15,7 @@ pub fn stream_bytes(
     }
 
     let path_buf = path.as_ref().to_owned();
-    let file = File::open(&path_buf).map_err(|e| {
-        io::Error::new(e.kind(), format!("Failed to open file '{}': {}", path_buf.display(), e))
-    })?;
+    let file = File::open(&path_buf).with_path_context(Operation::Open, &path_buf)?;
 
     Ok(ByteChunkIterator::new(BufReader::new(file), chunk_size, path_buf))
 }
@@ -38,10 +39,7 @@ impl<R: Read> Iterator for ByteChunkIterator<R> {
         match self.reader.read(&mut self.buffer) {
             Ok(0) => None, // EOF
             Ok(n) => Some(Ok(self.buffer[..n].to_vec())),
-            Err(e) => Some(Err(io::Error::new(
-                e.kind(),
-                format!("Failed to read chunk from '{}': {}", self.path.display(), e),
-            ))),
+            Err(e) => Some(Err(e).with_path_context(Operation::Read, &self.path)),
         }
     }
 }
@@ -1,18 +1,16 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-    path::Path,
+use {
+    crate::{IoResultExt, Operation},
+    std::{
+        fs::File,
+        io::{self, BufRead, BufReader},
+        path::Path,
+    },
 };
 
 pub fn stream_lines(
     path: impl AsRef<Path>,
 ) -> io::Result<impl Iterator<Item = io::Result<String>>> {
-    let file = File::open(path.as_ref()).map_err(|e| {
-        io::Error::new(
-            e.kind(),
-            format!("Failed to open file '{}': {}", path.as_ref().display(), e),
-        )
-    })?;
+    let file = File::open(path.as_ref()).with_path_context(Operation::Open, path.as_ref())?;
     Ok(BufReader::new(file).lines())
 }
 
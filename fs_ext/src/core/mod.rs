@@ -1,5 +1,8 @@
 pub mod dir;
 pub mod file;
 mod types;
+pub mod vfs;
 
-pub use types::{DirQuery, DirQueryOptions, ExtensionFilter, TempDir, TempFile};
+pub use types::{
+    DirQuery, DirQueryOptions, ExtensionFilter, FaultyFs, MemoryFs, RealFs, Root, TempDir, TempFile,
+};
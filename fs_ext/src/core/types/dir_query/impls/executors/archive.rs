@@ -0,0 +1,174 @@
+use {
+    crate::{ArchiveFormat, DirQuery, IoResultExt, Operation, dir, file},
+    std::{
+        io::{self, BufWriter, Write},
+        path::{Path, PathBuf},
+    },
+};
+
+impl DirQuery {
+    pub fn archive(self, dst: impl AsRef<Path>, format: ArchiveFormat) -> io::Result<()> {
+        let dst = dst.as_ref();
+        if !format.is_enabled() {
+            return Err(format.unsupported());
+        }
+        dir::assert_exists(&self.root)?;
+
+        let root = self.root.clone();
+        let mut entries = self.collect()?;
+        // Re-archiving into the source tree must not pick up the previous archive.
+        entries.retain(|p| p != dst);
+
+        file::atomic::overwrite(dst, |file| {
+            write_archive(&root, &entries, BufWriter::new(file), format)
+        })
+    }
+}
+
+fn write_archive<W>(
+    root: &Path, entries: &[PathBuf], writer: W, format: ArchiveFormat,
+) -> io::Result<()>
+where
+    W: Write + io::Seek,
+{
+    match format {
+        #[cfg(feature = "tar")]
+        ArchiveFormat::Tar => write_tar(root, entries, writer)?.flush(),
+        #[cfg(feature = "tar")]
+        ArchiveFormat::TarGz => {
+            let gz = flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+            write_tar(root, entries, gz)?.finish()?.flush()
+        }
+        #[cfg(feature = "zip")]
+        ArchiveFormat::Zip => write_zip(root, entries, writer),
+        #[allow(unreachable_patterns)]
+        _ => unreachable!("archive formats are checked with `is_enabled` first"),
+    }
+}
+
+#[cfg(feature = "tar")]
+fn write_tar<W: Write>(root: &Path, entries: &[PathBuf], writer: W) -> io::Result<W> {
+    let mut builder = tar::Builder::new(writer);
+    builder.follow_symlinks(false);
+
+    for path in entries {
+        let name = path.strip_prefix(root).expect("query results live under the root");
+        if path.is_dir() {
+            builder.append_dir(name, path)
+        } else {
+            builder.append_path_with_name(path, name)
+        }
+        .with_path_context(Operation::Read, path)?;
+    }
+    builder.into_inner()
+}
+
+#[cfg(feature = "zip")]
+fn write_zip<W: Write + io::Seek>(root: &Path, entries: &[PathBuf], writer: W) -> io::Result<()> {
+    use {
+        std::fs::{self, File},
+        zip::{ZipWriter, write::SimpleFileOptions},
+    };
+
+    let mut zip = ZipWriter::new(writer);
+
+    for path in entries {
+        let rel = path.strip_prefix(root).expect("query results live under the root");
+        let name =
+            rel.components().map(|c| c.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/");
+        let metadata = fs::metadata(path).with_path_context(Operation::Metadata, path)?;
+
+        let mut options =
+            SimpleFileOptions::default().large_file(metadata.len() >= u32::MAX as u64);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            options = options.unix_permissions(metadata.permissions().mode() & 0o7777);
+        }
+
+        if metadata.is_dir() {
+            zip.add_directory(name, options)?;
+        } else {
+            zip.start_file(name, options)?;
+            let mut src = File::open(path).with_path_context(Operation::Open, path)?;
+            io::copy(&mut src, &mut zip).with_path_context(Operation::Read, path)?;
+        }
+    }
+    zip.finish()?.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::{collections::BTreeSet, fs},
+        tempfile::tempdir,
+    };
+
+    fn fixture(root: &Path) {
+        fs::create_dir_all(root.join("src/nested")).unwrap();
+        fs::write(root.join("src/main.rs"), "fn main() {}").unwrap();
+        fs::write(root.join("src/nested/lib.rs"), "").unwrap();
+        fs::write(root.join("notes.log"), "noise").unwrap();
+    }
+
+    fn listing(dir: &Path) -> BTreeSet<String> {
+        DirQuery::new(dir)
+            .include_dirs(false)
+            .collect()
+            .unwrap()
+            .into_iter()
+            .map(|p| p.strip_prefix(dir).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect()
+    }
+
+    #[test]
+    fn archives_only_matching_entries() {
+        let src = tempdir().unwrap();
+        fixture(src.path());
+        let out = tempdir().unwrap();
+
+        for format in [ArchiveFormat::Tar, ArchiveFormat::TarGz, ArchiveFormat::Zip] {
+            if !format.is_enabled() {
+                continue;
+            }
+            let archive = out.path().join(format!("snap.{}", format.extension()));
+            DirQuery::new(src.path()).deny_extensions(["log"]).archive(&archive, format).unwrap();
+
+            let dst = out.path().join(format!("unpacked-{}", format.extension()));
+            dir::extract(&archive, &dst).unwrap();
+
+            let expected =
+                BTreeSet::from(["src/main.rs".to_owned(), "src/nested/lib.rs".to_owned()]);
+            assert_eq!(listing(&dst), expected, "{format:?}");
+            assert_eq!(fs::read_to_string(dst.join("src/main.rs")).unwrap(), "fn main() {}");
+        }
+    }
+
+    #[test]
+    fn archive_inside_source_does_not_include_itself() {
+        let src = tempdir().unwrap();
+        fixture(src.path());
+        let format = if cfg!(feature = "tar") { ArchiveFormat::Tar } else { ArchiveFormat::Zip };
+        let archive = src.path().join(format!("self.{}", format.extension()));
+
+        DirQuery::new(src.path()).archive(&archive, format).unwrap();
+        DirQuery::new(src.path()).archive(&archive, format).unwrap();
+
+        let dst = src.path().join("check");
+        dir::extract(&archive, &dst).unwrap();
+        assert!(!listing(&dst).iter().any(|p| p.starts_with("self.")));
+    }
+
+    #[test]
+    fn missing_source_is_an_error() {
+        let dir = tempdir().unwrap();
+        let format = if cfg!(feature = "zip") { ArchiveFormat::Zip } else { ArchiveFormat::Tar };
+
+        let err = DirQuery::new(dir.path().join("missing"))
+            .archive(dir.path().join("a.zip"), format)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(!dir.path().join("a.zip").exists());
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    crate::DirQuery,
+    crate::{DirQuery, FsError, IoResultExt, Operation},
     std::{io, path::PathBuf},
     walkdir::WalkDir,
 };
@@ -18,27 +18,16 @@ impl DirQuery {
 
         for entry in entries {
             let entry = entry.map_err(|e| {
-                let kind = e.io_error().map(|ioe| ioe.kind()).unwrap_or(io::ErrorKind::Other);
-                let msg = match e.path() {
-                    Some(p) => format!("walk error at '{}': {}", p.display(), e),
-                    None => format!("walk error: {}", e),
-                };
-                io::Error::new(kind, msg)
+                let at = e.path().unwrap_or(&self.root).to_owned();
+                io::Error::from(FsError::new(Operation::ReadDir, at, e.into()))
             })?;
 
             let entry_path = entry.path();
 
-            let metadata = entry.metadata().map_err(|meta_err| {
-                io::Error::new(
-                    meta_err.io_error().map(|ioe| ioe.kind()).unwrap_or(io::ErrorKind::Other),
-                    format!(
-                        "metadata error under '{}' at '{}': {}",
-                        self.root.display(),
-                        entry_path.display(),
-                        meta_err
-                    ),
-                )
-            })?;
+            let metadata = entry
+                .metadata()
+                .map_err(io::Error::from)
+                .with_path_context(Operation::Metadata, entry_path)?;
 
             let is_dir = metadata.is_dir();
             let is_file = metadata.is_file();
@@ -157,8 +146,11 @@ mod tests {
         let d = tempdir().unwrap();
         let missing = d.path().join("does_not_exist");
         let err = DirQuery::new(&missing).collect().unwrap_err();
-        // Error text comes from walkdir mapping; check it mentions "walk error"
-        assert!(err.to_string().contains("walk error"), "expected mapped walk error, got: {err}");
+
+        let fs_err = FsError::downcast_ref(&err).expect("structured walk error");
+        assert_eq!(fs_err.operation(), Operation::ReadDir);
+        assert_eq!(fs_err.path(), missing);
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
     }
 
     #[test]
@@ -0,0 +1,98 @@
+use {
+    crate::{DirQuery, FileSystem},
+    std::{io, path::PathBuf},
+};
+
+impl DirQuery {
+    // Same selection as `collect`, walked through `fs` instead of the disk.
+    // Children are visited in sorted order, depth first.
+    pub fn collect_in(self, fs: &(impl FileSystem + ?Sized)) -> io::Result<Vec<PathBuf>> {
+        let max_depth = if self.recursive { self.depth.unwrap_or(usize::MAX) } else { 1 };
+
+        let mut results = Vec::new();
+        let mut pending: Vec<(PathBuf, usize)> =
+            fs.read_dir(&self.root)?.into_iter().rev().map(|p| (p, 1)).collect();
+
+        while let Some((path, depth)) = pending.pop() {
+            // Symlinks are neither listed nor followed, as with `collect`.
+            let metadata = fs.symlink_metadata(&path)?;
+
+            let should_include = if metadata.is_dir() && self.include_dirs {
+                true
+            } else if metadata.is_file() && self.include_files {
+                self.is_extension_allowed(&path)
+            } else {
+                false
+            };
+
+            if should_include {
+                results.push(path.clone());
+
+                if self.limit.is_some_and(|limit| results.len() >= limit) {
+                    break;
+                }
+            }
+
+            if metadata.is_dir() && depth < max_depth {
+                pending.extend(fs.read_dir(&path)?.into_iter().rev().map(|p| (p, depth + 1)));
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::MemoryFs,
+        std::path::{Path, PathBuf},
+    };
+
+    fn tree() -> io::Result<MemoryFs> {
+        let fs = MemoryFs::new();
+        fs.create_dir_all(Path::new("/root/a/b"))?;
+        fs.write(Path::new("/root/x.rs"), b"")?;
+        fs.write(Path::new("/root/a/y.txt"), b"")?;
+        fs.write(Path::new("/root/a/b/z.rs"), b"")?;
+        Ok(fs)
+    }
+
+    fn paths(list: &[&str]) -> Vec<PathBuf> {
+        list.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn walks_depth_first_in_sorted_order() -> io::Result<()> {
+        let got = DirQuery::new("/root").collect_in(&tree()?)?;
+        assert_eq!(
+            got,
+            paths(&["/root/a", "/root/a/b", "/root/a/b/z.rs", "/root/a/y.txt", "/root/x.rs"])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn honours_filters_depth_and_limit() -> io::Result<()> {
+        let fs = tree()?;
+
+        let rs = DirQuery::new("/root").include_dirs(false).allow_extensions(["rs"]);
+        assert_eq!(rs.collect_in(&fs)?, paths(&["/root/a/b/z.rs", "/root/x.rs"]));
+
+        let shallow = DirQuery::new("/root").recursive(true).depth(1);
+        assert_eq!(shallow.collect_in(&fs)?, paths(&["/root/a", "/root/x.rs"]));
+
+        let flat = DirQuery::new("/root").recursive(false).include_dirs(false);
+        assert_eq!(flat.collect_in(&fs)?, paths(&["/root/x.rs"]));
+
+        assert_eq!(DirQuery::new("/root").limit(2).collect_in(&fs)?.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_root_is_an_error() {
+        let err = DirQuery::new("/nope").collect_in(&MemoryFs::new()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
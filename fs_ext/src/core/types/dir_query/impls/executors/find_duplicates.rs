@@ -0,0 +1,160 @@
+use {
+    crate::{
+        Digest, DirQuery, DuplicateGroup, HashAlgorithm, IoResultExt, Operation, dir, file,
+        file::hashing::hasher::Hasher,
+    },
+    std::{
+        collections::{BTreeMap, HashMap},
+        fs::{self, File},
+        io::{self, Read},
+        path::{Path, PathBuf},
+    },
+};
+
+const PARTIAL_LEN: u64 = 4 * 1024;
+
+impl DirQuery {
+    // Narrowed in three passes so most files are never read in full: by size,
+    // then by a hash of the first 4 KiB, then by a hash of the whole file.
+    pub fn find_duplicates(self, algorithm: HashAlgorithm) -> io::Result<Vec<DuplicateGroup>> {
+        dir::assert_exists(&self.root)?;
+        Hasher::new(algorithm)?;
+
+        let mut by_size: BTreeMap<u64, Vec<PathBuf>> = BTreeMap::new();
+        for (path, size) in unique_files(self.include_dirs(false).collect()?)? {
+            // Empty files are trivially identical and dedupe to nothing.
+            if size > 0 {
+                by_size.entry(size).or_default().push(path);
+            }
+        }
+
+        let mut groups = Vec::new();
+        for (size, candidates) in by_size.into_iter().filter(|(_, c)| c.len() > 1) {
+            for (digest, files) in group_by(candidates, |p| partial_hash(p, algorithm))? {
+                let matches = if size <= PARTIAL_LEN {
+                    vec![(digest, files)]
+                } else {
+                    group_by(files, |p| file::hash(p, algorithm))?
+                };
+                for (digest, mut files) in matches {
+                    files.sort();
+                    groups.push(DuplicateGroup { size, digest, files });
+                }
+            }
+        }
+        groups.sort_by(|a, b| a.files.cmp(&b.files));
+        Ok(groups)
+    }
+}
+
+// Paths that are already hard links to each other share storage, so only the
+// first path seen for each inode is a candidate.
+fn unique_files(paths: Vec<PathBuf>) -> io::Result<Vec<(PathBuf, u64)>> {
+    #[cfg(unix)]
+    let mut seen = std::collections::HashSet::new();
+    let mut files = Vec::with_capacity(paths.len());
+
+    for path in paths {
+        let metadata = fs::metadata(&path).with_path_context(Operation::Metadata, &path)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            if !seen.insert((metadata.dev(), metadata.ino())) {
+                continue;
+            }
+        }
+        files.push((path, metadata.len()));
+    }
+    Ok(files)
+}
+
+fn group_by<F>(paths: Vec<PathBuf>, mut key: F) -> io::Result<Vec<(Digest, Vec<PathBuf>)>>
+where
+    F: FnMut(&Path) -> io::Result<Digest>,
+{
+    let mut groups: HashMap<Digest, Vec<PathBuf>> = HashMap::new();
+    for path in paths {
+        groups.entry(key(&path)?).or_default().push(path);
+    }
+    Ok(groups.into_iter().filter(|(_, files)| files.len() > 1).collect())
+}
+
+fn partial_hash(path: &Path, algorithm: HashAlgorithm) -> io::Result<Digest> {
+    let file = File::open(path).with_path_context(Operation::Open, path)?;
+    let mut head = Vec::with_capacity(PARTIAL_LEN as usize);
+    file.take(PARTIAL_LEN).read_to_end(&mut head).with_path_context(Operation::Read, path)?;
+
+    let mut hasher = Hasher::new(algorithm)?;
+    hasher.update(&head);
+    Ok(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_utils::enabled_hash, tempfile::tempdir};
+
+    #[test]
+    fn groups_identical_files() -> io::Result<()> {
+        let dir = tempdir()?;
+        fs::create_dir(dir.path().join("nested"))?;
+        fs::write(dir.path().join("a.png"), b"same bytes")?;
+        fs::write(dir.path().join("nested/b.png"), b"same bytes")?;
+        fs::write(dir.path().join("c.png"), b"diff bytes")?;
+        fs::write(dir.path().join("d.png"), b"short")?;
+        fs::write(dir.path().join("empty1"), b"")?;
+        fs::write(dir.path().join("empty2"), b"")?;
+
+        let groups = DirQuery::new(dir.path()).find_duplicates(enabled_hash())?;
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].size, 10);
+        assert_eq!(
+            groups[0].files,
+            vec![dir.path().join("a.png"), dir.path().join("nested/b.png")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn large_files_differing_after_the_head_are_kept_apart() -> io::Result<()> {
+        let dir = tempdir()?;
+        let mut a = vec![1u8; PARTIAL_LEN as usize * 3];
+        fs::write(dir.path().join("a.bin"), &a)?;
+        fs::write(dir.path().join("b.bin"), &a)?;
+        *a.last_mut().unwrap() = 2;
+        fs::write(dir.path().join("c.bin"), &a)?;
+
+        let groups = DirQuery::new(dir.path()).find_duplicates(enabled_hash())?;
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].files, vec![dir.path().join("a.bin"), dir.path().join("b.bin")]);
+        assert_eq!(groups[0].digest, file::hash(dir.path().join("a.bin"), enabled_hash())?);
+        Ok(())
+    }
+
+    #[test]
+    fn respects_query_filters() -> io::Result<()> {
+        let dir = tempdir()?;
+        fs::write(dir.path().join("a.png"), b"x")?;
+        fs::write(dir.path().join("b.png"), b"x")?;
+        fs::write(dir.path().join("c.txt"), b"x")?;
+
+        let groups =
+            DirQuery::new(dir.path()).allow_extensions(["png"]).find_duplicates(enabled_hash())?;
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].files.len(), 2);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn existing_hard_links_are_not_duplicates() -> io::Result<()> {
+        let dir = tempdir()?;
+        fs::write(dir.path().join("a"), b"x")?;
+        fs::hard_link(dir.path().join("a"), dir.path().join("b"))?;
+
+        assert!(DirQuery::new(dir.path()).find_duplicates(enabled_hash())?.is_empty());
+        Ok(())
+    }
+}
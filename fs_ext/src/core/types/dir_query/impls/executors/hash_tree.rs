@@ -0,0 +1,110 @@
+use {
+    crate::{Digest, DirQuery, HashAlgorithm, dir, file, file::hashing::hasher::Hasher},
+    std::io,
+};
+
+impl DirQuery {
+    // Each entry becomes a leaf over its kind, relative path and (for files)
+    // content digest; the root hashes the leaves in path order. Renames, moves
+    // and empty directories therefore change the digest, but mtimes don't.
+    pub fn hash_tree(self, algorithm: HashAlgorithm) -> io::Result<Digest> {
+        dir::assert_exists(&self.root)?;
+        let mut root = Hasher::new(algorithm)?;
+
+        let base = self.root.clone();
+        let mut entries = self
+            .collect()?
+            .into_iter()
+            .map(|path| {
+                let rel = path.strip_prefix(&base).expect("query results live under the root");
+                let name = rel
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                (name, path)
+            })
+            .collect::<Vec<_>>();
+        entries.sort();
+
+        for (name, path) in entries {
+            let mut leaf = Hasher::new(algorithm)?;
+            if path.is_dir() {
+                leaf.update(b"d\0");
+                leaf.update(name.as_bytes());
+            } else {
+                leaf.update(b"f\0");
+                leaf.update(name.as_bytes());
+                leaf.update(b"\0");
+                leaf.update(&file::hash(&path, algorithm)?.bytes);
+            }
+            root.update(&leaf.finalize().bytes);
+        }
+        Ok(root.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::test_utils::enabled_hash,
+        std::{fs, path::Path},
+        tempfile::tempdir,
+    };
+
+    fn tree(root: &Path) -> io::Result<()> {
+        fs::create_dir_all(root.join("src/empty"))?;
+        fs::write(root.join("src/main.rs"), b"fn main() {}")?;
+        fs::write(root.join("README.md"), b"# demo")?;
+        Ok(())
+    }
+
+    #[test]
+    fn identical_trees_hash_equal() -> io::Result<()> {
+        let a = tempdir()?;
+        let b = tempdir()?;
+        tree(a.path())?;
+        tree(b.path())?;
+
+        let algorithm = enabled_hash();
+        assert_eq!(
+            DirQuery::new(a.path()).hash_tree(algorithm)?,
+            DirQuery::new(b.path()).hash_tree(algorithm)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn content_rename_and_structure_changes_are_detected() -> io::Result<()> {
+        let dir = tempdir()?;
+        tree(dir.path())?;
+        let algorithm = enabled_hash();
+        let original = DirQuery::new(dir.path()).hash_tree(algorithm)?;
+
+        fs::write(dir.path().join("README.md"), b"# changed")?;
+        let edited = DirQuery::new(dir.path()).hash_tree(algorithm)?;
+        assert_ne!(original, edited);
+
+        fs::rename(dir.path().join("README.md"), dir.path().join("README.txt"))?;
+        let renamed = DirQuery::new(dir.path()).hash_tree(algorithm)?;
+        assert_ne!(edited, renamed);
+
+        fs::remove_dir(dir.path().join("src/empty"))?;
+        assert_ne!(renamed, DirQuery::new(dir.path()).hash_tree(algorithm)?);
+        Ok(())
+    }
+
+    #[test]
+    fn filters_limit_what_is_hashed() -> io::Result<()> {
+        let dir = tempdir()?;
+        tree(dir.path())?;
+        let algorithm = enabled_hash();
+        let only_rs = || DirQuery::new(dir.path()).include_dirs(false).allow_extensions(["rs"]);
+        let before = only_rs().hash_tree(algorithm)?;
+
+        fs::write(dir.path().join("README.md"), b"ignored")?;
+        assert_eq!(before, only_rs().hash_tree(algorithm)?);
+        Ok(())
+    }
+}
@@ -1,3 +1,10 @@
+#[cfg(any(feature = "tar", feature = "zip"))]
+mod archive;
 mod collect;
+mod collect_in;
 mod count;
 mod exists;
+#[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+mod find_duplicates;
+#[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+mod hash_tree;
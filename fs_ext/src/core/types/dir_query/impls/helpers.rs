@@ -1,23 +1,8 @@
-use {
-    crate::{DirQuery, ExtensionFilter, utils::normalize_ext},
-    std::path::Path,
-};
+use {crate::DirQuery, std::path::Path};
 
 impl DirQuery {
     pub(crate) fn is_extension_allowed(&self, path: &Path) -> bool {
-        let Some(filter) = &self.extension_filter else {
-            return true;
-        };
-
-        let ext = path.extension().and_then(|e| e.to_str()).map(|e| normalize_ext(e));
-
-        match (ext, filter) {
-            (Some(ext), ExtensionFilter::Allow(allowed)) => allowed.contains(&ext),
-            (Some(ext), ExtensionFilter::Deny(denied)) => !denied.contains(&ext),
-
-            (None, ExtensionFilter::Allow(_)) => false, // Allow list = must have a listed extension
-            (None, ExtensionFilter::Deny(_)) => true, // Deny list = files without extensions are ok
-        }
+        self.extension_filter.as_ref().is_none_or(|filter| filter.matches(path))
     }
 }
 
@@ -25,6 +10,7 @@ impl DirQuery {
 mod tests {
     use {
         super::*,
+        crate::ExtensionFilter,
         std::{
             collections::HashSet,
             path::{Path, PathBuf},
@@ -1,4 +1,7 @@
-use {crate::utils::normalize_ext, std::collections::HashSet};
+use {
+    crate::utils::normalize_ext,
+    std::{collections::HashSet, path::Path},
+};
 
 #[derive(Debug, Clone)]
 pub enum ExtensionFilter {
@@ -34,4 +37,16 @@ impl ExtensionFilter {
                 .collect(),
         )
     }
+
+    pub(crate) fn matches(&self, path: &Path) -> bool {
+        let ext = path.extension().and_then(|e| e.to_str()).map(normalize_ext);
+
+        match (ext, self) {
+            (Some(ext), ExtensionFilter::Allow(allowed)) => allowed.contains(&ext),
+            (Some(ext), ExtensionFilter::Deny(denied)) => !denied.contains(&ext),
+
+            (None, ExtensionFilter::Allow(_)) => false, // Allow list = must have a listed extension
+            (None, ExtensionFilter::Deny(_)) => true, // Deny list = files without extensions are ok
+        }
+    }
 }
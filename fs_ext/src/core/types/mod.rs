@@ -1,7 +1,9 @@
 mod dir_query;
 mod temp;
+mod vfs;
 
 pub use {
     dir_query::{DirQuery, DirQueryOptions, ExtensionFilter},
     temp::{TempDir, TempFile},
+    vfs::{FaultyFs, MemoryFs, RealFs, Root},
 };
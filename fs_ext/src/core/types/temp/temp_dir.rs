@@ -1,5 +1,5 @@
 use {
-    crate::IoResultExt,
+    crate::{IoResultExt, Operation},
     std::{
         io,
         path::{Path, PathBuf},
@@ -21,7 +21,7 @@ impl TempDir {
             .prefix(".tmp-")
             .tempdir_in(dir)
             .map(Self)
-            .with_path_context("failed to create tempdir in", dir)
+            .with_path_context(Operation::CreateTemp, dir)
     }
 
     pub fn path(&self) -> &Path {
@@ -34,7 +34,7 @@ impl TempDir {
 
     pub fn close(self) -> io::Result<()> {
         let p = self.0.path().to_path_buf();
-        self.0.close().with_path_context("failed to close tempdir", p)
+        self.0.close().with_path_context(Operation::RemoveDir, p)
     }
 }
 
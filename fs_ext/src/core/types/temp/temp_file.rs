@@ -1,5 +1,5 @@
 use {
-    crate::IoResultExt,
+    crate::{CopyOptions, IoResultExt, Operation, file},
     std::{
         fs::File,
         io::{self, Seek, SeekFrom},
@@ -23,7 +23,7 @@ impl TempFile {
             .suffix(".tmp")
             .tempfile_in(dir)
             .map(Self)
-            .with_path_context("failed to create tempfile in", dir)
+            .with_path_context(Operation::CreateTemp, dir)
     }
 
     pub fn as_file(&self) -> &File {
@@ -42,7 +42,7 @@ impl TempFile {
         let dst = path.as_ref().to_owned();
         let src = self.path().to_owned();
         self.0.persist_noclobber(&dst).map_err(|e| e.error).with_paths_context(
-            "failed to persist tempfile (noclobber)",
+            Operation::Persist,
             &src,
             &dst,
         )
@@ -51,33 +51,33 @@ impl TempFile {
     pub fn persist(self, path: impl AsRef<Path>) -> io::Result<File> {
         let dst = path.as_ref().to_owned();
         let src = self.path().to_owned();
-        self.0.persist(&dst).map_err(|e| e.error).with_paths_context(
-            "failed to persist tempfile",
-            &src,
-            &dst,
-        )
+        self.0.persist(&dst).map_err(|e| e.error).with_paths_context(Operation::Persist, &src, &dst)
     }
 
     pub fn keep(self) -> io::Result<(File, PathBuf)> {
         let src = self.path().to_owned();
-        self.0.keep().map_err(|e| e.error).with_path_context("failed to keep tempfile", &src)
+        self.0.keep().map_err(|e| e.error).with_path_context(Operation::Persist, &src)
     }
 
     pub fn copy_from(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
         let src = path.as_ref().to_owned();
         let dst = self.path().to_owned();
 
-        let mut source =
-            File::open(&src).with_path_context("failed to open source for copy", &src)?;
+        let source = File::open(&src).with_path_context(Operation::Open, &src)?;
 
         // Truncate + rewind destination with context
         let tmp = self.as_file_mut();
-        tmp.set_len(0).with_path_context("failed to truncate tempfile", &dst)?;
-        tmp.seek(SeekFrom::Start(0)).with_path_context("failed to seek tempfile to start", &dst)?;
+        tmp.set_len(0).with_path_context(Operation::Truncate, &dst)?;
+        tmp.seek(SeekFrom::Start(0)).with_path_context(Operation::Seek, &dst)?;
 
-        io::copy(&mut source, tmp).with_paths_context("failed to copy", &src, &dst)?;
+        file::copy_data(&source, tmp, &CopyOptions::default()).with_paths_context(
+            Operation::Copy,
+            &src,
+            &dst,
+        )?;
+        tmp.seek(SeekFrom::End(0)).with_path_context(Operation::Seek, &dst)?;
 
-        tmp.sync_all().with_path_context("failed to fsync tempfile", &dst)?;
+        tmp.sync_all().with_path_context(Operation::Sync, &dst)?;
 
         Ok(())
     }
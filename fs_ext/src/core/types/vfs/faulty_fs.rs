@@ -0,0 +1,302 @@
+use {
+    crate::{Fault, FileSystem, IoResultExt, Operation, VfsMetadata},
+    std::{
+        io,
+        path::{Path, PathBuf},
+        sync::{Mutex, MutexGuard},
+    },
+};
+
+// Wraps a backend and fails scripted calls before they reach it, so the inner
+// backend shows exactly what a real failure at that point would leave behind.
+#[derive(Debug, Default)]
+pub struct FaultyFs<S> {
+    inner: S,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    faults: Vec<Armed>,
+    crashed: bool,
+    calls: Vec<(Operation, PathBuf)>,
+}
+
+#[derive(Debug)]
+struct Armed {
+    fault: Fault,
+    seen: usize,
+    fired: usize,
+}
+
+impl<S: FileSystem> FaultyFs<S> {
+    pub fn new(inner: S) -> Self {
+        Self { inner, state: Mutex::default() }
+    }
+
+    pub fn inject(&self, fault: Fault) -> &Self {
+        self.lock().faults.push(Armed { fault, seen: 0, fired: 0 });
+        self
+    }
+
+    pub fn fail(&self, operation: Operation, kind: io::ErrorKind) -> &Self {
+        self.inject(Fault::new(operation, kind))
+    }
+
+    // Disarms all faults and recovers from a crash; the call log is kept.
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.faults.clear();
+        state.crashed = false;
+    }
+
+    pub fn is_crashed(&self) -> bool {
+        self.lock().crashed
+    }
+
+    // Every call seen so far, including the failed ones, in order.
+    pub fn calls(&self) -> Vec<(Operation, PathBuf)> {
+        self.lock().calls.clone()
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn check(&self, operation: Operation, path: &Path, other: Option<&Path>) -> io::Result<()> {
+        let mut state = self.lock();
+        state.calls.push((operation, path.to_path_buf()));
+
+        let error = if state.crashed {
+            Some(io::Error::other("filesystem unavailable after injected crash"))
+        } else {
+            let mut fired = None;
+            for armed in &mut state.faults {
+                let fault = &armed.fault;
+                let on_path =
+                    fault.matches_path(path) || other.is_some_and(|p| fault.matches_path(p));
+                if !fault.covers(operation) || !on_path {
+                    continue;
+                }
+                armed.seen += 1;
+                if armed.seen <= fault.skip || fault.times.is_some_and(|t| armed.fired >= t) {
+                    continue;
+                }
+                armed.fired += 1;
+                fired = Some((fault.error(), fault.crash));
+                break;
+            }
+            fired.map(|(error, crash)| {
+                state.crashed |= crash;
+                error
+            })
+        };
+
+        match (error, other) {
+            (None, _) => Ok(()),
+            (Some(e), Some(other)) => Err(e).with_paths_context(operation, path, other),
+            (Some(e), None) => Err(e).with_path_context(operation, path),
+        }
+    }
+}
+
+impl<S: FileSystem> FileSystem for FaultyFs<S> {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.check(Operation::Read, path, None)?;
+        self.inner.read(path)
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        self.check(Operation::Write, path, None)?;
+        self.inner.write(path, contents)
+    }
+
+    fn append(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        self.check(Operation::Write, path, None)?;
+        self.inner.append(path, contents)
+    }
+
+    fn create_new(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        self.check(Operation::Create, path, None)?;
+        self.inner.create_new(path, contents)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<VfsMetadata> {
+        self.check(Operation::Metadata, path, None)?;
+        self.inner.metadata(path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<VfsMetadata> {
+        self.check(Operation::Metadata, path, None)?;
+        self.inner.symlink_metadata(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        self.check(Operation::ReadDir, path, None)?;
+        self.inner.read_dir(path)
+    }
+
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        self.check(Operation::CreateDir, path, None)?;
+        self.inner.create_dir(path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        self.check(Operation::CreateDir, path, None)?;
+        self.inner.create_dir_all(path)
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        self.check(Operation::Remove, path, None)?;
+        self.inner.remove_file(path)
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        self.check(Operation::RemoveDir, path, None)?;
+        self.inner.remove_dir(path)
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        self.check(Operation::RemoveDir, path, None)?;
+        self.inner.remove_dir_all(path)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        self.check(Operation::Move, from, Some(to))?;
+        self.inner.rename(from, to)
+    }
+
+    fn copy(&self, from: &Path, to: &Path) -> io::Result<u64> {
+        self.check(Operation::Copy, from, Some(to))?;
+        self.inner.copy(from, to)
+    }
+
+    fn sync(&self, path: &Path) -> io::Result<()> {
+        self.check(Operation::Sync, path, None)?;
+        self.inner.sync(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{CodecError, FsError, MemoryFs, formats::Json, vfs::file},
+    };
+
+    fn faulty() -> io::Result<FaultyFs<MemoryFs>> {
+        let fs = FaultyFs::new(MemoryFs::new());
+        fs.inner().write(Path::new("data.json"), b"{\"v\":1}")?;
+        Ok(fs)
+    }
+
+    fn write_v2(fs: &impl FileSystem) -> io::Result<()> {
+        file::atomic::overwrite(fs, "data.json", |buf| -> io::Result<()> {
+            buf.extend_from_slice(b"{\"v\":2}");
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn out_of_space_on_write_keeps_old_contents() -> io::Result<()> {
+        let fs = faulty()?;
+        fs.inject(Fault::new(Operation::Create, io::ErrorKind::StorageFull));
+
+        let err = write_v2(&fs).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::StorageFull);
+        assert_eq!(FsError::downcast_ref(&err).unwrap().operation(), Operation::Create);
+        assert_eq!(fs.inner().read(Path::new("data.json"))?, b"{\"v\":1}");
+        assert_eq!(fs.inner().paths(), vec![PathBuf::from("data.json")]);
+        Ok(())
+    }
+
+    #[test]
+    fn failed_persist_removes_the_staged_file() -> io::Result<()> {
+        let fs = faulty()?;
+        fs.fail(Operation::Persist, io::ErrorKind::CrossesDevices);
+
+        let err = write_v2(&fs).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::CrossesDevices);
+        assert_eq!(fs.inner().read(Path::new("data.json"))?, b"{\"v\":1}");
+        assert_eq!(fs.inner().paths(), vec![PathBuf::from("data.json")]);
+        let ops: Vec<_> = fs.calls().into_iter().map(|(op, _)| op).collect();
+        assert_eq!(ops, [Operation::Create, Operation::Sync, Operation::Move, Operation::Remove]);
+        Ok(())
+    }
+
+    #[test]
+    fn crash_before_persist_leaves_staged_file_behind() -> io::Result<()> {
+        let fs = faulty()?;
+        fs.inject(Fault::new(Operation::Persist, io::ErrorKind::Other).crash());
+
+        assert!(write_v2(&fs).is_err());
+        assert!(fs.is_crashed());
+        assert!(fs.read(Path::new("data.json")).is_err());
+
+        let inner = fs.inner();
+        assert_eq!(inner.read(Path::new("data.json"))?, b"{\"v\":1}");
+        assert_eq!(inner.paths().len(), 2, "staged temp file survives the crash");
+
+        fs.clear();
+        write_v2(&fs)?;
+        assert_eq!(fs.read(Path::new("data.json"))?, b"{\"v\":2}");
+        Ok(())
+    }
+
+    #[test]
+    fn open_fault_covers_reads_and_maps_into_codec_errors() -> io::Result<()> {
+        let fs = faulty()?;
+        fs.inject(Fault::new(Operation::Open, io::ErrorKind::PermissionDenied).at("data.json"));
+
+        let err = file::load::<serde_json::Value, Json>(&fs, "data.json").unwrap_err();
+        assert!(
+            matches!(&err, CodecError::Io(e) if e.kind() == io::ErrorKind::PermissionDenied),
+            "{err:?}"
+        );
+        assert!(fs.read(Path::new("other")).unwrap_err().kind() == io::ErrorKind::NotFound);
+        Ok(())
+    }
+
+    #[test]
+    fn skip_and_times_select_which_calls_fail() -> io::Result<()> {
+        let fs = FaultyFs::new(MemoryFs::new());
+        fs.inject(Fault::new(Operation::Write, io::ErrorKind::Interrupted).skip(1).once());
+
+        let path = Path::new("f");
+        fs.write(path, b"1")?;
+        assert_eq!(fs.write(path, b"2").unwrap_err().kind(), io::ErrorKind::Interrupted);
+        fs.write(path, b"3")?;
+        assert_eq!(fs.inner().read(path)?, b"3");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn wraps_the_real_backend_with_exact_errno() -> io::Result<()> {
+        use {crate::RealFs, tempfile::tempdir};
+
+        const ENOSPC: i32 = 28;
+        let dir = tempdir()?;
+        let path = dir.path().join("out.txt");
+        let fs = FaultyFs::new(RealFs);
+        fs.inject(Fault::os_error(Operation::Write, ENOSPC).at(dir.path()));
+
+        let err = file::write(&fs, &path, "x").unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::StorageFull);
+        assert_eq!(FsError::downcast_ref(&err).unwrap().io_error().raw_os_error(), Some(ENOSPC));
+        assert!(err.to_string().contains("out.txt"), "{err}");
+        assert!(!path.exists());
+        Ok(())
+    }
+}
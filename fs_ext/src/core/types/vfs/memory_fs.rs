@@ -0,0 +1,407 @@
+use {
+    crate::{FileSystem, IoResultExt, Operation, PathKind, VfsMetadata},
+    std::{
+        collections::BTreeMap,
+        io,
+        path::{Component, Path, PathBuf},
+        sync::{Arc, Mutex, MutexGuard},
+        time::SystemTime,
+    },
+};
+
+// Clones share the same tree, so a test can hand one to the code under test and
+// inspect the result through another.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFs {
+    nodes: Arc<Mutex<BTreeMap<PathBuf, Node>>>,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    File { contents: Vec<u8>, modified: SystemTime },
+    Dir { modified: SystemTime },
+}
+
+impl MemoryFs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Every entry in path order, relative to the root.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.lock().keys().cloned().collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<PathBuf, Node>> {
+        self.nodes.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+// There is no working directory: `/a`, `a` and `./x/../a` all name the same
+// entry, and `..` never climbs above the root.
+fn key(path: &Path) -> PathBuf {
+    let mut key = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => key.push(name),
+            Component::ParentDir => {
+                key.pop();
+            }
+            Component::Prefix(_) | Component::RootDir | Component::CurDir => {}
+        }
+    }
+    key
+}
+
+fn kind(nodes: &BTreeMap<PathBuf, Node>, key: &Path) -> Option<PathKind> {
+    if key.as_os_str().is_empty() {
+        return Some(PathKind::Dir);
+    }
+    nodes.get(key).map(|node| match node {
+        Node::File { .. } => PathKind::File,
+        Node::Dir { .. } => PathKind::Dir,
+    })
+}
+
+fn descendants<'a>(
+    nodes: &'a BTreeMap<PathBuf, Node>, key: &'a Path,
+) -> impl Iterator<Item = &'a PathBuf> {
+    nodes
+        .range(key.to_path_buf()..)
+        .map(|(k, _)| k)
+        .skip_while(move |k| *k == key)
+        .take_while(move |k| k.starts_with(key))
+}
+
+fn check_parent(nodes: &BTreeMap<PathBuf, Node>, key: &Path) -> Result<(), io::ErrorKind> {
+    match key.parent().map_or(Some(PathKind::Dir), |parent| kind(nodes, parent)) {
+        Some(PathKind::Dir) => Ok(()),
+        Some(_) => Err(io::ErrorKind::NotADirectory),
+        None => Err(io::ErrorKind::NotFound),
+    }
+}
+
+fn fail<T>(kind: io::ErrorKind, operation: Operation, path: &Path) -> io::Result<T> {
+    Err(io::Error::from(kind)).with_path_context(operation, path)
+}
+
+impl FileSystem for MemoryFs {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let nodes = self.lock();
+        let key = key(path);
+        match nodes.get(&key) {
+            Some(Node::File { contents, .. }) => Ok(contents.clone()),
+            _ if kind(&nodes, &key).is_some() => {
+                fail(io::ErrorKind::IsADirectory, Operation::Read, path)
+            }
+            _ => fail(io::ErrorKind::NotFound, Operation::Read, path),
+        }
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        let mut nodes = self.lock();
+        let key = key(path);
+        match kind(&nodes, &key) {
+            Some(PathKind::File) => {}
+            Some(_) => return fail(io::ErrorKind::IsADirectory, Operation::Write, path),
+            None => check_parent(&nodes, &key).or_else(|e| fail(e, Operation::Write, path))?,
+        }
+        nodes.insert(key, Node::File { contents: contents.to_vec(), modified: SystemTime::now() });
+        Ok(())
+    }
+
+    fn append(&self, path: &Path, data: &[u8]) -> io::Result<()> {
+        let mut nodes = self.lock();
+        let key = key(path);
+        match kind(&nodes, &key) {
+            Some(PathKind::File) => {}
+            Some(_) => return fail(io::ErrorKind::IsADirectory, Operation::Open, path),
+            None => return fail(io::ErrorKind::NotFound, Operation::Open, path),
+        }
+        if let Some(Node::File { contents, modified }) = nodes.get_mut(&key) {
+            contents.extend_from_slice(data);
+            *modified = SystemTime::now();
+        }
+        Ok(())
+    }
+
+    fn create_new(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        let mut nodes = self.lock();
+        let key = key(path);
+        if kind(&nodes, &key).is_some() {
+            return fail(io::ErrorKind::AlreadyExists, Operation::Create, path);
+        }
+        check_parent(&nodes, &key).or_else(|e| fail(e, Operation::Create, path))?;
+        nodes.insert(key, Node::File { contents: contents.to_vec(), modified: SystemTime::now() });
+        Ok(())
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<VfsMetadata> {
+        let nodes = self.lock();
+        let key = key(path);
+        match nodes.get(&key) {
+            Some(Node::File { contents, modified }) => Ok(VfsMetadata {
+                kind: PathKind::File,
+                len: contents.len() as u64,
+                modified: Some(*modified),
+            }),
+            Some(Node::Dir { modified }) => {
+                Ok(VfsMetadata { kind: PathKind::Dir, len: 0, modified: Some(*modified) })
+            }
+            None if key.as_os_str().is_empty() => {
+                Ok(VfsMetadata { kind: PathKind::Dir, len: 0, modified: None })
+            }
+            None => fail(io::ErrorKind::NotFound, Operation::Metadata, path),
+        }
+    }
+
+    // There are no symlinks in memory, so nothing is ever followed.
+    fn symlink_metadata(&self, path: &Path) -> io::Result<VfsMetadata> {
+        self.metadata(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let nodes = self.lock();
+        let key = key(path);
+        match kind(&nodes, &key) {
+            Some(PathKind::Dir) => {}
+            Some(_) => return fail(io::ErrorKind::NotADirectory, Operation::ReadDir, path),
+            None => return fail(io::ErrorKind::NotFound, Operation::ReadDir, path),
+        }
+        Ok(descendants(&nodes, &key)
+            .filter(|k| k.parent() == Some(key.as_path()))
+            .filter_map(|k| k.file_name())
+            .map(|name| path.join(name))
+            .collect())
+    }
+
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        let mut nodes = self.lock();
+        let key = key(path);
+        if kind(&nodes, &key).is_some() {
+            return fail(io::ErrorKind::AlreadyExists, Operation::CreateDir, path);
+        }
+        check_parent(&nodes, &key).or_else(|e| fail(e, Operation::CreateDir, path))?;
+        nodes.insert(key, Node::Dir { modified: SystemTime::now() });
+        Ok(())
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        let mut nodes = self.lock();
+        let key = key(path);
+        let mut current = PathBuf::new();
+        for name in key.iter() {
+            current.push(name);
+            match kind(&nodes, &current) {
+                Some(PathKind::Dir) => {}
+                Some(_) if current == key => {
+                    return fail(io::ErrorKind::AlreadyExists, Operation::CreateDir, path);
+                }
+                Some(_) => return fail(io::ErrorKind::NotADirectory, Operation::CreateDir, path),
+                None => {
+                    nodes.insert(current.clone(), Node::Dir { modified: SystemTime::now() });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        let mut nodes = self.lock();
+        let key = key(path);
+        match kind(&nodes, &key) {
+            Some(PathKind::File) => {
+                nodes.remove(&key);
+                Ok(())
+            }
+            Some(_) => fail(io::ErrorKind::IsADirectory, Operation::Remove, path),
+            None => fail(io::ErrorKind::NotFound, Operation::Remove, path),
+        }
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        let mut nodes = self.lock();
+        let key = key(path);
+        match kind(&nodes, &key) {
+            _ if key.as_os_str().is_empty() => {
+                fail(io::ErrorKind::InvalidInput, Operation::RemoveDir, path)
+            }
+            Some(PathKind::Dir) if descendants(&nodes, &key).next().is_some() => {
+                fail(io::ErrorKind::DirectoryNotEmpty, Operation::RemoveDir, path)
+            }
+            Some(PathKind::Dir) => {
+                nodes.remove(&key);
+                Ok(())
+            }
+            Some(_) => fail(io::ErrorKind::NotADirectory, Operation::RemoveDir, path),
+            None => fail(io::ErrorKind::NotFound, Operation::RemoveDir, path),
+        }
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        let mut nodes = self.lock();
+        let key = key(path);
+        match kind(&nodes, &key) {
+            Some(PathKind::Dir) => {
+                let doomed: Vec<_> = descendants(&nodes, &key).cloned().collect();
+                for k in doomed {
+                    nodes.remove(&k);
+                }
+                nodes.remove(&key);
+                Ok(())
+            }
+            Some(_) => fail(io::ErrorKind::NotADirectory, Operation::RemoveDir, path),
+            None => fail(io::ErrorKind::NotFound, Operation::RemoveDir, path),
+        }
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        let mut nodes = self.lock();
+        let (src, dst) = (key(from), key(to));
+        let fail = |kind: io::ErrorKind| {
+            Err(io::Error::from(kind)).with_paths_context(Operation::Move, from, to)
+        };
+
+        let Some(src_kind) = kind(&nodes, &src) else {
+            return fail(io::ErrorKind::NotFound);
+        };
+        if src == dst {
+            return Ok(());
+        }
+        if src.as_os_str().is_empty() || dst.starts_with(&src) {
+            return fail(io::ErrorKind::InvalidInput);
+        }
+        if let Err(e) = check_parent(&nodes, &dst) {
+            return fail(e);
+        }
+        match (src_kind, kind(&nodes, &dst)) {
+            (_, None) => {}
+            (PathKind::Dir, Some(PathKind::Dir)) if descendants(&nodes, &dst).next().is_some() => {
+                return fail(io::ErrorKind::DirectoryNotEmpty);
+            }
+            (PathKind::Dir, Some(PathKind::Dir)) | (PathKind::File, Some(PathKind::File)) => {
+                nodes.remove(&dst);
+            }
+            (PathKind::Dir, Some(_)) => return fail(io::ErrorKind::NotADirectory),
+            (_, Some(_)) => return fail(io::ErrorKind::IsADirectory),
+        }
+
+        let mut moved: Vec<_> = descendants(&nodes, &src).cloned().collect();
+        moved.push(src.clone());
+        for old in moved {
+            if let Some(node) = nodes.remove(&old) {
+                let new = match old.strip_prefix(&src) {
+                    Ok(rest) if !rest.as_os_str().is_empty() => dst.join(rest),
+                    _ => dst.clone(),
+                };
+                nodes.insert(new, node);
+            }
+        }
+        Ok(())
+    }
+
+    fn copy(&self, from: &Path, to: &Path) -> io::Result<u64> {
+        let mut nodes = self.lock();
+        let (src, dst) = (key(from), key(to));
+        let fail = |kind: io::ErrorKind| {
+            Err(io::Error::from(kind)).with_paths_context(Operation::Copy, from, to)
+        };
+
+        let contents = match nodes.get(&src) {
+            Some(Node::File { contents, .. }) => contents.clone(),
+            _ if kind(&nodes, &src).is_some() => return fail(io::ErrorKind::InvalidInput),
+            _ => return fail(io::ErrorKind::NotFound),
+        };
+        match kind(&nodes, &dst) {
+            Some(PathKind::File) => {}
+            Some(_) => return fail(io::ErrorKind::IsADirectory),
+            None => {
+                if let Err(e) = check_parent(&nodes, &dst) {
+                    return fail(e);
+                }
+            }
+        }
+        let len = contents.len() as u64;
+        nodes.insert(dst, Node::File { contents, modified: SystemTime::now() });
+        Ok(len)
+    }
+
+    fn sync(&self, path: &Path) -> io::Result<()> {
+        match kind(&self.lock(), &key(path)) {
+            Some(_) => Ok(()),
+            None => fail(io::ErrorKind::NotFound, Operation::Sync, path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(path: &str) -> &Path {
+        Path::new(path)
+    }
+
+    #[test]
+    fn resolves_paths_against_one_root() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.create_dir(p("/data"))?;
+        fs.write(p("data/a.txt"), b"a")?;
+
+        assert_eq!(fs.read(p("./data/../data/a.txt"))?, b"a");
+        assert_eq!(fs.read_dir(p("/data"))?, vec![PathBuf::from("/data/a.txt")]);
+        assert_eq!(fs.paths(), vec![PathBuf::from("data"), PathBuf::from("data/a.txt")]);
+        Ok(())
+    }
+
+    #[test]
+    fn mirrors_std_error_kinds() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.create_dir_all(p("a/b"))?;
+        fs.write(p("a/f"), b"x")?;
+
+        let kind = |r: io::Result<()>| r.unwrap_err().kind();
+        assert_eq!(kind(fs.write(p("missing/f"), b"")), io::ErrorKind::NotFound);
+        assert_eq!(kind(fs.write(p("a/f/g"), b"")), io::ErrorKind::NotADirectory);
+        assert_eq!(kind(fs.write(p("a/b"), b"")), io::ErrorKind::IsADirectory);
+        assert_eq!(kind(fs.create_new(p("a/f"), b"")), io::ErrorKind::AlreadyExists);
+        assert_eq!(kind(fs.create_dir(p("a"))), io::ErrorKind::AlreadyExists);
+        assert_eq!(kind(fs.remove_dir(p("a"))), io::ErrorKind::DirectoryNotEmpty);
+        assert_eq!(kind(fs.remove_file(p("a/b"))), io::ErrorKind::IsADirectory);
+        assert_eq!(kind(fs.append(p("a/none"), b"")), io::ErrorKind::NotFound);
+        assert_eq!(kind(fs.rename(p("a"), p("a/b/c"))), io::ErrorKind::InvalidInput);
+
+        let err = fs.read(p("/a/nope")).unwrap_err();
+        assert!(err.to_string().contains("/a/nope"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn rename_moves_whole_subtrees() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.create_dir_all(p("src/nested"))?;
+        fs.write(p("src/nested/f"), b"x")?;
+        fs.write(p("src-sibling"), b"y")?;
+
+        fs.rename(p("src"), p("dst"))?;
+
+        assert_eq!(
+            fs.paths(),
+            ["dst", "dst/nested", "dst/nested/f", "src-sibling"].map(PathBuf::from).to_vec()
+        );
+        assert_eq!(fs.read(p("dst/nested/f"))?, b"x");
+        Ok(())
+    }
+
+    #[test]
+    fn clones_share_state() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.clone().write(p("f"), b"shared")?;
+        fs.append(p("f"), b"!")?;
+
+        assert_eq!(fs.copy(p("f"), p("g"))?, 7);
+        assert_eq!(fs.metadata(p("g"))?.len, 7);
+        assert!(fs.metadata(p("/"))?.is_dir());
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+mod faulty_fs;
+mod memory_fs;
+mod real_fs;
+mod root;
+
+pub use {faulty_fs::FaultyFs, memory_fs::MemoryFs, real_fs::RealFs, root::Root};
@@ -0,0 +1,111 @@
+use {
+    crate::{FileSystem, IoResultExt, Operation, VfsMetadata},
+    std::{
+        fs::{self, File, OpenOptions},
+        io::{self, Write},
+        path::{Path, PathBuf},
+    },
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealFs;
+
+impl FileSystem for RealFs {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        fs::read(path).with_path_context(Operation::Read, path)
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        fs::write(path, contents).with_path_context(Operation::Write, path)
+    }
+
+    fn append(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        let mut file =
+            OpenOptions::new().append(true).open(path).with_path_context(Operation::Open, path)?;
+        file.write_all(contents).with_path_context(Operation::Write, path)
+    }
+
+    fn create_new(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .with_path_context(Operation::Create, path)?;
+        file.write_all(contents).with_path_context(Operation::Write, path)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<VfsMetadata> {
+        fs::metadata(path).map(VfsMetadata::from).with_path_context(Operation::Metadata, path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<VfsMetadata> {
+        fs::symlink_metadata(path)
+            .map(VfsMetadata::from)
+            .with_path_context(Operation::Metadata, path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut children = Vec::new();
+        for entry in fs::read_dir(path).with_path_context(Operation::ReadDir, path)? {
+            children.push(entry.with_path_context(Operation::ReadDir, path)?.path());
+        }
+        children.sort();
+        Ok(children)
+    }
+
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        fs::create_dir(path).with_path_context(Operation::CreateDir, path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        fs::create_dir_all(path).with_path_context(Operation::CreateDir, path)
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        fs::remove_file(path).with_path_context(Operation::Remove, path)
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        fs::remove_dir(path).with_path_context(Operation::RemoveDir, path)
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        fs::remove_dir_all(path).with_path_context(Operation::RemoveDir, path)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        fs::rename(from, to).with_paths_context(Operation::Move, from, to)
+    }
+
+    fn copy(&self, from: &Path, to: &Path) -> io::Result<u64> {
+        fs::copy(from, to).with_paths_context(Operation::Copy, from, to)
+    }
+
+    fn sync(&self, path: &Path) -> io::Result<()> {
+        File::open(path).and_then(|file| file.sync_all()).with_path_context(Operation::Sync, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, tempfile::tempdir};
+
+    #[test]
+    fn roundtrips_through_disk() -> io::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("a.txt");
+
+        RealFs.create_new(&path, b"hello")?;
+        RealFs.append(&path, b" world")?;
+        RealFs.sync(&path)?;
+
+        assert_eq!(fs::read_to_string(&path)?, "hello world");
+        assert_eq!(RealFs.metadata(&path)?.len, 11);
+        assert_eq!(RealFs.read_dir(dir.path())?, vec![path.clone()]);
+
+        let err = RealFs.create_new(&path, b"again").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert!(err.to_string().contains("a.txt"), "{err}");
+        Ok(())
+    }
+}
@@ -0,0 +1,727 @@
+use {
+    crate::{FileSystem, IoResultExt, Operation, VfsMetadata},
+    std::{
+        fs::{self, File, OpenOptions},
+        io::{self, Read, Write},
+        path::{Component, Path, PathBuf},
+    },
+};
+
+// A directory that paths are confined to. Every path is relative to it, and
+// absolute paths, `..` above the root and symlinks leading out are rejected
+// with `PermissionDenied`. On Linux every call goes through descriptors opened
+// with `openat2` and `RESOLVE_BENEATH`, so the kernel enforces the boundary;
+// elsewhere, or when the kernel lacks `openat2`, each existing component is
+// checked first.
+#[derive(Debug)]
+pub struct Root {
+    base: PathBuf,
+    #[cfg(target_os = "linux")]
+    dir: File,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Access {
+    Read,
+    Truncate,
+    Append,
+    CreateNew,
+}
+
+impl Root {
+    pub fn new(base: impl AsRef<Path>) -> io::Result<Self> {
+        let base = base.as_ref();
+        let canonical = fs::canonicalize(base).with_path_context(Operation::Open, base)?;
+        if !canonical.is_dir() {
+            return Err(io::Error::from(io::ErrorKind::NotADirectory))
+                .with_path_context(Operation::Open, base);
+        }
+
+        #[cfg(target_os = "linux")]
+        let dir = {
+            use std::os::unix::fs::OpenOptionsExt;
+            OpenOptions::new()
+                .read(true)
+                .custom_flags(libc::O_PATH | libc::O_DIRECTORY)
+                .open(&canonical)
+                .with_path_context(Operation::Open, base)?
+        };
+
+        Ok(Self {
+            base: canonical,
+            #[cfg(target_os = "linux")]
+            dir,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.base
+    }
+
+    // The checked absolute path for handing to APIs outside the root. Nothing
+    // stops the tree from changing between this check and that later use.
+    pub fn resolve(&self, path: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let path = path.as_ref();
+        let rel = relative(path, Operation::Open)?;
+        self.checked(&rel, path, Operation::Open)
+    }
+
+    // Walks the existing part of `rel` and makes sure no symlink on the way
+    // leads outside the root.
+    fn checked(&self, rel: &Path, path: &Path, operation: Operation) -> io::Result<PathBuf> {
+        let mut current = self.base.clone();
+        for name in rel.iter() {
+            current.push(name);
+            match fs::symlink_metadata(&current) {
+                Ok(meta) if meta.file_type().is_symlink() => {
+                    let target = fs::canonicalize(&current).ok();
+                    if !target.is_some_and(|t| t.starts_with(&self.base)) {
+                        return escapes(operation, path);
+                    }
+                }
+                Ok(_) => {}
+                Err(e) if e.kind() == io::ErrorKind::NotFound => break,
+                Err(e) => return Err(e).with_path_context(operation, path),
+            }
+        }
+        Ok(self.base.join(rel))
+    }
+
+    // Like `checked`, but leaves the last component alone for calls that act
+    // on a symlink itself rather than on what it points to.
+    fn checked_parent(&self, rel: &Path, path: &Path, operation: Operation) -> io::Result<PathBuf> {
+        match (rel.parent(), rel.file_name()) {
+            (Some(parent), Some(name)) => Ok(self.checked(parent, path, operation)?.join(name)),
+            _ => self.checked(rel, path, operation),
+        }
+    }
+
+    fn open(&self, path: &Path, access: Access, operation: Operation) -> io::Result<File> {
+        let rel = relative(path, operation)?;
+
+        #[cfg(target_os = "linux")]
+        match linux::open_beneath(&self.dir, &rel, access.flags()) {
+            Err(e) if e.raw_os_error() == Some(libc::ENOSYS) => {}
+            res => return res.with_path_context(operation, path),
+        }
+
+        let full = self.checked(&rel, path, operation)?;
+        access.options().open(full).with_path_context(operation, path)
+    }
+
+    // The entry's parent, opened beneath the root, and its name for `*at`
+    // calls. `None` means `openat2` is unavailable and the caller should fall
+    // back to checked paths.
+    #[cfg(target_os = "linux")]
+    fn parent(&self, rel: &Path) -> Option<io::Result<(File, std::ffi::CString)>> {
+        use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+        let name = match CString::new(rel.file_name()?.as_bytes()) {
+            Ok(name) => name,
+            Err(e) => return Some(Err(e.into())),
+        };
+        let parent = rel.parent().unwrap_or(Path::new(""));
+        match linux::open_beneath(&self.dir, parent, libc::O_PATH | libc::O_DIRECTORY) {
+            Err(e) if e.raw_os_error() == Some(libc::ENOSYS) => None,
+            res => Some(res.map(|dir| (dir, name))),
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn at(
+        &self, rel: &Path, call: impl FnOnce(&File, &std::ffi::CStr) -> libc::c_int,
+    ) -> Option<io::Result<()>> {
+        Some(self.parent(rel)?.and_then(|(dir, name)| linux::cvt(call(&dir, &name))))
+    }
+
+    // Refuses operations that would remove or replace the root itself.
+    fn entry(&self, path: &Path, operation: Operation) -> io::Result<PathBuf> {
+        let rel = relative(path, operation)?;
+        if rel.as_os_str().is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "refusing to modify the root",
+            ))
+            .with_path_context(operation, path);
+        }
+        Ok(rel)
+    }
+}
+
+impl Access {
+    #[cfg(target_os = "linux")]
+    fn flags(self) -> libc::c_int {
+        match self {
+            Access::Read => libc::O_RDONLY,
+            Access::Truncate => libc::O_WRONLY | libc::O_CREAT | libc::O_TRUNC,
+            Access::Append => libc::O_WRONLY | libc::O_APPEND,
+            Access::CreateNew => libc::O_WRONLY | libc::O_CREAT | libc::O_EXCL,
+        }
+    }
+
+    fn options(self) -> OpenOptions {
+        let mut options = OpenOptions::new();
+        match self {
+            Access::Read => options.read(true),
+            Access::Truncate => options.write(true).create(true).truncate(true),
+            Access::Append => options.append(true),
+            Access::CreateNew => options.write(true).create_new(true),
+        };
+        options
+    }
+}
+
+// Resolves `.` and `..` lexically, so the kernel never sees a `..`.
+fn relative(path: &Path, operation: Operation) -> io::Result<PathBuf> {
+    let mut rel = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => rel.push(name),
+            Component::CurDir => {}
+            Component::ParentDir if rel.pop() => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => {
+                return escapes(operation, path);
+            }
+        }
+    }
+    Ok(rel)
+}
+
+fn escapes<T>(operation: Operation, path: &Path) -> io::Result<T> {
+    Err(io::Error::new(io::ErrorKind::PermissionDenied, "path escapes the root"))
+        .with_path_context(operation, path)
+}
+
+impl FileSystem for Root {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let mut contents = Vec::new();
+        self.open(path, Access::Read, Operation::Read)?
+            .read_to_end(&mut contents)
+            .with_path_context(Operation::Read, path)?;
+        Ok(contents)
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        self.open(path, Access::Truncate, Operation::Write)?
+            .write_all(contents)
+            .with_path_context(Operation::Write, path)
+    }
+
+    fn append(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        self.open(path, Access::Append, Operation::Open)?
+            .write_all(contents)
+            .with_path_context(Operation::Write, path)
+    }
+
+    fn create_new(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        self.open(path, Access::CreateNew, Operation::Create)?
+            .write_all(contents)
+            .with_path_context(Operation::Write, path)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<VfsMetadata> {
+        let rel = relative(path, Operation::Metadata)?;
+
+        #[cfg(target_os = "linux")]
+        match linux::open_beneath(&self.dir, &rel, libc::O_PATH) {
+            Err(e) if e.raw_os_error() == Some(libc::ENOSYS) => {}
+            res => {
+                return res
+                    .and_then(|file| file.metadata())
+                    .map(VfsMetadata::from)
+                    .with_path_context(Operation::Metadata, path);
+            }
+        }
+
+        let full = self.checked(&rel, path, Operation::Metadata)?;
+        fs::metadata(full).map(VfsMetadata::from).with_path_context(Operation::Metadata, path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<VfsMetadata> {
+        let rel = relative(path, Operation::Metadata)?;
+
+        #[cfg(target_os = "linux")]
+        match linux::open_beneath(&self.dir, &rel, libc::O_PATH | libc::O_NOFOLLOW) {
+            Err(e) if e.raw_os_error() == Some(libc::ENOSYS) => {}
+            res => {
+                return res
+                    .and_then(|file| file.metadata())
+                    .map(VfsMetadata::from)
+                    .with_path_context(Operation::Metadata, path);
+            }
+        }
+
+        let full = self.checked_parent(&rel, path, Operation::Metadata)?;
+        fs::symlink_metadata(full)
+            .map(VfsMetadata::from)
+            .with_path_context(Operation::Metadata, path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let rel = relative(path, Operation::ReadDir)?;
+
+        #[cfg(target_os = "linux")]
+        match linux::open_beneath(&self.dir, &rel, libc::O_RDONLY | libc::O_DIRECTORY) {
+            Err(e) if e.raw_os_error() == Some(libc::ENOSYS) => {}
+            res => {
+                let names =
+                    res.and_then(linux::read_dir).with_path_context(Operation::ReadDir, path)?;
+                let mut children: Vec<_> = names.into_iter().map(|name| path.join(name)).collect();
+                children.sort();
+                return Ok(children);
+            }
+        }
+
+        let full = self.checked(&rel, path, Operation::ReadDir)?;
+        let mut children = Vec::new();
+        for entry in fs::read_dir(full).with_path_context(Operation::ReadDir, path)? {
+            children
+                .push(path.join(entry.with_path_context(Operation::ReadDir, path)?.file_name()));
+        }
+        children.sort();
+        Ok(children)
+    }
+
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        let rel = relative(path, Operation::CreateDir)?;
+
+        #[cfg(target_os = "linux")]
+        {
+            use std::os::fd::AsRawFd;
+            // SAFETY: `dir` is an open descriptor and `name` a valid C string.
+            let made = self.at(&rel, |dir, name| unsafe {
+                libc::mkdirat(dir.as_raw_fd(), name.as_ptr(), 0o777)
+            });
+            if let Some(res) = made {
+                return res.with_path_context(Operation::CreateDir, path);
+            }
+        }
+
+        let full = self.checked(&rel, path, Operation::CreateDir)?;
+        fs::create_dir(full).with_path_context(Operation::CreateDir, path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        let rel = relative(path, Operation::CreateDir)?;
+        let mut current = PathBuf::new();
+        for name in rel.iter() {
+            current.push(name);
+            match self.create_dir(&current) {
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                    if !self.metadata(&current)?.is_dir() {
+                        return Err(e);
+                    }
+                }
+                res => res?,
+            }
+        }
+        Ok(())
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        let rel = self.entry(path, Operation::Remove)?;
+
+        #[cfg(target_os = "linux")]
+        {
+            use std::os::fd::AsRawFd;
+            // SAFETY: `dir` is an open descriptor and `name` a valid C string.
+            let removed = self
+                .at(&rel, |dir, name| unsafe { libc::unlinkat(dir.as_raw_fd(), name.as_ptr(), 0) });
+            if let Some(res) = removed {
+                return res.with_path_context(Operation::Remove, path);
+            }
+        }
+
+        let full = self.checked_parent(&rel, path, Operation::Remove)?;
+        fs::remove_file(full).with_path_context(Operation::Remove, path)
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        let rel = self.entry(path, Operation::RemoveDir)?;
+
+        #[cfg(target_os = "linux")]
+        {
+            use std::os::fd::AsRawFd;
+            // SAFETY: `dir` is an open descriptor and `name` a valid C string.
+            let removed = self.at(&rel, |dir, name| unsafe {
+                libc::unlinkat(dir.as_raw_fd(), name.as_ptr(), libc::AT_REMOVEDIR)
+            });
+            if let Some(res) = removed {
+                return res.with_path_context(Operation::RemoveDir, path);
+            }
+        }
+
+        let full = self.checked(&rel, path, Operation::RemoveDir)?;
+        fs::remove_dir(full).with_path_context(Operation::RemoveDir, path)
+    }
+
+    // std's `remove_dir_all` never follows symlinks inside the tree, so on the
+    // fallback only the way to it needs checking.
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        let rel = self.entry(path, Operation::RemoveDir)?;
+
+        #[cfg(target_os = "linux")]
+        if let Some(parent) = self.parent(&rel) {
+            return parent
+                .and_then(|(dir, name)| linux::remove_tree(&dir, &name))
+                .with_path_context(Operation::RemoveDir, path);
+        }
+
+        let full = self.checked_parent(&rel, path, Operation::RemoveDir)?;
+        fs::remove_dir_all(full).with_path_context(Operation::RemoveDir, path)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        let src = self.entry(from, Operation::Move)?;
+        let dst = self.entry(to, Operation::Move)?;
+
+        #[cfg(target_os = "linux")]
+        if let Some((src_parent, dst_parent)) = self.parent(&src).zip(self.parent(&dst)) {
+            use std::os::fd::AsRawFd;
+            let moved = src_parent.and_then(|(src_dir, src_name)| {
+                let (dst_dir, dst_name) = dst_parent?;
+                // SAFETY: both descriptors are open and both names valid C strings.
+                linux::cvt(unsafe {
+                    libc::renameat(
+                        src_dir.as_raw_fd(),
+                        src_name.as_ptr(),
+                        dst_dir.as_raw_fd(),
+                        dst_name.as_ptr(),
+                    )
+                })
+            });
+            return moved.with_paths_context(Operation::Move, from, to);
+        }
+
+        let src = self.checked(&src, from, Operation::Move)?;
+        let dst = self.checked(&dst, to, Operation::Move)?;
+        fs::rename(src, dst).with_paths_context(Operation::Move, from, to)
+    }
+
+    fn copy(&self, from: &Path, to: &Path) -> io::Result<u64> {
+        let mut src = self.open(from, Access::Read, Operation::Copy)?;
+        let mut dst = self.open(to, Access::Truncate, Operation::Copy)?;
+        io::copy(&mut src, &mut dst).with_paths_context(Operation::Copy, from, to)
+    }
+
+    fn sync(&self, path: &Path) -> io::Result<()> {
+        self.open(path, Access::Read, Operation::Sync)?
+            .sync_all()
+            .with_path_context(Operation::Sync, path)
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::{
+        ffi::{CStr, CString, OsStr, OsString},
+        fs::File,
+        io, mem,
+        os::{
+            fd::{AsRawFd, FromRawFd, IntoRawFd},
+            unix::ffi::OsStrExt,
+        },
+        path::Path,
+    };
+
+    pub fn cvt(ret: libc::c_int) -> io::Result<()> {
+        if ret == -1 { Err(io::Error::last_os_error()) } else { Ok(()) }
+    }
+
+    // Lists the directory behind `dir` itself, so what gets read is what
+    // `openat2` checked rather than whatever the path names by now.
+    pub fn read_dir(dir: File) -> io::Result<Vec<OsString>> {
+        let fd = dir.into_raw_fd();
+        // SAFETY: `fd` is an open directory descriptor; the stream takes it over.
+        let stream = unsafe { libc::fdopendir(fd) };
+        if stream.is_null() {
+            let err = io::Error::last_os_error();
+            // SAFETY: `fdopendir` failed, so `fd` is still ours to close.
+            unsafe { libc::close(fd) };
+            return Err(err);
+        }
+
+        let mut names = Vec::new();
+        let res = loop {
+            // SAFETY: `readdir` only reports failures through `errno`, so it
+            // has to be cleared first to tell them from the end of the stream.
+            let entry = unsafe {
+                *libc::__errno_location() = 0;
+                libc::readdir(stream)
+            };
+            if entry.is_null() {
+                let err = io::Error::last_os_error();
+                break if err.raw_os_error() == Some(0) { Ok(()) } else { Err(err) };
+            }
+            // SAFETY: `entry` stays valid until the next `readdir` on `stream`.
+            let name = unsafe { CStr::from_ptr((*entry).d_name.as_ptr()) }.to_bytes();
+            if name != b"." && name != b".." {
+                names.push(OsStr::from_bytes(name).to_owned());
+            }
+        };
+        // SAFETY: `stream` is open and not used after this.
+        unsafe { libc::closedir(stream) };
+        res.map(|()| names)
+    }
+
+    // Removes `name` beneath `parent` one descriptor at a time. Each directory
+    // is opened with `O_NOFOLLOW` relative to the one above it, and symlinks
+    // are unlinked rather than followed, so nothing outside the tree is reached.
+    pub fn remove_tree(parent: &File, name: &CStr) -> io::Result<()> {
+        let flags = libc::O_RDONLY | libc::O_DIRECTORY | libc::O_NOFOLLOW | libc::O_CLOEXEC;
+        // SAFETY: `parent` is an open descriptor and `name` a valid C string.
+        let fd = unsafe { libc::openat(parent.as_raw_fd(), name.as_ptr(), flags) };
+        if fd < 0 {
+            let err = io::Error::last_os_error();
+            // Like std, a symlink is removed itself.
+            if matches!(err.raw_os_error(), Some(libc::ENOTDIR | libc::ELOOP))
+                && is_symlink(parent, name)
+            {
+                // SAFETY: as above.
+                return cvt(unsafe { libc::unlinkat(parent.as_raw_fd(), name.as_ptr(), 0) });
+            }
+            return Err(err);
+        }
+        // SAFETY: `openat` just handed us this descriptor.
+        let dir = unsafe { File::from_raw_fd(fd) };
+
+        for child in read_dir(dir.try_clone()?)? {
+            let child = CString::new(child.as_bytes())?;
+            // SAFETY: `dir` is open and `child` a valid C string.
+            match cvt(unsafe { libc::unlinkat(dir.as_raw_fd(), child.as_ptr(), 0) }) {
+                Err(e) if e.raw_os_error() == Some(libc::EISDIR) => remove_tree(&dir, &child)?,
+                res => res?,
+            }
+        }
+
+        // SAFETY: `parent` is an open descriptor and `name` a valid C string.
+        cvt(unsafe { libc::unlinkat(parent.as_raw_fd(), name.as_ptr(), libc::AT_REMOVEDIR) })
+    }
+
+    fn is_symlink(parent: &File, name: &CStr) -> bool {
+        // SAFETY: `stat` is plain old data and only read after a successful call.
+        let mut stat: libc::stat = unsafe { mem::zeroed() };
+        let flags = libc::AT_SYMLINK_NOFOLLOW;
+        // SAFETY: `parent` is an open descriptor and `name` a valid C string.
+        let res = unsafe { libc::fstatat(parent.as_raw_fd(), name.as_ptr(), &mut stat, flags) };
+        res == 0 && stat.st_mode & libc::S_IFMT == libc::S_IFLNK
+    }
+
+    // `openat2` with `RESOLVE_BENEATH` fails with `EXDEV` whenever resolution
+    // would leave `dir`, whether through `..`, an absolute symlink or a
+    // relative one climbing out.
+    pub fn open_beneath(dir: &File, rel: &Path, flags: libc::c_int) -> io::Result<File> {
+        let rel = if rel.as_os_str().is_empty() { Path::new(".") } else { rel };
+        let path = CString::new(rel.as_os_str().as_bytes())?;
+
+        // SAFETY: `open_how` is plain old data; all-zero is its default.
+        let mut how: libc::open_how = unsafe { mem::zeroed() };
+        how.flags = (flags | libc::O_CLOEXEC) as u64;
+        how.mode = if flags & libc::O_CREAT != 0 { 0o666 } else { 0 };
+        how.resolve = libc::RESOLVE_BENEATH | libc::RESOLVE_NO_MAGICLINKS;
+
+        loop {
+            // SAFETY: `path` and `how` outlive the call and `size` matches `how`.
+            let fd = unsafe {
+                libc::syscall(
+                    libc::SYS_openat2,
+                    dir.as_raw_fd(),
+                    path.as_ptr(),
+                    &how as *const libc::open_how,
+                    mem::size_of::<libc::open_how>(),
+                )
+            };
+            if fd >= 0 {
+                // SAFETY: the kernel just handed us this descriptor.
+                return Ok(unsafe { File::from_raw_fd(fd as libc::c_int) });
+            }
+            let err = io::Error::last_os_error();
+            match err.raw_os_error() {
+                // A concurrent rename can make the kernel ask for a retry.
+                Some(libc::EAGAIN | libc::EINTR) => continue,
+                Some(libc::EXDEV) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::PermissionDenied,
+                        "path escapes the root",
+                    ));
+                }
+                _ => return Err(err),
+            }
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use {
+        super::*,
+        crate::{DirQuery, PathKind, vfs},
+        std::os::unix::fs::symlink,
+        tempfile::tempdir,
+    };
+
+    fn denied(res: io::Result<impl std::fmt::Debug>) {
+        let err = res.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied, "{err}");
+    }
+
+    #[test]
+    fn rejects_lexical_traversal_and_absolute_paths() -> io::Result<()> {
+        let tmp = tempdir()?;
+        fs::create_dir(tmp.path().join("jail"))?;
+        let root = Root::new(tmp.path().join("jail"))?;
+
+        denied(root.write(Path::new("../outside.txt"), b"x"));
+        denied(root.write(Path::new("a/../../outside.txt"), b"x"));
+        denied(root.read(Path::new("/etc/passwd")));
+        denied(root.resolve("../x"));
+        assert!(!tmp.path().join("outside.txt").exists());
+
+        root.create_dir(Path::new("a"))?;
+        root.write(Path::new("a/../b.txt"), b"ok")?;
+        assert_eq!(fs::read(tmp.path().join("jail/b.txt"))?, b"ok");
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_symlinks_leading_out() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let (jail, outside) = (tmp.path().join("jail"), tmp.path().join("outside"));
+        fs::create_dir(&jail)?;
+        fs::create_dir(&outside)?;
+        fs::write(outside.join("secret"), b"s")?;
+        symlink(&outside, jail.join("abs"))?;
+        symlink("../outside/secret", jail.join("rel"))?;
+        let root = Root::new(&jail)?;
+
+        denied(root.read(Path::new("abs/secret")));
+        denied(root.read(Path::new("rel")));
+        denied(root.write(Path::new("abs/new"), b"x"));
+        denied(root.create_dir(Path::new("abs/dir")));
+        denied(root.remove_file(Path::new("abs/secret")));
+        denied(root.resolve("abs/secret"));
+        assert!(outside.join("secret").exists());
+        assert!(!outside.join("new").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn follows_symlinks_that_stay_inside() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let root = Root::new(tmp.path())?;
+        root.create_dir(Path::new("real"))?;
+        root.write(Path::new("real/f"), b"inside")?;
+        symlink("real", tmp.path().join("alias"))?;
+
+        assert_eq!(root.read(Path::new("alias/f"))?, b"inside");
+        assert_eq!(root.resolve("alias/f")?, root.path().join("alias/f"));
+        Ok(())
+    }
+
+    #[test]
+    fn remove_dir_all_leaves_symlinked_targets_alone() -> io::Result<()> {
+        let tmp = tempdir()?;
+        fs::create_dir_all(tmp.path().join("jail/tree/sub"))?;
+        fs::create_dir(tmp.path().join("outside"))?;
+        fs::write(tmp.path().join("outside/keep.txt"), b"keep")?;
+        fs::write(tmp.path().join("jail/tree/sub/f"), b"x")?;
+        symlink(tmp.path().join("outside"), tmp.path().join("jail/tree/sub/out"))?;
+        symlink(tmp.path().join("outside"), tmp.path().join("jail/link"))?;
+        let root = Root::new(tmp.path().join("jail"))?;
+
+        assert_eq!(
+            root.read_dir(Path::new("tree/sub"))?,
+            [PathBuf::from("tree/sub/f"), PathBuf::from("tree/sub/out")]
+        );
+        root.remove_dir_all(Path::new("tree"))?;
+        root.remove_dir_all(Path::new("link"))?;
+
+        assert_eq!(fs::read_dir(tmp.path().join("jail"))?.count(), 0);
+        assert_eq!(fs::read(tmp.path().join("outside/keep.txt"))?, b"keep");
+        Ok(())
+    }
+
+    #[test]
+    fn walks_report_symlinks_without_following_them() -> io::Result<()> {
+        let tmp = tempdir()?;
+        fs::create_dir_all(tmp.path().join("jail/src"))?;
+        fs::create_dir(tmp.path().join("outside"))?;
+        fs::write(tmp.path().join("outside/secret"), b"s")?;
+        fs::write(tmp.path().join("jail/src/a.txt"), b"a")?;
+        symlink("missing", tmp.path().join("jail/src/dangling"))?;
+        symlink(tmp.path().join("outside"), tmp.path().join("jail/src/out"))?;
+        let root = Root::new(tmp.path().join("jail"))?;
+
+        assert_eq!(root.symlink_metadata(Path::new("src/out"))?.kind, PathKind::SymLink);
+        assert_eq!(root.symlink_metadata(Path::new("src/dangling"))?.kind, PathKind::SymLink);
+        denied(root.metadata(Path::new("src/out")));
+
+        let listed = DirQuery::new("src").collect_in(&root)?;
+        assert_eq!(listed, [PathBuf::from("src/a.txt")]);
+
+        vfs::dir::copy(&root, "src", "dst")?;
+        assert_eq!(root.read_dir(Path::new("dst"))?, [PathBuf::from("dst/a.txt")]);
+        Ok(())
+    }
+
+    #[test]
+    fn create_with_renames_inside_the_root() -> io::Result<()> {
+        use crate::{CollisionStrategy, RenameOptions, WriteOptions};
+
+        let tmp = tempdir()?;
+        let root = Root::new(tmp.path())?;
+        root.write(Path::new("a.txt"), b"first")?;
+
+        let opts = WriteOptions {
+            collision: CollisionStrategy::Rename(RenameOptions::Counter),
+            ..Default::default()
+        };
+        let created = vfs::file::create_with(&root, "a.txt", "second", opts)?;
+
+        assert_eq!(created.as_deref(), Some(Path::new("./a_1.txt")));
+        assert_eq!(fs::read(tmp.path().join("a_1.txt"))?, b"second");
+        Ok(())
+    }
+
+    #[test]
+    fn scopes_the_vfs_helpers() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let root = Root::new(tmp.path())?;
+
+        vfs::dir::ensure(&root, "uploads/2024")?;
+        vfs::file::atomic::overwrite(&root, "uploads/2024/a.txt", |buf| -> io::Result<()> {
+            buf.extend_from_slice(b"upload");
+            Ok(())
+        })?;
+        root.rename(Path::new("uploads/2024/a.txt"), Path::new("uploads/b.txt"))?;
+        vfs::file::copy(&root, "uploads/b.txt", "uploads/2024/c.txt")?;
+
+        let listed = DirQuery::new("uploads").include_dirs(false).collect_in(&root)?;
+        assert_eq!(listed, [PathBuf::from("uploads/2024/c.txt"), PathBuf::from("uploads/b.txt")]);
+        assert_eq!(fs::read(tmp.path().join("uploads/b.txt"))?, b"upload");
+
+        vfs::dir::clear(&root, "uploads")?;
+        assert!(vfs::dir::is_empty(&root, "uploads")?);
+        denied(root.remove_dir_all(Path::new(".")));
+        denied(root.rename(Path::new(""), Path::new("x")));
+        Ok(())
+    }
+
+    #[test]
+    fn keeps_std_error_kinds_inside_the_root() -> io::Result<()> {
+        let tmp = tempdir()?;
+        let root = Root::new(tmp.path())?;
+        root.write(Path::new("f"), b"")?;
+
+        assert_eq!(root.read(Path::new("missing")).unwrap_err().kind(), io::ErrorKind::NotFound);
+        assert_eq!(
+            root.create_new(Path::new("f"), b"").unwrap_err().kind(),
+            io::ErrorKind::AlreadyExists
+        );
+        assert_eq!(
+            Root::new(tmp.path().join("f")).unwrap_err().kind(),
+            io::ErrorKind::NotADirectory
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn assert_exists(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<()> {
+    _assert_exists(fs, path.as_ref())
+}
+
+fn _assert_exists(fs: &(impl FileSystem + ?Sized), path: &Path) -> io::Result<()> {
+    let meta = fs.metadata(path)?;
+
+    if !meta.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Path '{}' exists but is not a directory (found: {:?})",
+                path.display(),
+                meta.kind
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn accepts_dirs_only() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.create_dir(Path::new("d"))?;
+        fs.write(Path::new("f"), b"")?;
+
+        assert_exists(&fs, "d")?;
+        assert_exists(&fs, "/")?;
+        assert_eq!(assert_exists(&fs, "f").unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(assert_exists(&fs, "x").unwrap_err().kind(), io::ErrorKind::NotFound);
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use {
+    crate::{FileSystem, vfs::dir},
+    std::{io, path::Path},
+};
+
+pub fn clear(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<()> {
+    _clear(fs, path.as_ref())
+}
+
+fn _clear(fs: &(impl FileSystem + ?Sized), path: &Path) -> io::Result<()> {
+    dir::assert_exists(fs, path)?;
+
+    // Symlinks are unlinked, never followed, even when dangling.
+    for child in fs.read_dir(path)? {
+        if fs.symlink_metadata(&child)?.is_dir() {
+            fs.remove_dir_all(&child)?;
+        } else {
+            fs.remove_file(&child)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs, std::path::PathBuf};
+
+    #[test]
+    fn removes_children_but_keeps_dir() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.create_dir_all(Path::new("cache/nested"))?;
+        fs.write(Path::new("cache/nested/a"), b"")?;
+        fs.write(Path::new("cache/b"), b"")?;
+        fs.write(Path::new("keep"), b"")?;
+
+        clear(&fs, "cache")?;
+
+        assert_eq!(fs.paths(), vec![PathBuf::from("cache"), PathBuf::from("keep")]);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unlinks_symlinks_without_following_them() -> io::Result<()> {
+        use {crate::Root, std::os::unix::fs::symlink};
+
+        let tmp = tempfile::tempdir()?;
+        std::fs::create_dir_all(tmp.path().join("jail/up"))?;
+        std::fs::create_dir(tmp.path().join("outside"))?;
+        std::fs::write(tmp.path().join("outside/keep"), b"")?;
+        std::fs::write(tmp.path().join("jail/up/a"), b"")?;
+        symlink("missing", tmp.path().join("jail/up/dangling"))?;
+        symlink(tmp.path().join("outside"), tmp.path().join("jail/up/out"))?;
+        let root = Root::new(tmp.path().join("jail"))?;
+
+        clear(&root, "up")?;
+
+        assert_eq!(std::fs::read_dir(tmp.path().join("jail/up"))?.count(), 0);
+        assert!(tmp.path().join("outside/keep").exists());
+        Ok(())
+    }
+}
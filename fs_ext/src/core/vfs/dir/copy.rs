@@ -0,0 +1,53 @@
+use {
+    crate::{FileSystem, vfs::dir},
+    std::{io, path::Path},
+};
+
+pub fn copy(
+    fs: &(impl FileSystem + ?Sized), src: impl AsRef<Path>, dst: impl AsRef<Path>,
+) -> io::Result<()> {
+    _copy(fs, src.as_ref(), dst.as_ref())
+}
+
+fn _copy(fs: &(impl FileSystem + ?Sized), src: &Path, dst: &Path) -> io::Result<()> {
+    dir::assert_exists(fs, src)?;
+    fs.create_dir_all(dst)?;
+
+    for child in fs.read_dir(src)? {
+        let Some(name) = child.file_name() else { continue };
+        let target = dst.join(name);
+        // `FileSystem` can't create links, so symlinks are skipped.
+        let meta = fs.symlink_metadata(&child)?;
+
+        if meta.is_dir() {
+            _copy(fs, &child, &target)?;
+        } else if meta.is_file() {
+            fs.copy(&child, &target)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs, std::path::PathBuf};
+
+    #[test]
+    fn copies_tree_recursively() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.create_dir_all(Path::new("src/sub/empty"))?;
+        fs.write(Path::new("src/a"), b"a")?;
+        fs.write(Path::new("src/sub/b"), b"b")?;
+
+        copy(&fs, "src", "dst")?;
+
+        let copied: Vec<_> = fs.paths().into_iter().filter(|p| p.starts_with("dst")).collect();
+        assert_eq!(
+            copied,
+            ["dst", "dst/a", "dst/sub", "dst/sub/b", "dst/sub/empty"].map(PathBuf::from).to_vec()
+        );
+        assert_eq!(fs.read(Path::new("dst/sub/b"))?, b"b");
+        Ok(())
+    }
+}
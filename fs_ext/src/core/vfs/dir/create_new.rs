@@ -0,0 +1,21 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn create_new(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<()> {
+    fs.create_dir(path.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn rejects_existing_dir() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        create_new(&fs, "d")?;
+        assert_eq!(create_new(&fs, "d").unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+        Ok(())
+    }
+}
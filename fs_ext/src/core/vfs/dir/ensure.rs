@@ -0,0 +1,25 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn ensure(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<()> {
+    fs.create_dir_all(path.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn creates_missing_ancestors_and_is_idempotent() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        ensure(&fs, "a/b/c")?;
+        ensure(&fs, "a/b/c")?;
+
+        assert!(fs.metadata(Path::new("a/b"))?.is_dir());
+        fs.write(Path::new("a/f"), b"")?;
+        assert_eq!(ensure(&fs, "a/f").unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+        Ok(())
+    }
+}
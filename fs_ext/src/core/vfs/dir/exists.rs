@@ -0,0 +1,29 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn exists(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<bool> {
+    match fs.metadata(path.as_ref()) {
+        Ok(meta) => Ok(meta.is_dir()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn only_dirs_exist() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.create_dir(Path::new("d"))?;
+        fs.write(Path::new("f"), b"")?;
+
+        assert!(exists(&fs, "d")?);
+        assert!(!exists(&fs, "f")?);
+        assert!(!exists(&fs, "missing")?);
+        Ok(())
+    }
+}
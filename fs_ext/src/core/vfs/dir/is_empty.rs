@@ -0,0 +1,23 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn is_empty(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<bool> {
+    Ok(fs.read_dir(path.as_ref())?.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn checks_for_children() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.create_dir_all(Path::new("a/b"))?;
+
+        assert!(is_empty(&fs, "a/b")?);
+        assert!(!is_empty(&fs, "a")?);
+        Ok(())
+    }
+}
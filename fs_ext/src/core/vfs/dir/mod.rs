@@ -0,0 +1,12 @@
+mod assert_exists;
+mod clear;
+mod copy;
+mod create_new;
+mod ensure;
+mod exists;
+mod is_empty;
+
+pub use {
+    assert_exists::assert_exists, clear::clear, copy::copy, create_new::create_new, ensure::ensure,
+    exists::exists, is_empty::is_empty,
+};
@@ -0,0 +1,26 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn append(
+    fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>, content: impl AsRef<[u8]>,
+) -> io::Result<()> {
+    fs.append(path.as_ref(), content.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn appends_to_existing_file_only() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("log"), b"a")?;
+        append(&fs, "log", "b")?;
+
+        assert_eq!(fs.read(Path::new("log"))?, b"ab");
+        assert_eq!(append(&fs, "missing", "x").unwrap_err().kind(), io::ErrorKind::NotFound);
+        Ok(())
+    }
+}
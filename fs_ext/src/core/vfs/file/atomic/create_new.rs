@@ -0,0 +1,44 @@
+use {
+    super::persist::persist,
+    crate::FileSystem,
+    std::{error, io, path::Path},
+};
+
+pub fn create_new<F, T, E>(
+    fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>, write_fn: F,
+) -> io::Result<T>
+where
+    E: Into<Box<dyn error::Error + Send + Sync>>,
+    F: FnOnce(&mut Vec<u8>) -> Result<T, E>,
+{
+    let mut contents = Vec::new();
+    let val = write_fn(&mut contents).map_err(|e| io::Error::other(e.into()))?;
+
+    persist(fs, path.as_ref(), &contents, false)?;
+    Ok(val)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs, std::path::PathBuf};
+
+    #[test]
+    fn fails_if_target_exists_and_cleans_up() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        create_new(&fs, "a.txt", |buf| -> io::Result<()> {
+            buf.extend_from_slice(b"first");
+            Ok(())
+        })?;
+
+        let err = create_new(&fs, "a.txt", |buf| -> io::Result<()> {
+            buf.extend_from_slice(b"second");
+            Ok(())
+        })
+        .unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs.read(Path::new("a.txt"))?, b"first");
+        assert_eq!(fs.paths(), vec![PathBuf::from("a.txt")]);
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+mod create_new;
+mod overwrite;
+mod persist;
+mod update;
+
+pub use {create_new::create_new, overwrite::overwrite, update::update};
@@ -0,0 +1,61 @@
+use {
+    super::persist::persist,
+    crate::FileSystem,
+    std::{error, io, path::Path},
+};
+
+pub fn overwrite<F, T, E>(
+    fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>, write_fn: F,
+) -> io::Result<T>
+where
+    E: Into<Box<dyn error::Error + Send + Sync>>,
+    F: FnOnce(&mut Vec<u8>) -> Result<T, E>,
+{
+    let mut contents = Vec::new();
+    let val = write_fn(&mut contents).map_err(|e| io::Error::other(e.into()))?;
+
+    persist(fs, path.as_ref(), &contents, true)?;
+    Ok(val)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{MemoryFs, test_utils::CustomError},
+        std::path::PathBuf,
+    };
+
+    #[test]
+    fn replaces_content_without_leaving_temp_files() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("data.txt"), b"old content that is longer")?;
+
+        let n = overwrite(&fs, "data.txt", |buf| -> io::Result<usize> {
+            buf.extend_from_slice(b"new");
+            Ok(buf.len())
+        })?;
+
+        assert_eq!(n, 3);
+        assert_eq!(fs.read(Path::new("data.txt"))?, b"new");
+        assert_eq!(fs.paths(), vec![PathBuf::from("data.txt")]);
+        Ok(())
+    }
+
+    #[test]
+    fn closure_error_leaves_target_untouched() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("data.txt"), b"keep")?;
+
+        let err = overwrite(&fs, "data.txt", |buf| -> Result<(), CustomError> {
+            buf.extend_from_slice(b"partial");
+            Err(CustomError("nope"))
+        })
+        .unwrap_err();
+
+        assert!(err.to_string().contains("nope"), "{err}");
+        assert_eq!(fs.read(Path::new("data.txt"))?, b"keep");
+        assert_eq!(fs.paths(), vec![PathBuf::from("data.txt")]);
+        Ok(())
+    }
+}
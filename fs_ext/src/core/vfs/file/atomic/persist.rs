@@ -0,0 +1,37 @@
+use {
+    crate::{FileSystem, IoResultExt, Operation, PathExt},
+    std::{io, path::Path},
+    uuid::Uuid,
+};
+
+// Stages `contents` in a hidden sibling, syncs it and renames it over `path`,
+// so readers see either the old file or the new one. The staged file is
+// removed again on failure.
+pub(super) fn persist(
+    fs: &(impl FileSystem + ?Sized), path: &Path, contents: &[u8], replace: bool,
+) -> io::Result<()> {
+    let Some(name) = path.file_name() else {
+        return Err(io::Error::from(io::ErrorKind::InvalidInput))
+            .with_path_context(Operation::CreateTemp, path);
+    };
+    let temp = path.parent_or_current().join(format!(
+        ".{}.{}.tmp",
+        name.to_string_lossy(),
+        Uuid::new_v4().simple()
+    ));
+
+    fs.create_new(&temp, contents)?;
+    let result = fs.sync(&temp).and_then(|()| {
+        // Unlike `TempFile::persist_new` this check and the rename are two
+        // calls, so a concurrent writer can still slip in between them.
+        if !replace && fs.exists(path) {
+            return Err(io::Error::from(io::ErrorKind::AlreadyExists))
+                .with_path_context(Operation::Persist, path);
+        }
+        fs.rename(&temp, path)
+    });
+    if result.is_err() {
+        let _ = fs.remove_file(&temp);
+    }
+    result
+}
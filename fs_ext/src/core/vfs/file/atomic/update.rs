@@ -0,0 +1,48 @@
+use {
+    super::persist::persist,
+    crate::FileSystem,
+    std::{error, io, path::Path},
+};
+
+pub fn update<F, T, E>(
+    fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>, write_fn: F,
+) -> io::Result<T>
+where
+    E: Into<Box<dyn error::Error + Send + Sync>>,
+    F: FnOnce(&mut Vec<u8>) -> Result<T, E>,
+{
+    let path = path.as_ref();
+    let mut contents = fs.read(path)?;
+
+    let val = write_fn(&mut contents).map_err(|e| io::Error::other(e.into()))?;
+
+    persist(fs, path, &contents, true)?;
+    Ok(val)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn edits_existing_content() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("counter.txt"), b"41")?;
+
+        update(&fs, "counter.txt", |buf| -> Result<(), Box<dyn error::Error + Send + Sync>> {
+            let n: u64 = std::str::from_utf8(buf)?.parse()?;
+            *buf = (n + 1).to_string().into_bytes();
+            Ok(())
+        })?;
+
+        assert_eq!(fs.read(Path::new("counter.txt"))?, b"42");
+        Ok(())
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        let fs = MemoryFs::new();
+        let err = update(&fs, "missing", |_| -> io::Result<()> { Ok(()) }).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
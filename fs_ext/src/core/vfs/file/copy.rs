@@ -0,0 +1,25 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn copy(
+    fs: &(impl FileSystem + ?Sized), src: impl AsRef<Path>, dst: impl AsRef<Path>,
+) -> io::Result<u64> {
+    fs.copy(src.as_ref(), dst.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn copies_contents() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("a"), b"abc")?;
+
+        assert_eq!(copy(&fs, "a", "b")?, 3);
+        assert_eq!(fs.read(Path::new("b"))?, b"abc");
+        Ok(())
+    }
+}
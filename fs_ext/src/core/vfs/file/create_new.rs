@@ -0,0 +1,26 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn create_new(
+    fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>, contents: impl AsRef<[u8]>,
+) -> io::Result<()> {
+    fs.create_new(path.as_ref(), contents.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn refuses_to_replace_existing_file() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        create_new(&fs, "a.txt", "first")?;
+
+        let err = create_new(&fs, "a.txt", "second").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs.read(Path::new("a.txt"))?, b"first");
+        Ok(())
+    }
+}
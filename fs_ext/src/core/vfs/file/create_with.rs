@@ -0,0 +1,122 @@
+use {
+    crate::{
+        CollisionStrategy, FileSystem, IoResultExt, Operation, ParentPolicy, PathKind, WriteOptions,
+    },
+    std::{
+        io,
+        path::{Path, PathBuf},
+    },
+};
+
+pub fn create_with(
+    fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>, contents: impl AsRef<[u8]>,
+    options: impl AsRef<WriteOptions>,
+) -> io::Result<Option<PathBuf>> {
+    _create_with(fs, path.as_ref(), contents.as_ref(), options.as_ref())
+}
+
+fn _create_with(
+    fs: &(impl FileSystem + ?Sized), path: &Path, contents: &[u8], options: &WriteOptions,
+) -> io::Result<Option<PathBuf>> {
+    // An empty parent is the filesystem's own root, which always exists.
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        match options.parent {
+            ParentPolicy::RequireExists => {
+                if !fs.metadata(parent).is_ok_and(|meta| meta.kind == PathKind::Dir) {
+                    return Err(io::Error::from(io::ErrorKind::NotFound))
+                        .with_path_context(Operation::Create, parent);
+                }
+            }
+            ParentPolicy::CreateIfMissing => fs.create_dir_all(parent)?,
+        }
+    }
+
+    let created = match &options.collision {
+        CollisionStrategy::Overwrite => fs.write(path, contents),
+        _ => fs.create_new(path, contents),
+    };
+
+    match (created, &options.collision) {
+        (Ok(()), _) => Ok(Some(path.to_path_buf())),
+        (Err(e), CollisionStrategy::Skip) if e.kind() == io::ErrorKind::AlreadyExists => Ok(None),
+        (Err(e), CollisionStrategy::Rename(rename_opts))
+            if e.kind() == io::ErrorKind::AlreadyExists =>
+        {
+            let unique = rename_opts.unique_path_by(path, |cand| fs.exists(cand))?;
+            fs.create_new(&unique, contents)?;
+            Ok(Some(unique))
+        }
+        (Err(e), _) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{MemoryFs, RenameOptions},
+    };
+
+    fn with_collision(collision: CollisionStrategy) -> WriteOptions {
+        WriteOptions { collision, ..Default::default() }
+    }
+
+    #[test]
+    fn creates_new_file() -> io::Result<()> {
+        let fs = MemoryFs::new();
+
+        let created = create_with(&fs, "a.txt", "hello", WriteOptions::default())?;
+
+        assert_eq!(created.as_deref(), Some(Path::new("a.txt")));
+        assert_eq!(fs.read(Path::new("a.txt"))?, b"hello");
+        Ok(())
+    }
+
+    #[test]
+    fn applies_each_collision_strategy() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("a.txt"), b"old")?;
+
+        let err = create_with(&fs, "a.txt", "new", WriteOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        assert_eq!(
+            create_with(&fs, "a.txt", "new", with_collision(CollisionStrategy::Skip))?,
+            None
+        );
+        assert_eq!(fs.read(Path::new("a.txt"))?, b"old");
+
+        create_with(&fs, "a.txt", "new", with_collision(CollisionStrategy::Overwrite))?;
+        assert_eq!(fs.read(Path::new("a.txt"))?, b"new");
+        Ok(())
+    }
+
+    #[test]
+    fn rename_checks_names_inside_the_vfs() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.create_dir(Path::new("dir"))?;
+        fs.write(Path::new("dir/a.txt"), b"first")?;
+        fs.write(Path::new("dir/a_1.txt"), b"second")?;
+
+        let opts = with_collision(CollisionStrategy::Rename(RenameOptions::Counter));
+        let created = create_with(&fs, "dir/a.txt", "third", opts)?.unwrap();
+
+        assert_eq!(created, Path::new("dir/a_2.txt"));
+        assert_eq!(fs.read(&created)?, b"third");
+        assert_eq!(fs.read(Path::new("dir/a_1.txt"))?, b"second");
+        Ok(())
+    }
+
+    #[test]
+    fn parent_policy_is_honoured() -> io::Result<()> {
+        let fs = MemoryFs::new();
+
+        let err = create_with(&fs, "nested/dir/a.txt", "x", WriteOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+        let opts = WriteOptions { parent: ParentPolicy::CreateIfMissing, ..Default::default() };
+        create_with(&fs, "nested/dir/a.txt", "x", opts)?;
+        assert_eq!(fs.read(Path::new("nested/dir/a.txt"))?, b"x");
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use {
+    crate::{FileSystem, IoResultExt, Operation, PathKind},
+    std::{io, path::Path},
+};
+
+pub fn ensure(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<()> {
+    _ensure(fs, path.as_ref())
+}
+
+fn _ensure(fs: &(impl FileSystem + ?Sized), path: &Path) -> io::Result<()> {
+    match fs.metadata(path) {
+        Ok(meta) if meta.kind == PathKind::File => Ok(()),
+        Ok(_) => Err(io::Error::from(io::ErrorKind::IsADirectory))
+            .with_path_context(Operation::Create, path),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => match fs.create_new(path, &[]) {
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(()),
+            res => res,
+        },
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn creates_missing_and_keeps_existing() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("kept"), b"data")?;
+        fs.create_dir(Path::new("dir"))?;
+
+        ensure(&fs, "kept")?;
+        ensure(&fs, "new")?;
+
+        assert_eq!(fs.read(Path::new("kept"))?, b"data");
+        assert_eq!(fs.read(Path::new("new"))?, b"");
+        assert_eq!(ensure(&fs, "dir").unwrap_err().kind(), io::ErrorKind::IsADirectory);
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn exists(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<bool> {
+    match fs.metadata(path.as_ref()) {
+        Ok(meta) => Ok(meta.is_file()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn only_regular_files_exist() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("f"), b"")?;
+        fs.create_dir(Path::new("d"))?;
+
+        assert!(exists(&fs, "f")?);
+        assert!(!exists(&fs, "d")?);
+        assert!(!exists(&fs, "missing")?);
+        Ok(())
+    }
+}
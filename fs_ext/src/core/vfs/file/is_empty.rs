@@ -0,0 +1,24 @@
+use {
+    crate::{FileSystem, vfs::file},
+    std::{io, path::Path},
+};
+
+pub fn is_empty(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<bool> {
+    Ok(file::size(fs, path)? == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn checks_length() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("empty"), b"")?;
+        fs.write(Path::new("full"), b"x")?;
+
+        assert!(is_empty(&fs, "empty")?);
+        assert!(!is_empty(&fs, "full")?);
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use {
+    crate::{CodecError, FileSystem, Format, FsError, Operation},
+    serde::de::DeserializeOwned,
+    std::path::Path,
+};
+
+pub fn load<T, F>(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> Result<T, CodecError>
+where
+    F: Format,
+    T: DeserializeOwned,
+{
+    let path = path.as_ref();
+    let bytes = fs.read(path)?;
+    F::from_reader(bytes.as_slice()).map_err(|e| with_read_context(e, path))
+}
+
+pub(super) fn with_read_context(e: CodecError, path: &Path) -> CodecError {
+    match e {
+        CodecError::Deserialize(e) => CodecError::Deserialize(e.with_path(path)),
+        CodecError::Io(e) => CodecError::Io(FsError::new(Operation::Read, path, e).into()),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{MemoryFs, formats::Json},
+        serde::Deserialize,
+    };
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Demo {
+        id: u32,
+    }
+
+    #[test]
+    fn parses_and_reports_path_on_error() -> std::io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("ok.json"), br#"{ "id": 7 }"#)?;
+        fs.write(Path::new("bad.json"), b"{ id: }")?;
+
+        assert_eq!(load::<Demo, Json>(&fs, "ok.json").unwrap(), Demo { id: 7 });
+        let err = load::<Demo, Json>(&fs, "bad.json").unwrap_err();
+        assert!(matches!(err, CodecError::Deserialize(_)), "{err:?}");
+        assert!(err.to_string().contains("bad.json"), "{err}");
+        Ok(())
+    }
+}
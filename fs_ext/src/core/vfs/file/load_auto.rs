@@ -0,0 +1,40 @@
+use {
+    super::load::with_read_context,
+    crate::{CodecError, FileSystem, FormatRegistry},
+    serde::de::DeserializeOwned,
+    std::path::Path,
+};
+
+pub fn load_auto<T>(
+    fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>,
+) -> Result<T, CodecError>
+where
+    T: DeserializeOwned,
+{
+    let path = path.as_ref();
+    let handler = FormatRegistry::global().resolve(path)?;
+    let bytes = fs.read(path)?;
+    handler.read(&mut bytes.as_slice()).map_err(|e| with_read_context(e, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs, serde::Deserialize};
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Demo {
+        id: u32,
+    }
+
+    #[test]
+    fn picks_format_from_extension() -> std::io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("a.toml"), b"id = 1")?;
+        fs.write(Path::new("b.yaml"), b"id: 2")?;
+
+        assert_eq!(load_auto::<Demo>(&fs, "a.toml").unwrap(), Demo { id: 1 });
+        assert_eq!(load_auto::<Demo>(&fs, "b.yaml").unwrap(), Demo { id: 2 });
+        assert!(load_auto::<Demo>(&fs, "c.nope").is_err());
+        Ok(())
+    }
+}
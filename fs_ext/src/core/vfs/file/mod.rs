@@ -0,0 +1,24 @@
+mod append;
+pub mod atomic;
+mod copy;
+mod create_new;
+mod create_with;
+mod ensure;
+mod exists;
+mod is_empty;
+mod load;
+mod load_auto;
+mod read_bytes;
+mod read_string;
+mod remove;
+mod save;
+mod save_auto;
+mod size;
+mod write;
+
+pub use {
+    append::append, copy::copy, create_new::create_new, create_with::create_with, ensure::ensure,
+    exists::exists, is_empty::is_empty, load::load, load_auto::load_auto, read_bytes::read_bytes,
+    read_string::read_string, remove::remove, save::save, save_auto::save_auto, size::size,
+    write::write,
+};
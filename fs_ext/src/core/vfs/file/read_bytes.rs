@@ -0,0 +1,22 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn read_bytes(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    fs.read(path.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn reads_written_bytes() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("a.bin"), &[0, 1, 2])?;
+        assert_eq!(read_bytes(&fs, "a.bin")?, vec![0, 1, 2]);
+        assert_eq!(read_bytes(&fs, "b.bin").unwrap_err().kind(), io::ErrorKind::NotFound);
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use {
+    crate::{FileSystem, IoResultExt, Operation},
+    std::{io, path::Path},
+};
+
+pub fn read_string(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<String> {
+    _read_string(fs, path.as_ref())
+}
+
+fn _read_string(fs: &(impl FileSystem + ?Sized), path: &Path) -> io::Result<String> {
+    String::from_utf8(fs.read(path)?)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        .with_path_context(Operation::Read, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn reads_utf8_and_rejects_invalid_bytes() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("ok.txt"), "héllo".as_bytes())?;
+        fs.write(Path::new("bad.txt"), &[0xff, 0xfe])?;
+
+        assert_eq!(read_string(&fs, "ok.txt")?, "héllo");
+        let err = read_string(&fs, "bad.txt").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("bad.txt"), "{err}");
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn remove(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<()> {
+    fs.remove_file(path.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn removes_files_but_not_dirs() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("f"), b"")?;
+        fs.create_dir(Path::new("d"))?;
+
+        remove(&fs, "f")?;
+        assert!(!fs.exists(Path::new("f")));
+        assert_eq!(remove(&fs, "d").unwrap_err().kind(), io::ErrorKind::IsADirectory);
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use {
+    crate::{CodecError, FileSystem, Format, vfs::file::atomic},
+    serde::Serialize,
+    std::path::Path,
+};
+
+pub fn save<T, F>(
+    fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>, model: T,
+) -> Result<(), CodecError>
+where
+    F: Format,
+    T: Serialize,
+{
+    atomic::overwrite(fs, path, |buf| F::to_writer(buf, model)).map_err(CodecError::from_io)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{MemoryFs, formats::Json, vfs::file},
+        serde::Deserialize,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+    }
+
+    #[test]
+    fn roundtrips_through_memory() {
+        let fs = MemoryFs::new();
+        save::<_, Json>(&fs, "d.json", Demo { id: 3 }).unwrap();
+
+        let got: Demo = file::load::<_, Json>(&fs, "d.json").unwrap();
+        assert_eq!(got, Demo { id: 3 });
+    }
+}
@@ -0,0 +1,47 @@
+use {
+    crate::{CodecError, FileSystem, FormatRegistry, vfs::file::atomic},
+    serde::Serialize,
+    std::path::Path,
+};
+
+pub fn save_auto<T>(
+    fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>, model: &T,
+) -> Result<(), CodecError>
+where
+    T: Serialize,
+{
+    let path = path.as_ref();
+    let handler = FormatRegistry::global().resolve(path)?;
+    atomic::overwrite(fs, path, |buf| handler.write(buf, model)).map_err(CodecError::from_io)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{MemoryFs, vfs::file},
+        serde::Deserialize,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+    }
+
+    #[test]
+    fn saves_by_extension() {
+        let fs = MemoryFs::new();
+        fs.create_dir(Path::new("cfg")).unwrap();
+        save_auto(&fs, "cfg/d.yaml", &Demo { id: 4 }).unwrap();
+
+        assert_eq!(file::read_string(&fs, "cfg/d.yaml").unwrap(), "id: 4\n");
+        assert_eq!(file::load_auto::<Demo>(&fs, "cfg/d.yaml").unwrap(), Demo { id: 4 });
+    }
+
+    #[test]
+    fn missing_parent_surfaces_as_io_error() {
+        let fs = MemoryFs::new();
+        let err = save_auto(&fs, "nope/d.json", &Demo { id: 5 }).unwrap_err();
+        assert!(matches!(&err, CodecError::Io(e) if e.kind() == std::io::ErrorKind::NotFound));
+    }
+}
@@ -0,0 +1,35 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn size(fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>) -> io::Result<u64> {
+    _size(fs, path.as_ref())
+}
+
+fn _size(fs: &(impl FileSystem + ?Sized), path: &Path) -> io::Result<u64> {
+    let meta = fs.metadata(path)?;
+    if !meta.is_file() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Path '{}' is not a regular file", path.display()),
+        ));
+    }
+    Ok(meta.len)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn reports_file_length_and_rejects_dirs() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        fs.write(Path::new("f"), b"12345")?;
+        fs.create_dir(Path::new("d"))?;
+
+        assert_eq!(size(&fs, "f")?, 5);
+        assert_eq!(size(&fs, "d").unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use {
+    crate::FileSystem,
+    std::{io, path::Path},
+};
+
+pub fn write(
+    fs: &(impl FileSystem + ?Sized), path: impl AsRef<Path>, contents: impl AsRef<[u8]>,
+) -> io::Result<()> {
+    fs.write(path.as_ref(), contents.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::MemoryFs};
+
+    #[test]
+    fn creates_then_truncates() -> io::Result<()> {
+        let fs = MemoryFs::new();
+        write(&fs, "a.txt", "longer content")?;
+        write(&fs, "a.txt", "short")?;
+        assert_eq!(fs.read(Path::new("a.txt"))?, b"short");
+        Ok(())
+    }
+}
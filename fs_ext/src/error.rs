@@ -1,23 +1,293 @@
-use std::io;
+use std::{
+    error, fmt, io,
+    path::{Path, PathBuf},
+};
+
+pub type BoxError = Box<dyn error::Error + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Operation {
+    Open,
+    Create,
+    Read,
+    Write,
+    Remove,
+    Trash,
+    Copy,
+    Move,
+    Metadata,
+    SetPermissions,
+    SetTimes,
+    ReadDir,
+    CreateDir,
+    RemoveDir,
+    CreateTemp,
+    Persist,
+    Seek,
+    Truncate,
+    Sync,
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let action = match self {
+            Operation::Open => "failed to open",
+            Operation::Create => "failed to create",
+            Operation::Read => "failed to read",
+            Operation::Write => "failed to write",
+            Operation::Remove => "failed to remove",
+            Operation::Trash => "failed to trash",
+            Operation::Copy => "failed to copy",
+            Operation::Move => "failed to move",
+            Operation::Metadata => "failed to read metadata for",
+            Operation::SetPermissions => "failed to set permissions on",
+            Operation::SetTimes => "failed to set file times on",
+            Operation::ReadDir => "failed to read directory",
+            Operation::CreateDir => "failed to create directory",
+            Operation::RemoveDir => "failed to remove directory",
+            Operation::CreateTemp => "failed to create temporary entry in",
+            Operation::Persist => "failed to persist",
+            Operation::Seek => "failed to seek",
+            Operation::Truncate => "failed to truncate",
+            Operation::Sync => "failed to sync",
+        };
+        f.write_str(action)
+    }
+}
+
+#[derive(Debug)]
+pub struct FsError {
+    operation: Operation,
+    path: PathBuf,
+    other_path: Option<PathBuf>,
+    source: io::Error,
+}
+
+impl FsError {
+    pub fn new(operation: Operation, path: impl Into<PathBuf>, source: io::Error) -> Self {
+        Self { operation, path: path.into(), other_path: None, source }
+    }
+
+    pub fn with_other_path(mut self, other_path: impl Into<PathBuf>) -> Self {
+        self.other_path = Some(other_path.into());
+        self
+    }
+
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn other_path(&self) -> Option<&Path> {
+        self.other_path.as_deref()
+    }
+
+    pub fn kind(&self) -> io::ErrorKind {
+        self.source.kind()
+    }
+
+    pub fn io_error(&self) -> &io::Error {
+        &self.source
+    }
+
+    pub fn into_io_error(self) -> io::Error {
+        self.source
+    }
+
+    pub fn downcast_ref(err: &io::Error) -> Option<&FsError> {
+        err.get_ref()?.downcast_ref()
+    }
+
+    pub fn downcast(err: io::Error) -> Result<FsError, io::Error> {
+        err.downcast()
+    }
+}
+
+impl fmt::Display for FsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} '{}'", self.operation, self.path.display())?;
+        if let Some(other) = &self.other_path {
+            write!(f, " -> '{}'", other.display())?;
+        }
+        write!(f, ": {}", self.source)
+    }
+}
+
+impl error::Error for FsError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl From<FsError> for io::Error {
+    fn from(err: FsError) -> Self {
+        io::Error::new(err.kind(), err)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub format: &'static str,
+    pub path: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub snippet: Option<String>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn new(
+        format: &'static str, src: &str, position: Option<(usize, usize)>,
+        message: impl Into<String>,
+    ) -> Self {
+        let snippet = position.and_then(|(line, _)| src.lines().nth(line.checked_sub(1)?));
+
+        Self {
+            format,
+            path: None,
+            line: position.map(|(line, _)| line),
+            column: position.map(|(_, column)| column),
+            snippet: snippet.map(str::to_owned),
+            message: message.into(),
+        }
+    }
+
+    pub fn from_offset(
+        format: &'static str, src: &str, offset: usize, message: impl Into<String>,
+    ) -> Self {
+        let mut end = offset.min(src.len());
+        while !src.is_char_boundary(end) {
+            end -= 1;
+        }
+        let before = &src[..end];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        Self::new(format, src, Some((line, column)), message)
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}", self.format)?;
+        if let Some(path) = &self.path {
+            write!(f, " in '{}'", path.display())?;
+        }
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            write!(f, " at line {line}, column {column}")?;
+        }
+        write!(f, ": {}", self.message)?;
+
+        if let (Some(line), Some(column), Some(snippet)) = (self.line, self.column, &self.snippet) {
+            let gutter = " ".repeat(line.to_string().len());
+            // Reuse the snippet's own tabs so the caret lines up in any tab width.
+            let pad: String = snippet
+                .chars()
+                .take(column.saturating_sub(1))
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            write!(f, "\n{gutter} |\n{line} | {snippet}\n{gutter} | {pad}^")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseError<E> {
+    pub diagnostic: Box<Diagnostic>,
+    pub source: E,
+}
+
+impl<E> ParseError<E> {
+    pub fn new(diagnostic: Diagnostic, source: E) -> Self {
+        Self { diagnostic: Box::new(diagnostic), source }
+    }
+}
+
+impl<E> fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.diagnostic.fmt(f)
+    }
+}
+
+impl<E: error::Error + 'static> error::Error for ParseError<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
 
 #[derive(Debug, thiserror::Error)]
 pub enum DeserializeError {
-    #[error("JSON parse error in")]
-    Json(serde_json::Error),
-    #[error("JSON parse error in")]
-    Toml(toml::de::Error),
-    #[error("JSON parse error in")]
-    Yaml(serde_yaml::Error),
+    #[error(transparent)]
+    Json(ParseError<serde_json::Error>),
+    #[error(transparent)]
+    Toml(ParseError<toml::de::Error>),
+    #[error(transparent)]
+    Yaml(ParseError<serde_yaml::Error>),
+    #[error("{diagnostic}")]
+    Other { diagnostic: Box<Diagnostic>, source: BoxError },
+}
+
+impl DeserializeError {
+    pub fn other(diagnostic: Diagnostic, source: impl Into<BoxError>) -> Self {
+        DeserializeError::Other { diagnostic: Box::new(diagnostic), source: source.into() }
+    }
+
+    pub fn diagnostic(&self) -> &Diagnostic {
+        match self {
+            DeserializeError::Json(e) => &e.diagnostic,
+            DeserializeError::Toml(e) => &e.diagnostic,
+            DeserializeError::Yaml(e) => &e.diagnostic,
+            DeserializeError::Other { diagnostic, .. } => diagnostic,
+        }
+    }
+
+    pub fn diagnostic_mut(&mut self) -> &mut Diagnostic {
+        match self {
+            DeserializeError::Json(e) => &mut e.diagnostic,
+            DeserializeError::Toml(e) => &mut e.diagnostic,
+            DeserializeError::Yaml(e) => &mut e.diagnostic,
+            DeserializeError::Other { diagnostic, .. } => diagnostic,
+        }
+    }
+
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.diagnostic_mut().path = Some(path.into());
+        self
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum SerializeError {
-    #[error("JSON parse error in")]
+    #[error("failed to serialize JSON: {0}")]
     Json(serde_json::Error),
-    #[error("JSON parse error in")]
+    #[error("failed to serialize TOML: {0}")]
     Toml(toml::ser::Error),
-    #[error("JSON parse error in")]
+    #[error("failed to serialize YAML: {0}")]
     Yaml(serde_yaml::Error),
+    #[error("failed to serialize {format}: {source}")]
+    Other { format: &'static str, source: BoxError },
+}
+
+impl SerializeError {
+    pub fn other(format: &'static str, source: impl Into<BoxError>) -> Self {
+        SerializeError::Other { format, source: source.into() }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MigrationError {
+    #[error("'{}' has version {found}, newer than the supported version {supported}", path.display())]
+    TooNew { path: PathBuf, found: u32, supported: u32 },
+    #[error("'{}' has an invalid `{key}` field; expected a non-negative integer", path.display())]
+    InvalidVersion { path: PathBuf, key: &'static str },
+    #[error("no migration registered from version {from} for '{}'", path.display())]
+    MissingStep { path: PathBuf, from: u32 },
+    #[error("migration from version {from} failed for '{}': {source}", path.display())]
+    Failed { path: PathBuf, from: u32, source: BoxError },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -28,4 +298,93 @@ pub enum CodecError {
     Deserialize(#[from] DeserializeError),
     #[error(transparent)]
     Serialize(#[from] SerializeError),
+    #[error(transparent)]
+    Migration(#[from] MigrationError),
+}
+
+impl CodecError {
+    // Recovers a `CodecError` that was boxed into an `io::Error`, e.g. by a
+    // fallible `atomic::overwrite` closure.
+    pub(crate) fn from_io(e: io::Error) -> Self {
+        if !e.get_ref().is_some_and(|inner| inner.is::<CodecError>()) {
+            return CodecError::Io(e);
+        }
+        match e.into_inner().map(|inner| inner.downcast::<CodecError>()) {
+            Some(Ok(codec)) => *codec,
+            _ => unreachable!("inner error was checked to be a CodecError"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{Diagnostic, FsError, Operation},
+        crate::IoResultExt,
+        std::{fs, io, path::Path},
+    };
+
+    #[test]
+    fn io_result_ext_produces_fs_error() {
+        let path = Path::new("definitely/missing/file.txt");
+        let err = fs::read(path).with_path_context(Operation::Read, path).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        let fs_err = FsError::downcast_ref(&err).expect("structured error");
+        assert_eq!(fs_err.operation(), Operation::Read);
+        assert_eq!(fs_err.path(), path);
+        assert_eq!(fs_err.other_path(), None);
+        assert_eq!(fs_err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn paths_context_records_both_paths() {
+        let (src, dst) = (Path::new("missing/src"), Path::new("missing/dst"));
+        let err = fs::copy(src, dst).with_paths_context(Operation::Copy, src, dst).unwrap_err();
+
+        let fs_err = FsError::downcast(err).expect("structured error");
+        assert_eq!(fs_err.operation(), Operation::Copy);
+        assert_eq!(fs_err.path(), src);
+        assert_eq!(fs_err.other_path(), Some(dst));
+    }
+
+    #[test]
+    fn display_keeps_operation_paths_and_source() {
+        let source = io::Error::new(io::ErrorKind::PermissionDenied, "denied");
+        let err = FsError::new(Operation::Move, "a", source).with_other_path("b");
+
+        assert_eq!(err.to_string(), "failed to move 'a' -> 'b': denied");
+        assert_eq!(io::Error::from(err).to_string(), "failed to move 'a' -> 'b': denied");
+    }
+
+    #[test]
+    fn diagnostic_renders_caret_under_column() {
+        let src = "a = 1\nb = oops\n";
+        let mut diag = Diagnostic::from_offset("TOML", src, 10, "invalid value");
+        diag.path = Some("config.toml".into());
+
+        assert_eq!((diag.line, diag.column), (Some(2), Some(5)));
+        assert_eq!(
+            diag.to_string(),
+            "invalid TOML in 'config.toml' at line 2, column 5: invalid value\n  |\n2 | b = oops\n  |     ^"
+        );
+    }
+
+    #[test]
+    fn diagnostic_without_position_is_single_line() {
+        let diag = Diagnostic::new("JSON", "{}", None, "missing field `id`");
+        assert_eq!(diag.to_string(), "invalid JSON: missing field `id`");
+    }
+
+    #[test]
+    fn diagnostic_caret_keeps_tabs() {
+        let diag = Diagnostic::new("YAML", "\tkey: [", Some((1, 8)), "unexpected end");
+        assert!(diag.to_string().ends_with("1 | \tkey: [\n  | \t      ^"), "{diag}");
+    }
+
+    #[test]
+    fn plain_io_errors_are_not_fs_errors() {
+        let err = io::Error::other("plain");
+        assert!(FsError::downcast_ref(&err).is_none());
+    }
 }
@@ -1,6 +1,10 @@
 #[cfg(test)]
 pub mod test_utils;
 
+// Lets `#[fs_test]` expand to `::fs_ext::testing::...` inside this crate too.
+#[cfg(test)]
+extern crate self as fs_ext;
+
 mod core;
 mod error;
 mod macros;
@@ -8,12 +12,26 @@ mod traits;
 mod types;
 pub(crate) mod utils;
 
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 #[cfg(feature = "tokio")]
 pub mod tokio;
 
+#[cfg(feature = "edit")]
+pub use toml_edit;
+
 pub use {
     core::*,
-    error::{CodecError, DeserializeError, SerializeError},
-    traits::{Format, IoResultExt, PathExt},
-    types::{CollisionStrategy, ParentPolicy, PathKind, RenameOptions, WriteOptions, formats},
+    error::{
+        BoxError, CodecError, DeserializeError, Diagnostic, FsError, MigrationError, Operation,
+        ParseError, SerializeError,
+    },
+    traits::{BinaryFormat, FileSystem, Format, IoResultExt, PathExt, Versioned},
+    types::{
+        ArchiveFormat, ClearOptions, CollisionStrategy, CompareOptions, Compression, CopyOptions,
+        CopyProgress, DedupeAction, DedupeOptions, DedupeReport, Detected, DetectedBy, Digest,
+        DirDiff, DuplicateGroup, Fault, FormatRegistry, HashAlgorithm, Layer, LayerSource, Layered,
+        LayeredOptions, Migrations, ParentPolicy, PathKind, Reflink, RenameOptions, SyncCompare,
+        SyncOptions, SyncReport, VfsMetadata, WriteOptions, formats,
+    },
 };
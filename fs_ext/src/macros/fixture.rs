@@ -0,0 +1,23 @@
+#[macro_export]
+macro_rules! fixture {
+    (@push $f:ident;) => {};
+    (@push $f:ident; $path:literal => dir $(, $($rest:tt)*)?) => {
+        $f = $f.dir($path);
+        $crate::fixture!(@push $f; $($($rest)*)?);
+    };
+    (@push $f:ident; $path:literal => symlink($target:expr) $(, $($rest:tt)*)?) => {
+        $f = $f.symlink($path, $target);
+        $crate::fixture!(@push $f; $($($rest)*)?);
+    };
+    (@push $f:ident; $path:literal => $content:expr $(, $($rest:tt)*)?) => {
+        $f = $f.file($path, $content);
+        $crate::fixture!(@push $f; $($($rest)*)?);
+    };
+
+    ($($body:tt)*) => {{
+        #[allow(unused_mut)]
+        let mut __f = $crate::testing::Fixture::new();
+        $crate::fixture!(@push __f; $($body)*);
+        __f.build()
+    }};
+}
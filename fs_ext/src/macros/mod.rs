@@ -1,5 +1,7 @@
 mod dir;
 mod file;
+#[cfg(any(test, feature = "testing"))]
+mod fixture;
 mod load;
 mod save;
 mod temp;
@@ -0,0 +1,8 @@
+use crate::HashAlgorithm;
+
+pub fn enabled_hash() -> HashAlgorithm {
+    [HashAlgorithm::Sha256, HashAlgorithm::Blake3, HashAlgorithm::Xxh3]
+        .into_iter()
+        .find(|a| a.is_enabled())
+        .expect("a hashing feature is enabled")
+}
@@ -1,5 +1,9 @@
-mod asserts;
 mod custom_error;
+#[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+mod enabled_hash;
 mod file_count;
 
-pub use {asserts::*, custom_error::CustomError, file_count::file_count};
+pub use {custom_error::CustomError, file_count::file_count};
+
+#[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+pub use enabled_hash::enabled_hash;
@@ -0,0 +1,12 @@
+use {
+    crate::{CompareOptions, dir},
+    std::path::Path,
+};
+
+pub fn assert_dirs_eq(a: impl AsRef<Path>, b: impl AsRef<Path>) {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    let diff = dir::compare(a, b, CompareOptions::default()).unwrap_or_else(|e| {
+        panic!("failed to compare '{}' with '{}': {e}", a.display(), b.display())
+    });
+    assert!(diff.is_empty(), "'{}' and '{}' differ: {diff:#?}", a.display(), b.display());
+}
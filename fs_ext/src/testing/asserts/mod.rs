@@ -1,3 +1,4 @@
+mod dirs_eq;
 mod existing_dir_ok;
 mod existing_file_ok;
 mod new_dir_ok;
@@ -9,8 +10,8 @@ mod rejects_file;
 mod rejects_missing_path;
 
 pub use {
-    existing_dir_ok::existing_dir_ok, existing_file_ok::existing_file_ok, new_dir_ok::new_dir_ok,
-    new_file_ok::new_file_ok, rejects_dir::assert_fn_rejects_dir_path,
+    dirs_eq::assert_dirs_eq, existing_dir_ok::existing_dir_ok, existing_file_ok::existing_file_ok,
+    new_dir_ok::new_dir_ok, new_file_ok::new_file_ok, rejects_dir::assert_fn_rejects_dir_path,
     rejects_exisitng_file::assert_fn_rejects_existing_file,
     rejects_existing_dir::assert_fn_rejects_existing_dir,
     rejects_file::assert_fn_rejects_file_path,
@@ -0,0 +1,534 @@
+use {
+    crate::{CodecError, Format, IoResultExt, Operation, TempDir, dir, file},
+    filetime::FileTime,
+    serde_json::{Map, Value},
+    std::{
+        collections::{BTreeMap, BTreeSet},
+        fs, io,
+        path::{Component, Path, PathBuf},
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    },
+};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Fixture {
+    entries: BTreeMap<PathBuf, FixtureEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FixtureEntry {
+    pub kind: FixtureKind,
+    pub mode: Option<u32>,
+    pub mtime: Option<SystemTime>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FixtureKind {
+    File(Vec<u8>),
+    Dir,
+    Symlink(PathBuf),
+}
+
+impl Fixture {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn file(mut self, path: impl AsRef<Path>, content: impl AsRef<[u8]>) -> Self {
+        self.push(path.as_ref(), FixtureKind::File(content.as_ref().to_vec()));
+        self
+    }
+
+    pub fn dir(mut self, path: impl AsRef<Path>) -> Self {
+        self.push(path.as_ref(), FixtureKind::Dir);
+        self
+    }
+
+    pub fn symlink(mut self, path: impl AsRef<Path>, target: impl AsRef<Path>) -> Self {
+        self.push(path.as_ref(), FixtureKind::Symlink(target.as_ref().to_path_buf()));
+        self
+    }
+
+    // Symlinks have no mode of their own on most platforms, and setting one
+    // would change the target instead.
+    pub fn mode(mut self, path: impl AsRef<Path>, mode: u32) -> Self {
+        let path = path.as_ref();
+        let entry = self.entry_mut(path);
+        assert!(
+            !matches!(entry.kind, FixtureKind::Symlink(_)),
+            "'{}' is a symlink; only files and directories take a mode",
+            path.display()
+        );
+        entry.mode = Some(mode);
+        self
+    }
+
+    pub fn mtime(mut self, path: impl AsRef<Path>, mtime: SystemTime) -> Self {
+        self.entry_mut(path.as_ref()).mtime = Some(mtime);
+        self
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (&Path, &FixtureEntry)> {
+        self.entries.iter().map(|(path, entry)| (path.as_path(), entry))
+    }
+
+    // Strings are files, `null` or maps are directories, and a map holding
+    // `content`, `symlink` or `dir` (plus optional `mode` as an octal string
+    // and `mtime` in unix seconds) is a single entry.
+    pub fn parse<F: Format>(description: &str) -> Result<Self, CodecError> {
+        Self::from_value(F::parse_str(description)?)
+    }
+
+    pub fn load<F: Format>(path: impl AsRef<Path>) -> Result<Self, CodecError> {
+        Self::from_value(F::load(path)?)
+    }
+
+    pub fn load_auto(path: impl AsRef<Path>) -> Result<Self, CodecError> {
+        Self::from_value(file::load_auto(path)?)
+    }
+
+    pub fn build(&self) -> io::Result<TempDir> {
+        let temp = dir::temp()?;
+        self.build_in(temp.path())?;
+        Ok(temp)
+    }
+
+    // Modes and timestamps are applied last, children first, so a read-only
+    // directory is still writable while it is filled and creating children
+    // doesn't bump a directory's mtime afterwards.
+    pub fn build_in(&self, root: impl AsRef<Path>) -> io::Result<()> {
+        let root = root.as_ref();
+        for (rel, entry) in &self.entries {
+            let path = root.join(rel);
+            if let Some(parent) = path.parent() {
+                dir::ensure(parent)?;
+            }
+            match &entry.kind {
+                FixtureKind::File(content) => {
+                    fs::write(&path, content).with_path_context(Operation::Write, &path)?
+                }
+                FixtureKind::Dir => dir::ensure(&path)?,
+                FixtureKind::Symlink(target) => {
+                    symlink(target, &path).with_path_context(Operation::Create, &path)?
+                }
+            }
+        }
+        for (rel, entry) in self.entries.iter().rev() {
+            let path = root.join(rel);
+            if let Some(mode) = entry.mode {
+                set_mode(&path, mode)?;
+            }
+            if let Some(mtime) = entry.mtime {
+                filetime::set_symlink_file_times(
+                    &path,
+                    FileTime::from(mtime),
+                    FileTime::from(mtime),
+                )
+                .with_path_context(Operation::SetTimes, &path)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn assert_matches(&self, root: impl AsRef<Path>) {
+        let root = root.as_ref();
+        let problems = self
+            .mismatches(root)
+            .unwrap_or_else(|e| panic!("failed to inspect '{}': {e}", root.display()));
+        assert!(
+            problems.is_empty(),
+            "'{}' does not match the fixture:\n  {}",
+            root.display(),
+            problems.join("\n  ")
+        );
+    }
+
+    pub fn mismatches(&self, root: impl AsRef<Path>) -> io::Result<Vec<String>> {
+        let root = root.as_ref();
+        let mut problems = Vec::new();
+
+        for (rel, entry) in &self.entries {
+            check_entry(&root.join(rel), rel, entry, &mut problems)?;
+        }
+
+        // Parents of declared entries are implied, so they aren't extras.
+        let expected = self.entries.keys().flat_map(|p| p.ancestors()).collect::<BTreeSet<_>>();
+        for path in walkdir::WalkDir::new(root).min_depth(1).follow_links(false) {
+            let path = path.map_err(io::Error::from)?.into_path();
+            let rel = path.strip_prefix(root).expect("walked paths live under the root");
+            if !expected.contains(rel) {
+                problems.push(format!("{}: unexpected entry", rel.display()));
+            }
+        }
+        Ok(problems)
+    }
+
+    fn push(&mut self, path: &Path, kind: FixtureKind) {
+        assert!(
+            is_relative_name(path),
+            "fixture paths must be relative and free of `..`: '{}'",
+            path.display()
+        );
+        self.entries.insert(path.to_path_buf(), FixtureEntry { kind, mode: None, mtime: None });
+    }
+
+    fn entry_mut(&mut self, path: &Path) -> &mut FixtureEntry {
+        self.entries
+            .get_mut(path)
+            .unwrap_or_else(|| panic!("'{}' must be declared before its metadata", path.display()))
+    }
+
+    fn from_value(value: Value) -> Result<Self, CodecError> {
+        let mut fixture = Fixture::new();
+        match value {
+            Value::Object(map) => fixture.add_dir_contents(Path::new(""), map)?,
+            Value::Null => {}
+            _ => return Err(invalid(Path::new("."), "the top level must be a map").into()),
+        }
+        Ok(fixture)
+    }
+
+    fn add_dir_contents(&mut self, dir: &Path, map: Map<String, Value>) -> io::Result<()> {
+        for (name, value) in map {
+            let path = dir.join(&name);
+            if !is_relative_name(Path::new(&name)) {
+                return Err(invalid(&path, "names must be relative and free of `..`"));
+            }
+
+            match value {
+                Value::String(content) => self.push(&path, FixtureKind::File(content.into_bytes())),
+                Value::Null => self.push(&path, FixtureKind::Dir),
+                Value::Object(map) if is_entry_spec(&map) => self.add_spec(&path, map)?,
+                Value::Object(map) if map.is_empty() => self.push(&path, FixtureKind::Dir),
+                Value::Object(map) => self.add_dir_contents(&path, map)?,
+                _ => return Err(invalid(&path, "expected a string, map or null")),
+            }
+        }
+        Ok(())
+    }
+
+    fn add_spec(&mut self, path: &Path, mut spec: Map<String, Value>) -> io::Result<()> {
+        match (spec.remove("content"), spec.remove("symlink"), spec.remove("dir")) {
+            (Some(Value::String(content)), None, None) => {
+                self.push(path, FixtureKind::File(content.into_bytes()))
+            }
+            (None, Some(Value::String(target)), None) => {
+                if spec.contains_key("mode") {
+                    return Err(invalid(path, "symlinks can't have a `mode`"));
+                }
+                self.push(path, FixtureKind::Symlink(PathBuf::from(target)))
+            }
+            (None, None, Some(Value::Null)) => self.push(path, FixtureKind::Dir),
+            (None, None, Some(Value::Object(children))) => {
+                self.push(path, FixtureKind::Dir);
+                self.add_dir_contents(path, children)?;
+            }
+            _ => {
+                return Err(invalid(
+                    path,
+                    "needs exactly one string `content`, string `symlink` or map `dir`",
+                ));
+            }
+        }
+
+        // Numbers are refused: YAML reads `0o644` as 420, which is also valid octal.
+        if let Some(mode) = spec.get("mode") {
+            let mode = mode
+                .as_str()
+                .and_then(|s| u32::from_str_radix(s.trim_start_matches("0o"), 8).ok())
+                .ok_or_else(|| invalid(path, "`mode` must be an octal string such as \"755\""))?;
+            self.entry_mut(path).mode = Some(mode);
+        }
+        if let Some(mtime) = spec.get("mtime") {
+            let secs =
+                mtime.as_u64().ok_or_else(|| invalid(path, "`mtime` must be unix seconds"))?;
+            self.entry_mut(path).mtime = Some(UNIX_EPOCH + Duration::from_secs(secs));
+        }
+        Ok(())
+    }
+}
+
+fn is_relative_name(path: &Path) -> bool {
+    path.components().all(|c| matches!(c, Component::Normal(_)))
+}
+
+fn is_entry_spec(map: &Map<String, Value>) -> bool {
+    ["content", "symlink", "dir"].iter().any(|k| map.contains_key(*k))
+        && map
+            .keys()
+            .all(|k| matches!(k.as_str(), "content" | "symlink" | "dir" | "mode" | "mtime"))
+}
+
+fn check_entry(
+    path: &Path, rel: &Path, entry: &FixtureEntry, problems: &mut Vec<String>,
+) -> io::Result<()> {
+    let metadata = match fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            problems.push(format!("{}: missing", rel.display()));
+            return Ok(());
+        }
+        Err(e) => return Err(e).with_path_context(Operation::Metadata, path),
+    };
+
+    let ft = metadata.file_type();
+    match &entry.kind {
+        FixtureKind::File(content) if ft.is_file() => {
+            if fs::read(path).with_path_context(Operation::Read, path)? != *content {
+                problems.push(format!("{}: content differs", rel.display()));
+            }
+        }
+        FixtureKind::Dir if ft.is_dir() => {}
+        FixtureKind::Symlink(target) if ft.is_symlink() => {
+            let actual = fs::read_link(path).with_path_context(Operation::Read, path)?;
+            if actual != *target {
+                problems.push(format!(
+                    "{}: links to '{}', expected '{}'",
+                    rel.display(),
+                    actual.display(),
+                    target.display()
+                ));
+            }
+        }
+        expected => {
+            problems.push(format!(
+                "{}: expected {}, found {ft:?}",
+                rel.display(),
+                kind_name(expected)
+            ));
+            return Ok(());
+        }
+    }
+
+    #[cfg(unix)]
+    if let Some(mode) = entry.mode {
+        use std::os::unix::fs::PermissionsExt;
+        let actual = metadata.permissions().mode() & 0o7777;
+        if actual != mode {
+            problems.push(format!("{}: mode is {actual:o}, expected {mode:o}", rel.display()));
+        }
+    }
+    if let Some(mtime) = entry.mtime
+        && FileTime::from_last_modification_time(&metadata) != FileTime::from(mtime)
+    {
+        problems.push(format!("{}: mtime differs", rel.display()));
+    }
+    Ok(())
+}
+
+fn kind_name(kind: &FixtureKind) -> &'static str {
+    match kind {
+        FixtureKind::File(_) => "a file",
+        FixtureKind::Dir => "a directory",
+        FixtureKind::Symlink(_) => "a symlink",
+    }
+}
+
+fn invalid(path: &Path, reason: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid fixture entry '{}': {reason}", path.display()),
+    )
+}
+
+fn symlink(target: &Path, link: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    return std::os::unix::fs::symlink(target, link);
+    #[cfg(windows)]
+    return std::os::windows::fs::symlink_file(target, link);
+    #[cfg(not(any(unix, windows)))]
+    return Err(io::Error::new(io::ErrorKind::Unsupported, "symlinks are not supported"));
+}
+
+// Only the read-only bit maps onto non-unix permissions.
+fn set_mode(path: &Path, mode: u32) -> io::Result<()> {
+    #[cfg(unix)]
+    let perms = {
+        use std::os::unix::fs::PermissionsExt;
+        fs::Permissions::from_mode(mode)
+    };
+    #[cfg(not(unix))]
+    let perms = {
+        let mut perms =
+            fs::metadata(path).with_path_context(Operation::Metadata, path)?.permissions();
+        perms.set_readonly(mode & 0o200 == 0);
+        perms
+    };
+    fs::set_permissions(path, perms).with_path_context(Operation::SetPermissions, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::formats::{Json, Yaml},
+    };
+
+    #[test]
+    fn builds_and_matches_tree() -> io::Result<()> {
+        let mtime = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let fixture = Fixture::new()
+            .file("src/main.rs", "fn main() {}")
+            .dir("empty")
+            .file("bin/run.sh", "#!/bin/sh")
+            .mode("bin/run.sh", 0o755)
+            .mtime("src/main.rs", mtime)
+            .symlink("latest", "src/main.rs");
+
+        let temp = fixture.build()?;
+
+        assert_eq!(fs::read_to_string(temp.path().join("src/main.rs"))?, "fn main() {}");
+        assert!(temp.path().join("empty").is_dir());
+        assert_eq!(fs::read_link(temp.path().join("latest"))?, Path::new("src/main.rs"));
+        let meta = fs::metadata(temp.path().join("src/main.rs"))?;
+        assert_eq!(FileTime::from_last_modification_time(&meta), FileTime::from(mtime));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(temp.path().join("bin/run.sh"))?.permissions().mode();
+            assert_eq!(mode & 0o777, 0o755);
+        }
+        fixture.assert_matches(temp.path());
+        Ok(())
+    }
+
+    #[test]
+    fn reports_missing_extra_and_changed_entries() -> io::Result<()> {
+        let fixture = Fixture::new().file("a.txt", "a").file("b.txt", "b").dir("d");
+        let temp = fixture.build()?;
+        fs::write(temp.path().join("a.txt"), "changed")?;
+        fs::remove_file(temp.path().join("b.txt"))?;
+        fs::write(temp.path().join("extra.txt"), "")?;
+        fs::remove_dir(temp.path().join("d"))?;
+        fs::write(temp.path().join("d"), "")?;
+
+        let problems = fixture.mismatches(temp.path())?;
+
+        assert_eq!(
+            problems,
+            [
+                "a.txt: content differs",
+                "b.txt: missing",
+                &format!(
+                    "d: expected a directory, found {:?}",
+                    fs::symlink_metadata(temp.path().join("d"))?.file_type()
+                ),
+                "extra.txt: unexpected entry",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parses_yaml_description() -> Result<(), CodecError> {
+        let yaml = r##"
+src:
+  main.rs: "fn main() {}"
+  empty: {}
+cache: null
+run.sh:
+  content: "#!/bin/sh"
+  mode: "755"
+  mtime: 1700000000
+current:
+  symlink: src/main.rs
+"##;
+        let fixture = Fixture::parse::<Yaml>(yaml)?;
+
+        let expected = Fixture::new()
+            .file("src/main.rs", "fn main() {}")
+            .dir("src/empty")
+            .dir("cache")
+            .file("run.sh", "#!/bin/sh")
+            .mode("run.sh", 0o755)
+            .mtime("run.sh", UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+            .symlink("current", "src/main.rs");
+        assert_eq!(fixture, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn loads_json_description_and_rejects_traversal() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("tree.json");
+        fs::write(&path, r#"{ "a": { "b.txt": "hi" } }"#)?;
+        let fixture = Fixture::load::<Json>(&path).unwrap();
+        assert_eq!(fixture, Fixture::new().file("a/b.txt", "hi"));
+        assert_eq!(Fixture::load_auto(&path).unwrap(), fixture);
+
+        let err = Fixture::parse::<Json>(r#"{ "..": { "x": "y" } }"#).unwrap_err();
+        assert!(err.to_string().contains("free of `..`"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn macro_builds_fixture() -> io::Result<()> {
+        let temp = crate::fixture! {
+            "src/lib.rs" => "pub fn f() {}",
+            "assets/" => dir,
+            "link" => symlink("src/lib.rs"),
+        }?;
+
+        Fixture::new()
+            .file("src/lib.rs", "pub fn f() {}")
+            .dir("assets")
+            .symlink("link", "src/lib.rs")
+            .assert_matches(temp.path());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn read_only_dirs_are_locked_after_their_children_exist() -> io::Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let fixture = Fixture::new().dir("ro").mode("ro", 0o555).file("ro/x", "x");
+        let temp = fixture.build()?;
+
+        let mode = fs::metadata(temp.path().join("ro"))?.permissions().mode();
+        let problems = fixture.mismatches(temp.path());
+        fs::set_permissions(temp.path().join("ro"), fs::Permissions::from_mode(0o755))?;
+
+        assert_eq!(mode & 0o777, 0o555);
+        assert_eq!(problems?, Vec::<String>::new());
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "only files and directories take a mode")]
+    fn modes_on_symlinks_are_rejected() {
+        let _ = Fixture::new().symlink("link", "target").mode("link", 0o600);
+    }
+
+    #[test]
+    fn parses_dir_specs_and_rejects_bad_modes() -> Result<(), CodecError> {
+        let yaml = r#"
+locked:
+  dir:
+    inner.txt: "i"
+  mode: "0o700"
+  mtime: 1600000000
+blank:
+  dir: null
+"#;
+        let fixture = Fixture::parse::<Yaml>(yaml)?;
+
+        let expected = Fixture::new()
+            .dir("blank")
+            .dir("locked")
+            .mode("locked", 0o700)
+            .mtime("locked", UNIX_EPOCH + Duration::from_secs(1_600_000_000))
+            .file("locked/inner.txt", "i");
+        assert_eq!(fixture, expected);
+
+        for bad in [
+            r#"{ "f": { "content": "", "mode": 755 } }"#,
+            r#"{ "f": { "content": "", "mode": "9" } }"#,
+            r#"{ "l": { "symlink": "t", "mode": "644" } }"#,
+            r#"{ "d": { "dir": "no" } }"#,
+        ] {
+            assert!(Fixture::parse::<Json>(bad).is_err(), "{bad}");
+        }
+        Ok(())
+    }
+}
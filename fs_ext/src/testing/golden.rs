@@ -0,0 +1,97 @@
+use {
+    crate::{IoResultExt, Operation, PathExt, file},
+    std::{env, fs, io, path::Path},
+};
+
+pub const UPDATE_GOLDENS_ENV: &str = "FS_EXT_UPDATE_GOLDENS";
+
+// Set `FS_EXT_UPDATE_GOLDENS=1` to (re)write goldens from the actual output
+// instead of comparing against them.
+pub fn assert_matches_golden(path: impl AsRef<Path>, actual: impl AsRef<[u8]>) {
+    _assert_matches_golden(path.as_ref(), actual.as_ref())
+}
+
+fn _assert_matches_golden(path: &Path, actual: &[u8]) {
+    if updating() {
+        write_golden(path, actual)
+            .unwrap_or_else(|e| panic!("failed to update golden '{}': {e}", path.display()));
+        return;
+    }
+
+    let expected = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => panic!(
+            "golden '{}' does not exist; rerun with {UPDATE_GOLDENS_ENV}=1 to create it",
+            path.display()
+        ),
+        Err(e) => panic!("failed to read golden '{}': {e}", path.display()),
+    };
+    if expected == actual {
+        return;
+    }
+
+    match (std::str::from_utf8(&expected), std::str::from_utf8(actual)) {
+        (Ok(expected), Ok(actual)) => panic!(
+            "output does not match golden '{}' (rerun with {UPDATE_GOLDENS_ENV}=1 to accept)\n\
+             --- expected\n{expected}\n--- actual\n{actual}",
+            path.display()
+        ),
+        _ => panic!(
+            "output does not match golden '{}' (rerun with {UPDATE_GOLDENS_ENV}=1 to accept): \
+             expected {} bytes, got {}",
+            path.display(),
+            expected.len(),
+            actual.len()
+        ),
+    }
+}
+
+fn updating() -> bool {
+    env::var_os(UPDATE_GOLDENS_ENV).is_some_and(|v| !v.is_empty() && v != "0")
+}
+
+fn write_golden(path: &Path, actual: &[u8]) -> io::Result<()> {
+    let parent = path.parent_or_current();
+    fs::create_dir_all(&parent).with_path_context(Operation::CreateDir, &parent)?;
+    file::atomic::overwrite(path, |f| io::Write::write_all(f, actual))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, tempfile::tempdir};
+
+    #[test]
+    fn matching_output_passes() {
+        let dir = tempdir().unwrap();
+        let golden = dir.path().join("report.txt");
+        fs::write(&golden, "ok\n").unwrap();
+
+        assert_matches_golden(&golden, "ok\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "--- expected\nold\n--- actual\nnew")]
+    fn mismatch_shows_both_sides() {
+        let dir = tempdir().unwrap();
+        let golden = dir.path().join("report.txt");
+        fs::write(&golden, "old").unwrap();
+
+        assert_matches_golden(&golden, "new");
+    }
+
+    #[test]
+    #[should_panic(expected = "does not exist")]
+    fn missing_golden_panics_with_hint() {
+        let dir = tempdir().unwrap();
+        assert_matches_golden(dir.path().join("missing.txt"), "x");
+    }
+
+    #[test]
+    fn write_golden_creates_parents() {
+        let dir = tempdir().unwrap();
+        let golden = dir.path().join("goldens/nested/out.bin");
+
+        write_golden(&golden, &[0, 159, 146, 150]).unwrap();
+        assert_matches_golden(&golden, [0, 159, 146, 150]);
+    }
+}
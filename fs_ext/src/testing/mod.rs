@@ -0,0 +1,12 @@
+mod asserts;
+mod fixture;
+mod golden;
+
+pub use {
+    asserts::*,
+    fixture::{Fixture, FixtureEntry, FixtureKind},
+    golden::{UPDATE_GOLDENS_ENV, assert_matches_golden},
+};
+
+#[cfg(feature = "testing")]
+pub use fs_ext_test_macros::fs_test;
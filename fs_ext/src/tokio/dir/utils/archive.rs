@@ -0,0 +1,32 @@
+use {
+    crate::{ArchiveFormat, dir, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn archive(
+    src: impl AsRef<Path>, dst: impl AsRef<Path>, format: ArchiveFormat,
+) -> io::Result<()> {
+    let src = src.as_ref().to_owned();
+    let dst = dst.as_ref().to_owned();
+    asyncify(move || dir::archive(src, dst, format)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::archive, crate::ArchiveFormat, std::io};
+
+    #[tokio::test]
+    async fn smoke_archive() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        let src = root.path().join("src");
+        std::fs::create_dir(&src)?;
+        std::fs::write(src.join("file.txt"), b"hello")?;
+
+        let format = if cfg!(feature = "tar") { ArchiveFormat::Tar } else { ArchiveFormat::Zip };
+        let dst = root.path().join(format!("out.{}", format.extension()));
+        archive(&src, &dst, format).await?;
+        assert!(dst.is_file());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use {
+    crate::{ClearOptions, dir, tokio::utils::asyncify},
+    std::{
+        io,
+        path::{Path, PathBuf},
+    },
+};
+
+pub async fn clear_matching(
+    path: impl AsRef<Path>, options: impl AsRef<ClearOptions>,
+) -> io::Result<Vec<PathBuf>> {
+    let path = path.as_ref().to_owned();
+    let options = options.as_ref().clone();
+    asyncify(move || dir::clear_matching(path, options)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::clear_matching, crate::ClearOptions, std::io};
+
+    #[tokio::test]
+    async fn smoke_clear_matching() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        std::fs::write(dir.path().join("a.tmp"), b"")?;
+        std::fs::write(dir.path().join("b.txt"), b"")?;
+
+        let removed = clear_matching(dir.path(), ClearOptions::new().glob("*.tmp")).await?;
+        assert_eq!(removed, vec![dir.path().join("a.tmp")]);
+        assert!(dir.path().join("b.txt").exists());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use {
+    crate::{CompareOptions, DirDiff, dir, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn compare(
+    a: impl AsRef<Path>, b: impl AsRef<Path>, options: impl AsRef<CompareOptions>,
+) -> io::Result<DirDiff> {
+    let a = a.as_ref().to_owned();
+    let b = b.as_ref().to_owned();
+    let options = options.as_ref().clone();
+    asyncify(move || dir::compare(a, b, options)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::compare, crate::CompareOptions, std::io};
+
+    #[tokio::test]
+    async fn smoke_compare() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        let a = root.path().join("a");
+        let b = root.path().join("b");
+        std::fs::create_dir(&a)?;
+        std::fs::create_dir(&b)?;
+        std::fs::write(a.join("file.txt"), b"hello")?;
+
+        let diff = compare(&a, &b, CompareOptions::default()).await?;
+        assert_eq!(diff.only_in_a, vec![std::path::PathBuf::from("file.txt")]);
+        Ok(())
+    }
+}
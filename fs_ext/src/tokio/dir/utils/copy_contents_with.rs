@@ -0,0 +1,34 @@
+use {
+    crate::{CopyProgress, WriteOptions, dir, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn copy_dir_contents_with(
+    src: impl AsRef<Path>, dst: impl AsRef<Path>, options: impl AsRef<WriteOptions>,
+    progress: impl FnMut(&CopyProgress) + Send + 'static,
+) -> io::Result<CopyProgress> {
+    let src = src.as_ref().to_owned();
+    let dst = dst.as_ref().to_owned();
+    let options = *options.as_ref();
+    asyncify(move || dir::copy_dir_contents_with(src, dst, options, progress)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::copy_dir_contents_with, crate::WriteOptions, std::io};
+
+    #[tokio::test]
+    async fn smoke_copy_dir_contents_with() -> io::Result<()> {
+        let src = tempfile::tempdir()?;
+        let dst = tempfile::tempdir()?;
+
+        std::fs::write(src.path().join("file.txt"), b"hello")?;
+
+        let summary =
+            copy_dir_contents_with(src.path(), dst.path(), WriteOptions::default(), |_| {}).await?;
+        assert_eq!(summary.files_copied, 1);
+        assert_eq!(std::fs::read_to_string(dst.path().join("file.txt"))?, "hello");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use {
+    crate::{DedupeOptions, DedupeReport, DuplicateGroup, dir, tokio::utils::asyncify},
+    std::io,
+};
+
+pub async fn dedupe(
+    groups: &[DuplicateGroup], options: impl AsRef<DedupeOptions>,
+) -> io::Result<DedupeReport> {
+    let groups = groups.to_vec();
+    let options = *options.as_ref();
+    asyncify(move || dir::dedupe(&groups, options)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::dedupe,
+        crate::{DedupeAction, DedupeOptions, test_utils::enabled_hash},
+        std::io,
+    };
+
+    #[tokio::test]
+    async fn smoke_dedupe() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        std::fs::write(root.path().join("a.txt"), b"same")?;
+        std::fs::write(root.path().join("b.txt"), b"same")?;
+        let groups = crate::dir::find_duplicates(root.path(), enabled_hash())?;
+
+        let options = DedupeOptions { action: DedupeAction::Trash, dry_run: true };
+        let report = dedupe(&groups, options).await?;
+        assert_eq!(report.replaced, vec![root.path().join("b.txt")]);
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use {
+    crate::{dir, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn extract(archive: impl AsRef<Path>, dst: impl AsRef<Path>) -> io::Result<()> {
+    let archive = archive.as_ref().to_owned();
+    let dst = dst.as_ref().to_owned();
+    asyncify(move || dir::extract(archive, dst)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::extract, crate::ArchiveFormat, std::io};
+
+    #[tokio::test]
+    async fn smoke_extract() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        let src = root.path().join("src");
+        std::fs::create_dir(&src)?;
+        std::fs::write(src.join("file.txt"), b"hello")?;
+
+        let format = if cfg!(feature = "zip") { ArchiveFormat::Zip } else { ArchiveFormat::Tar };
+        let archive = root.path().join(format!("out.{}", format.extension()));
+        crate::dir::archive(&src, &archive, format)?;
+
+        let dst = root.path().join("dst");
+        extract(&archive, &dst).await?;
+        assert_eq!(std::fs::read_to_string(dst.join("file.txt"))?, "hello");
+
+        Ok(())
+    }
+}
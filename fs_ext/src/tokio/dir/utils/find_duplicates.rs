@@ -0,0 +1,27 @@
+use {
+    crate::{DuplicateGroup, HashAlgorithm, dir, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn find_duplicates(
+    path: impl AsRef<Path>, algorithm: HashAlgorithm,
+) -> io::Result<Vec<DuplicateGroup>> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || dir::find_duplicates(path, algorithm)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::find_duplicates, crate::test_utils::enabled_hash, std::io};
+
+    #[tokio::test]
+    async fn smoke_find_duplicates() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        std::fs::write(root.path().join("a.txt"), b"same")?;
+        std::fs::write(root.path().join("b.txt"), b"same")?;
+
+        let groups = find_duplicates(root.path(), enabled_hash()).await?;
+        assert_eq!(groups.len(), 1);
+        Ok(())
+    }
+}
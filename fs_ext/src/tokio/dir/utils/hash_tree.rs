@@ -0,0 +1,27 @@
+use {
+    crate::{Digest, HashAlgorithm, dir, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn hash_tree(path: impl AsRef<Path>, algorithm: HashAlgorithm) -> io::Result<Digest> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || dir::hash_tree(path, algorithm)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::hash_tree, crate::test_utils::enabled_hash, std::io};
+
+    #[tokio::test]
+    async fn smoke_hash_tree() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        std::fs::write(root.path().join("file.txt"), b"hello")?;
+
+        let algorithm = enabled_hash();
+        assert_eq!(
+            hash_tree(root.path(), algorithm).await?,
+            crate::dir::hash_tree(root.path(), algorithm)?
+        );
+        Ok(())
+    }
+}
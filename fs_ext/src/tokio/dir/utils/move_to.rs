@@ -0,0 +1,37 @@
+use {
+    crate::{WriteOptions, dir, tokio::utils::asyncify},
+    std::{
+        io,
+        path::{Path, PathBuf},
+    },
+};
+
+pub async fn move_to(
+    src: impl AsRef<Path>, dst: impl AsRef<Path>, options: impl AsRef<WriteOptions>,
+) -> io::Result<Option<PathBuf>> {
+    let src = src.as_ref().to_owned();
+    let dst = dst.as_ref().to_owned();
+    let options = *options.as_ref();
+    asyncify(move || dir::move_to(src, dst, options)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::move_to, crate::WriteOptions, std::io};
+
+    #[tokio::test]
+    async fn smoke_move_to() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        let src = root.path().join("src");
+        let dst = root.path().join("dst");
+        std::fs::create_dir(&src)?;
+        std::fs::write(src.join("file.txt"), b"hello")?;
+
+        let moved = move_to(&src, &dst, WriteOptions::default()).await?;
+        assert_eq!(moved, Some(dst.clone()));
+        assert!(!src.exists());
+        assert_eq!(std::fs::read_to_string(dst.join("file.txt"))?, "hello");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use {
+    crate::{SyncOptions, SyncReport, dir, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn sync(
+    src: impl AsRef<Path>, dst: impl AsRef<Path>, options: impl AsRef<SyncOptions>,
+) -> io::Result<SyncReport> {
+    let src = src.as_ref().to_owned();
+    let dst = dst.as_ref().to_owned();
+    let options = *options.as_ref();
+    asyncify(move || dir::sync(src, dst, options)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::sync, crate::SyncOptions, std::io};
+
+    #[tokio::test]
+    async fn smoke_sync() -> io::Result<()> {
+        let src = tempfile::tempdir()?;
+        let dst = tempfile::tempdir()?;
+
+        std::fs::write(src.path().join("file.txt"), b"hello")?;
+
+        let first = sync(src.path(), dst.path(), SyncOptions::default()).await?;
+        assert_eq!(first.copied.len(), 1);
+
+        let second = sync(src.path(), dst.path(), SyncOptions::default()).await?;
+        assert_eq!(second.skipped.len(), 1);
+        assert_eq!(std::fs::read_to_string(dst.path().join("file.txt"))?, "hello");
+
+        Ok(())
+    }
+}
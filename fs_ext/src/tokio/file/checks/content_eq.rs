@@ -0,0 +1,27 @@
+use {
+    crate::{file, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn content_eq(a: impl AsRef<Path>, b: impl AsRef<Path>) -> io::Result<bool> {
+    let a = a.as_ref().to_owned();
+    let b = b.as_ref().to_owned();
+    asyncify(move || file::content_eq(a, b)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::content_eq, std::io};
+
+    #[tokio::test]
+    async fn smoke_content_eq() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let a = dir.path().join("a.txt");
+        let b = dir.path().join("b.txt");
+        std::fs::write(&a, b"hello")?;
+        std::fs::write(&b, b"hello")?;
+
+        assert!(content_eq(&a, &b).await?);
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ mod assert_exists;
 mod assert_not_exists;
 mod assert_readable;
 mod assert_writable;
+mod content_eq;
 mod exists;
 mod is_empty;
 mod is_readable;
@@ -10,6 +11,7 @@ mod size;
 
 pub use {
     assert_exists::assert_exists, assert_not_exists::assert_not_exists,
-    assert_readable::assert_readable, assert_writable::assert_writable, exists::exists,
-    is_empty::is_empty, is_readable::is_readable, is_writable::is_writable, size::size,
+    assert_readable::assert_readable, assert_writable::assert_writable, content_eq::content_eq,
+    exists::exists, is_empty::is_empty, is_readable::is_readable, is_writable::is_writable,
+    size::size,
 };
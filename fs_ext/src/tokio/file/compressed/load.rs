@@ -0,0 +1,44 @@
+use {
+    crate::{CodecError, Format, file, tokio::utils::join_err_to_io},
+    serde::de::DeserializeOwned,
+    std::path::Path,
+    tokio::task,
+};
+
+pub async fn load<T, F>(path: impl AsRef<Path>) -> Result<T, CodecError>
+where
+    F: Format,
+    T: DeserializeOwned + Send + 'static,
+{
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::compressed::load::<T, F>(path))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::formats::Json,
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+    }
+
+    #[tokio::test]
+    async fn load_async_smoke() {
+        let dir = tempdir().unwrap();
+        let name = if cfg!(feature = "gzip") { "demo.json.gz" } else { "demo.json.zst" };
+        let path = dir.path().join(name);
+        file::compressed::save::<_, Json>(&path, &Demo { id: 1 }).unwrap();
+
+        let got: Demo = load::<_, Json>(&path).await.expect("async load should succeed");
+        assert_eq!(got, Demo { id: 1 });
+    }
+}
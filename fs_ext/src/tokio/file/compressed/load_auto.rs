@@ -0,0 +1,42 @@
+use {
+    crate::{CodecError, file, tokio::utils::join_err_to_io},
+    serde::de::DeserializeOwned,
+    std::path::Path,
+    tokio::task,
+};
+
+pub async fn load_auto<T>(path: impl AsRef<Path>) -> Result<T, CodecError>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::compressed::load_auto::<T>(path))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+    }
+
+    #[tokio::test]
+    async fn load_auto_async_smoke() {
+        let dir = tempdir().unwrap();
+        let name = if cfg!(feature = "gzip") { "demo.yaml.gz" } else { "demo.yaml.zst" };
+        let path = dir.path().join(name);
+        file::compressed::save_auto(&path, &Demo { id: 3 }).unwrap();
+
+        let got: Demo = load_auto(&path).await.expect("async load should succeed");
+        assert_eq!(got, Demo { id: 3 });
+    }
+}
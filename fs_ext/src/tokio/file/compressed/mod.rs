@@ -0,0 +1,12 @@
+mod load;
+mod load_auto;
+mod read_bytes;
+mod read_string;
+mod save;
+mod save_auto;
+mod write;
+
+pub use {
+    load::load, load_auto::load_auto, read_bytes::read_bytes, read_string::read_string, save::save,
+    save_auto::save_auto, write::write,
+};
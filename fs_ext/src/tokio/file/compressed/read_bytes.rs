@@ -0,0 +1,25 @@
+use {
+    crate::{file, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn read_bytes(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || file::compressed::read_bytes(path)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::read_bytes, crate::file, std::io};
+
+    #[tokio::test]
+    async fn smoke_read_bytes() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let name = if cfg!(feature = "gzip") { "file.bin.gz" } else { "file.bin.zst" };
+        let path = dir.path().join(name);
+        file::compressed::write(&path, [1, 2, 3])?;
+
+        assert_eq!(read_bytes(&path).await?, [1, 2, 3]);
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use {
+    crate::{file, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn read_string(path: impl AsRef<Path>) -> io::Result<String> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || file::compressed::read_string(path)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::read_string, crate::file, std::io};
+
+    #[tokio::test]
+    async fn smoke_read_string() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let name = if cfg!(feature = "zstd") { "app.log.zst" } else { "app.log.gz" };
+        let path = dir.path().join(name);
+        file::compressed::write(&path, "hello")?;
+
+        assert_eq!(read_string(&path).await?, "hello");
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use {
+    crate::{CodecError, Format, file, tokio::utils::join_err_to_io},
+    serde::Serialize,
+    std::path::Path,
+    tokio::task,
+};
+
+pub async fn save<T, F>(path: impl AsRef<Path>, model: T) -> Result<(), CodecError>
+where
+    F: Format,
+    T: Serialize + Send + 'static,
+{
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::compressed::save::<T, F>(path, model))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))??;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::formats::Toml,
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+    }
+
+    #[tokio::test]
+    async fn save_async_smoke() {
+        let dir = tempdir().unwrap();
+        let name = if cfg!(feature = "zstd") { "demo.toml.zst" } else { "demo.toml.gz" };
+        let path = dir.path().join(name);
+
+        save::<_, Toml>(&path, Demo { id: 2 }).await.expect("async save should succeed");
+
+        let got: Demo = file::compressed::load::<_, Toml>(&path).unwrap();
+        assert_eq!(got, Demo { id: 2 });
+    }
+}
@@ -0,0 +1,45 @@
+use {
+    crate::{CodecError, file, tokio::utils::join_err_to_io},
+    serde::Serialize,
+    std::path::Path,
+    tokio::task,
+};
+
+pub async fn save_auto<T>(path: impl AsRef<Path>, model: T) -> Result<(), CodecError>
+where
+    T: Serialize + Send + 'static,
+{
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::compressed::save_auto(path, &model))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))??;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+    }
+
+    #[tokio::test]
+    async fn save_auto_async_smoke() {
+        let dir = tempdir().unwrap();
+        let name = if cfg!(feature = "zstd") { "demo.json.zst" } else { "demo.json.gz" };
+        let path = dir.path().join(name);
+
+        save_auto(&path, Demo { id: 4 }).await.expect("async save should succeed");
+
+        let got: Demo = file::compressed::load_auto(&path).unwrap();
+        assert_eq!(got, Demo { id: 4 });
+    }
+}
@@ -0,0 +1,27 @@
+use {
+    crate::{file, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn write(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let path = path.as_ref().to_owned();
+    let contents = contents.as_ref().to_vec();
+    asyncify(move || file::compressed::write(path, contents)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::write, crate::file, std::io};
+
+    #[tokio::test]
+    async fn smoke_write() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let name = if cfg!(feature = "gzip") { "out.txt.gz" } else { "out.txt.zst" };
+        let path = dir.path().join(name);
+
+        write(&path, "compressed").await?;
+
+        assert_eq!(file::compressed::read_string(&path)?, "compressed");
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use {
+    crate::{IoResultExt, Operation},
     std::{io, path::Path},
     tokio::{
         fs::{File, OpenOptions},
@@ -15,34 +16,31 @@ async fn _ensure_or_init(path: &Path, content: &[u8]) -> io::Result<File> {
         Ok(file) => Ok(file),
 
         Err(e) if e.kind() == io::ErrorKind::NotFound => {
-            let mut file =
-                OpenOptions::new().write(true).create(true).open(path).await.map_err(|e| {
-                    io::Error::new(
-                        e.kind(),
-                        format!("Failed to create file at '{}': {e}", path.display()),
-                    )
-                })?;
-
-            file.write_all(content).await.map_err(|e| {
-                io::Error::new(
-                    e.kind(),
-                    format!("Failed to write to file at '{}': {e}", path.display()),
-                )
-            })?;
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .open(path)
+                .await
+                .with_path_context(Operation::Create, path)?;
+
+            file.write_all(content).await.with_path_context(Operation::Write, path)?;
 
             Ok(file)
         }
 
-        Err(e) => Err(io::Error::new(
-            e.kind(),
-            format!("Failed to open file at '{}': {e}", path.display()),
-        )),
+        Err(e) => Err(e).with_path_context(Operation::Open, path),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use {super::ensure_or_init, std::io, tempfile::tempdir, tokio::fs};
+    use {
+        super::ensure_or_init,
+        crate::{FsError, Operation},
+        std::io,
+        tempfile::tempdir,
+        tokio::fs,
+    };
 
     #[tokio::test]
     async fn creates_file_and_writes_content() {
@@ -94,4 +92,17 @@ mod tests {
             "Unexpected error kind for directory: {kind:?}"
         );
     }
+
+    #[tokio::test]
+    async fn missing_parent_yields_structured_error() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("missing/test.txt");
+
+        let err = ensure_or_init(&file_path, "x").await.unwrap_err();
+
+        let fs_err = FsError::downcast_ref(&err).expect("structured error");
+        assert_eq!(fs_err.operation(), Operation::Create);
+        assert_eq!(fs_err.path(), file_path);
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
 }
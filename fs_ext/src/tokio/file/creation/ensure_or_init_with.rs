@@ -1,4 +1,5 @@
 use {
+    crate::{IoResultExt, Operation},
     std::{io, path::Path},
     tokio::{
         fs::{File, OpenOptions},
@@ -20,28 +21,19 @@ where
             let content = content_fn();
             let bytes = content.as_ref();
 
-            let mut file =
-                OpenOptions::new().write(true).create(true).open(path).await.map_err(|e| {
-                    io::Error::new(
-                        e.kind(),
-                        format!("Failed to create file at '{}': {e}", path.display()),
-                    )
-                })?;
-
-            file.write_all(bytes).await.map_err(|e| {
-                io::Error::new(
-                    e.kind(),
-                    format!("Failed to write to file at '{}': {e}", path.display()),
-                )
-            })?;
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .open(path)
+                .await
+                .with_path_context(Operation::Create, path)?;
+
+            file.write_all(bytes).await.with_path_context(Operation::Write, path)?;
 
             Ok(file)
         }
 
-        Err(e) => Err(io::Error::new(
-            e.kind(),
-            format!("Failed to open file at '{}': {e}", path.display()),
-        )),
+        Err(e) => Err(e).with_path_context(Operation::Open, path),
     }
 }
 
@@ -0,0 +1,33 @@
+use {
+    crate::{CodecError, file, tokio::utils::join_err_to_io},
+    serde_json::Value,
+    std::path::Path,
+    tokio::task,
+};
+
+pub async fn edit_json<R, F>(path: impl AsRef<Path>, f: F) -> Result<R, CodecError>
+where
+    F: FnOnce(&mut Value) -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::edit_json(path, f))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::fs, tempfile::tempdir};
+
+    #[tokio::test]
+    async fn edit_json_async_smoke() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("app.json");
+        fs::write(&path, "{\n  \"b\": 1,\n  \"a\": 1\n}\n").unwrap();
+
+        edit_json(&path, |v| v["b"] = 2.into()).await.expect("async edit should succeed");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\n  \"b\": 2,\n  \"a\": 1\n}\n");
+    }
+}
@@ -0,0 +1,35 @@
+use {
+    crate::{CodecError, file, tokio::utils::join_err_to_io},
+    std::path::Path,
+    tokio::task,
+    toml_edit::DocumentMut,
+};
+
+pub async fn edit_toml<R, F>(path: impl AsRef<Path>, f: F) -> Result<R, CodecError>
+where
+    F: FnOnce(&mut DocumentMut) -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::edit_toml(path, f))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::fs, tempfile::tempdir};
+
+    #[tokio::test]
+    async fn edit_toml_async_smoke() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("app.toml");
+        fs::write(&path, "# keep me\nport = 1\n").unwrap();
+
+        edit_toml(&path, |doc| doc["port"] = toml_edit::value(2))
+            .await
+            .expect("async edit should succeed");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "# keep me\nport = 2\n");
+    }
+}
@@ -0,0 +1,33 @@
+use {
+    crate::{CodecError, file, tokio::utils::join_err_to_io},
+    serde_yaml::Value,
+    std::path::Path,
+    tokio::task,
+};
+
+pub async fn edit_yaml<R, F>(path: impl AsRef<Path>, f: F) -> Result<R, CodecError>
+where
+    F: FnOnce(&mut Value) -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::edit_yaml(path, f))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::fs, tempfile::tempdir};
+
+    #[tokio::test]
+    async fn edit_yaml_async_smoke() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("app.yaml");
+        fs::write(&path, "# keep me\nb: 1\na: 1\n").unwrap();
+
+        edit_yaml(&path, |v| v["b"] = 2.into()).await.expect("async edit should succeed");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "# keep me\nb: 2\na: 1\n");
+    }
+}
@@ -0,0 +1,5 @@
+mod edit_json;
+mod edit_toml;
+mod edit_yaml;
+
+pub use {edit_json::edit_json, edit_toml::edit_toml, edit_yaml::edit_yaml};
@@ -0,0 +1,25 @@
+use {
+    crate::{Digest, HashAlgorithm, file, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn hash(path: impl AsRef<Path>, algorithm: HashAlgorithm) -> io::Result<Digest> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || file::hash(path, algorithm)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::hash, crate::test_utils::enabled_hash, std::io};
+
+    #[tokio::test]
+    async fn smoke_hash() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.bin");
+        std::fs::write(&path, b"hello")?;
+
+        let algorithm = enabled_hash();
+        assert_eq!(hash(&path, algorithm).await?, crate::file::hash(&path, algorithm)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use {
+    crate::{Digest, HashAlgorithm, file, tokio::utils::asyncify},
+    std::{
+        io,
+        path::{Path, PathBuf},
+    },
+};
+
+pub async fn read_checksums(
+    path: impl AsRef<Path>, algorithm: HashAlgorithm,
+) -> io::Result<Vec<(PathBuf, Digest)>> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || file::read_checksums(path, algorithm)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::read_checksums, crate::test_utils::enabled_hash, std::io};
+
+    #[tokio::test]
+    async fn smoke_read_checksums() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let algorithm = enabled_hash();
+        let sums = dir.path().join("SUMS");
+        std::fs::write(&sums, format!("{}  a.txt\n", "0".repeat(algorithm.digest_len() * 2)))?;
+
+        let entries = read_checksums(&sums, algorithm).await?;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].0, std::path::Path::new("a.txt"));
+        Ok(())
+    }
+}
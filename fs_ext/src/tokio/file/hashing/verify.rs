@@ -0,0 +1,26 @@
+use {
+    crate::{Digest, file, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn verify(path: impl AsRef<Path>, expected: &Digest) -> io::Result<bool> {
+    let path = path.as_ref().to_owned();
+    let expected = expected.clone();
+    asyncify(move || file::verify(path, &expected)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::verify, crate::test_utils::enabled_hash, std::io};
+
+    #[tokio::test]
+    async fn smoke_verify() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.bin");
+        std::fs::write(&path, b"hello")?;
+
+        let expected = crate::file::hash(&path, enabled_hash())?;
+        assert!(verify(&path, &expected).await?);
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use {
+    crate::{HashAlgorithm, file, tokio::utils::asyncify},
+    std::{
+        io,
+        path::{Path, PathBuf},
+    },
+};
+
+pub async fn verify_checksums(
+    path: impl AsRef<Path>, algorithm: HashAlgorithm,
+) -> io::Result<Vec<PathBuf>> {
+    let path = path.as_ref().to_owned();
+    asyncify(move || file::verify_checksums(path, algorithm)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::verify_checksums, crate::test_utils::enabled_hash, std::io};
+
+    #[tokio::test]
+    async fn smoke_verify_checksums() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let algorithm = enabled_hash();
+        let file = dir.path().join("a.txt");
+        std::fs::write(&file, b"a")?;
+        let sums = dir.path().join("SUMS");
+        crate::file::write_checksums(
+            &sums,
+            &[("a.txt".into(), crate::file::hash(&file, algorithm)?)],
+        )?;
+
+        assert!(verify_checksums(&sums, algorithm).await?.is_empty());
+        Ok(())
+    }
+}
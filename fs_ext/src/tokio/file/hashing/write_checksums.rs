@@ -0,0 +1,33 @@
+use {
+    crate::{Digest, file, tokio::utils::asyncify},
+    std::{
+        io,
+        path::{Path, PathBuf},
+    },
+};
+
+pub async fn write_checksums(
+    path: impl AsRef<Path>, entries: &[(PathBuf, Digest)],
+) -> io::Result<()> {
+    let path = path.as_ref().to_owned();
+    let entries = entries.to_vec();
+    asyncify(move || file::write_checksums(path, &entries)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::write_checksums, crate::test_utils::enabled_hash, std::io};
+
+    #[tokio::test]
+    async fn smoke_write_checksums() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("a.txt");
+        std::fs::write(&file, b"a")?;
+        let entries = vec![("a.txt".into(), crate::file::hash(&file, enabled_hash())?)];
+
+        let sums = dir.path().join("SUMS");
+        write_checksums(&sums, &entries).await?;
+        assert_eq!(std::fs::read_to_string(&sums)?, format!("{}  a.txt\n", entries[0].1));
+        Ok(())
+    }
+}
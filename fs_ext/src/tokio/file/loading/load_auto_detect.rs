@@ -0,0 +1,46 @@
+use {
+    crate::{CodecError, Detected, file, tokio::utils::join_err_to_io},
+    serde::de::DeserializeOwned,
+    std::path::Path,
+    tokio::task,
+};
+
+pub async fn load_auto_detect<T>(path: impl AsRef<Path>) -> Result<Detected<T>, CodecError>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::load_auto_detect::<T>(path))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::DetectedBy,
+        serde::{Deserialize, Serialize},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+    }
+
+    #[tokio::test]
+    async fn load_auto_detect_async_smoke() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("app.conf");
+        fs::write(&path, "id = 1\nname = \"alpha\"\n").unwrap();
+
+        let got: Detected<Demo> =
+            load_auto_detect(&path).await.expect("async detect should succeed");
+        assert_eq!(got.value, Demo { id: 1, name: "alpha".into() });
+        assert_eq!((got.format, got.by), ("TOML", DetectedBy::Content));
+    }
+}
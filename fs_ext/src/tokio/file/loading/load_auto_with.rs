@@ -0,0 +1,50 @@
+use {
+    crate::{CodecError, FormatRegistry, file, tokio::utils::join_err_to_io},
+    serde::de::DeserializeOwned,
+    std::path::Path,
+    tokio::task,
+};
+
+pub async fn load_auto_with<T>(
+    registry: &FormatRegistry, path: impl AsRef<Path>,
+) -> Result<T, CodecError>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let registry = registry.clone();
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::load_auto_with::<T>(&registry, path))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::formats::Json,
+        serde::{Deserialize, Serialize},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+    }
+
+    #[tokio::test]
+    async fn load_auto_with_async_smoke() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.data");
+        fs::write(&path, r#"{ "id": 1, "name": "alpha" }"#).unwrap();
+
+        let mut registry = FormatRegistry::empty();
+        registry.register::<Json>(["data"]);
+
+        let got: Demo = load_auto_with(&registry, &path).await.expect("async load should succeed");
+        assert_eq!(got, Demo { id: 1, name: "alpha".into() });
+    }
+}
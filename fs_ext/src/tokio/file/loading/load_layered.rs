@@ -0,0 +1,36 @@
+use {
+    crate::{CodecError, Layer, Layered, LayeredOptions, tokio::file},
+    serde::de::DeserializeOwned,
+};
+
+pub async fn load_layered<T>(
+    layers: impl IntoIterator<Item = impl Into<Layer>>,
+) -> Result<Layered<T>, CodecError>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    file::load_layered_with(layers, LayeredOptions::default()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde::Deserialize, std::fs, tempfile::tempdir};
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+    }
+
+    #[tokio::test]
+    async fn load_layered_async_smoke() {
+        let dir = tempdir().unwrap();
+        let (base, over) = (dir.path().join("base.toml"), dir.path().join("over.json"));
+        fs::write(&base, "id = 1\nname = \"alpha\"\n").unwrap();
+        fs::write(&over, r#"{ "id": 2 }"#).unwrap();
+
+        let got: Layered<Demo> =
+            load_layered([base, over]).await.expect("async layered load should succeed");
+        assert_eq!(got.value, Demo { id: 2, name: "alpha".into() });
+    }
+}
@@ -0,0 +1,46 @@
+use {
+    crate::{CodecError, Layer, Layered, LayeredOptions, file, tokio::utils::join_err_to_io},
+    serde::de::DeserializeOwned,
+    tokio::task,
+};
+
+pub async fn load_layered_with<T>(
+    layers: impl IntoIterator<Item = impl Into<Layer>>, options: impl AsRef<LayeredOptions>,
+) -> Result<Layered<T>, CodecError>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let layers: Vec<Layer> = layers.into_iter().map(Into::into).collect();
+    let options = options.as_ref().clone();
+
+    task::spawn_blocking(move || file::load_layered_with::<T>(layers, options))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::LayerSource, serde::Deserialize, std::fs, tempfile::tempdir};
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Demo {
+        id: u32,
+    }
+
+    #[tokio::test]
+    async fn load_layered_with_async_smoke() {
+        let dir = tempdir().unwrap();
+        let base = dir.path().join("base.yaml");
+        fs::write(&base, "id: 1\n").unwrap();
+
+        let got: Layered<Demo> = load_layered_with(
+            [Layer::required(&base), Layer::optional(dir.path().join("local.yaml"))],
+            LayeredOptions::default(),
+        )
+        .await
+        .expect("async layered load should succeed");
+        assert_eq!(got.value, Demo { id: 1 });
+        assert_eq!(got.source("id"), Some(&LayerSource::File(base)));
+        assert_eq!(got.skipped.len(), 1);
+    }
+}
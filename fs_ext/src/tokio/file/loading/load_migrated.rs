@@ -0,0 +1,56 @@
+use {
+    crate::{CodecError, Format, Versioned, file, tokio::utils::join_err_to_io},
+    std::path::Path,
+    tokio::task,
+};
+
+pub async fn load_migrated<T, F>(path: impl AsRef<Path>) -> Result<T, CodecError>
+where
+    F: Format,
+    T: Versioned + Send + 'static,
+{
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::load_migrated::<T, F>(path))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{Migrations, formats::Json},
+        serde::{Deserialize, Serialize},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+    }
+
+    impl Versioned for Demo {
+        const VERSION: u32 = 1;
+
+        fn migrations() -> Migrations {
+            Migrations::new().step(0, |_| Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn load_migrated_async_smoke() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.json");
+        fs::write(&path, r#"{"id":7}"#).unwrap();
+
+        let got: Demo =
+            load_migrated::<_, Json>(&path).await.expect("async migrated load should succeed");
+        assert_eq!(got, Demo { id: 7 });
+
+        let rewritten: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(rewritten["version"], 1);
+    }
+}
@@ -0,0 +1,55 @@
+use {
+    crate::{CodecError, Format, Versioned, file, tokio::utils::join_err_to_io},
+    std::path::Path,
+    tokio::task,
+};
+
+pub async fn load_versioned<T, F>(path: impl AsRef<Path>) -> Result<T, CodecError>
+where
+    F: Format,
+    T: Versioned + Send + 'static,
+{
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::load_versioned::<T, F>(path))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{Migrations, formats::Json},
+        serde::{Deserialize, Serialize},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+    }
+
+    impl Versioned for Demo {
+        const VERSION: u32 = 1;
+
+        fn migrations() -> Migrations {
+            Migrations::new().step(0, |doc| {
+                doc["id"] = doc["legacy_id"].take();
+                Ok(())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn load_versioned_async_smoke() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.json");
+        fs::write(&path, r#"{ "legacy_id": 7 }"#).unwrap();
+
+        let got: Demo =
+            load_versioned::<_, Json>(&path).await.expect("async versioned load should succeed");
+        assert_eq!(got, Demo { id: 7 });
+    }
+}
@@ -1,11 +1,21 @@
 mod load;
 mod load_auto;
+mod load_auto_detect;
+mod load_auto_with;
+mod load_layered;
+mod load_layered_with;
+mod load_migrated;
 mod load_or_default;
 mod load_or_init;
 mod load_or_init_with;
 mod load_or_write_str;
+mod load_versioned;
 
 pub use {
-    load::load, load_auto::load_auto, load_or_default::load_or_default, load_or_init::load_or_init,
+    load::load, load_auto::load_auto, load_auto_detect::load_auto_detect,
+    load_auto_with::load_auto_with, load_layered::load_layered,
+    load_layered_with::load_layered_with, load_migrated::load_migrated,
+    load_or_default::load_or_default, load_or_init::load_or_init,
     load_or_init_with::load_or_init_with, load_or_write_str::load_or_write_str,
+    load_versioned::load_versioned,
 };
@@ -0,0 +1,32 @@
+use {
+    crate::{CopyOptions, file, tokio::utils::asyncify},
+    std::{io, path::Path},
+};
+
+pub async fn copy_with(
+    src: impl AsRef<Path>, dst: impl AsRef<Path>, options: impl AsRef<CopyOptions>,
+) -> io::Result<u64> {
+    let src = src.as_ref().to_owned();
+    let dst = dst.as_ref().to_owned();
+    let options = *options.as_ref();
+    asyncify(move || file::copy_with(src, dst, options)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::copy_with, crate::CopyOptions, std::io};
+
+    #[tokio::test]
+    async fn smoke_copy_with() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let src = dir.path().join("a.txt");
+        let dst = dir.path().join("b.txt");
+        std::fs::write(&src, b"hello")?;
+
+        let n = copy_with(&src, &dst, CopyOptions::default()).await?;
+        assert_eq!(n, 5);
+        assert_eq!(std::fs::read_to_string(&dst)?, "hello");
+
+        Ok(())
+    }
+}
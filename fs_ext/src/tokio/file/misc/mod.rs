@@ -1,4 +1,6 @@
 mod append;
+mod copy_with;
+mod move_to;
 mod open;
 
-pub use {append::append, open::open};
+pub use {append::append, copy_with::copy_with, move_to::move_to, open::open};
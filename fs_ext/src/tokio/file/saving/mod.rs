@@ -1,4 +1,9 @@
 mod save;
 mod save_auto;
+mod save_auto_with;
+mod save_versioned;
 
-pub use {save::save, save_auto::save_auto};
+pub use {
+    save::save, save_auto::save_auto, save_auto_with::save_auto_with,
+    save_versioned::save_versioned,
+};
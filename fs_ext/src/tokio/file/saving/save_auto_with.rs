@@ -0,0 +1,53 @@
+use {
+    crate::{CodecError, FormatRegistry, file, tokio::utils::join_err_to_io},
+    serde::Serialize,
+    std::path::Path,
+    tokio::task,
+};
+
+pub async fn save_auto_with<T>(
+    registry: &FormatRegistry, path: impl AsRef<Path>, model: T,
+) -> Result<(), CodecError>
+where
+    T: Serialize + Send + 'static,
+{
+    let registry = registry.clone();
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::save_auto_with::<T>(&registry, path, &model))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))??;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{Format, formats::Json},
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+    }
+
+    #[tokio::test]
+    async fn save_auto_with_async_smoke() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.data");
+        let model = Demo { id: 1, name: "alpha".into() };
+
+        let mut registry = FormatRegistry::empty();
+        registry.register::<Json>(["data"]);
+
+        save_auto_with(&registry, &path, model.clone()).await.expect("async save should succeed");
+
+        let roundtrip: Demo = Json::load(&path).expect("sync load should succeed");
+        assert_eq!(roundtrip, model);
+    }
+}
@@ -0,0 +1,49 @@
+use {
+    crate::{CodecError, Format, Versioned, file, tokio::utils::join_err_to_io},
+    std::path::Path,
+    tokio::task,
+};
+
+pub async fn save_versioned<T, F>(path: impl AsRef<Path>, model: T) -> Result<(), CodecError>
+where
+    F: Format,
+    T: Versioned + Send + 'static,
+{
+    let path = path.as_ref().to_owned();
+
+    task::spawn_blocking(move || file::save_versioned::<T, F>(path, &model))
+        .await
+        .map_err(|e| CodecError::from(join_err_to_io(e)))??;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::formats::Json,
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+    }
+
+    impl Versioned for Demo {
+        const VERSION: u32 = 3;
+    }
+
+    #[tokio::test]
+    async fn save_versioned_async_smoke() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.json");
+
+        save_versioned::<_, Json>(&path, Demo { id: 1 }).await.expect("async save should succeed");
+
+        let raw: serde_json::Value = Json::load(&path).unwrap();
+        assert_eq!(raw, serde_json::json!({ "version": 3, "id": 1 }));
+    }
+}
@@ -1,4 +1,5 @@
 use {
+    crate::{IoResultExt, Operation},
     std::path::{Path, PathBuf},
     tokio::{
         fs::File,
@@ -16,9 +17,7 @@ pub async fn stream_bytes(
     }
 
     let path_buf = path.as_ref().to_owned();
-    let file = File::open(&path_buf).await.map_err(|e| {
-        io::Error::new(e.kind(), format!("Failed to open file '{}': {}", path_buf.display(), e))
-    })?;
+    let file = File::open(&path_buf).await.with_path_context(Operation::Open, &path_buf)?;
 
     Ok(AsyncByteChunkReader::new(BufReader::new(file), chunk_size, path_buf))
 }
@@ -38,10 +37,7 @@ impl<R: AsyncRead + Unpin> AsyncByteChunkReader<R> {
         match self.reader.read(&mut self.buffer).await {
             Ok(0) => None, // EOF
             Ok(n) => Some(Ok(self.buffer[..n].to_vec())),
-            Err(e) => Some(Err(io::Error::new(
-                e.kind(),
-                format!("Failed to read chunk from '{}': {}", self.path.display(), e),
-            ))),
+            Err(e) => Some(Err(e).with_path_context(Operation::Read, &self.path)),
         }
     }
 }
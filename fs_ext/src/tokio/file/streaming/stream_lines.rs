@@ -1,4 +1,5 @@
 use {
+    crate::{IoResultExt, Operation},
     std::{io, path::Path},
     tokio::{
         fs::File,
@@ -8,15 +9,18 @@ use {
 
 pub async fn stream_lines(path: impl AsRef<Path>) -> io::Result<tokio::io::Lines<BufReader<File>>> {
     let path = path.as_ref();
-    let file = File::open(path).await.map_err(|e| {
-        io::Error::new(e.kind(), format!("Failed to open file '{}': {}", path.display(), e))
-    })?;
+    let file = File::open(path).await.with_path_context(Operation::Open, path)?;
     Ok(BufReader::new(file).lines())
 }
 
 #[cfg(test)]
 mod tests {
-    use {super::stream_lines, tempfile::tempdir, tokio::fs};
+    use {
+        super::stream_lines,
+        crate::{FsError, Operation},
+        tempfile::tempdir,
+        tokio::fs,
+    };
 
     #[tokio::test]
     async fn reads_multiple_lines() {
@@ -59,4 +63,16 @@ mod tests {
         }
         assert_eq!(out, vec!["alpha", "beta"]);
     }
+
+    #[tokio::test]
+    async fn missing_file_yields_structured_error() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("missing.txt");
+
+        let err = stream_lines(&file).await.unwrap_err();
+
+        let fs_err = FsError::downcast_ref(&err).expect("structured error");
+        assert_eq!(fs_err.operation(), Operation::Open);
+        assert_eq!(fs_err.path(), file);
+    }
 }
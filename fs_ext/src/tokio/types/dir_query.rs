@@ -84,6 +84,32 @@ impl DirQuery {
     pub async fn exists(self) -> io::Result<bool> {
         Ok(self.collect().await?.len() != 0)
     }
+
+    #[cfg(any(feature = "tar", feature = "zip"))]
+    pub async fn archive(
+        self, dst: impl AsRef<Path>, format: crate::ArchiveFormat,
+    ) -> io::Result<()> {
+        let dst = dst.as_ref().to_owned();
+        tokio::task::spawn_blocking(move || self.inner.archive(dst, format))
+            .await
+            .map_err(join_err_to_io)?
+    }
+
+    #[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+    pub async fn hash_tree(self, algorithm: crate::HashAlgorithm) -> io::Result<crate::Digest> {
+        tokio::task::spawn_blocking(move || self.inner.hash_tree(algorithm))
+            .await
+            .map_err(join_err_to_io)?
+    }
+
+    #[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+    pub async fn find_duplicates(
+        self, algorithm: crate::HashAlgorithm,
+    ) -> io::Result<Vec<crate::DuplicateGroup>> {
+        tokio::task::spawn_blocking(move || self.inner.find_duplicates(algorithm))
+            .await
+            .map_err(join_err_to_io)?
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,80 @@
+use {
+    crate::{
+        CodecError, DeserializeError, Diagnostic, FsError, IoResultExt, Operation, SerializeError,
+        file, utils::short_type_name,
+    },
+    serde::{Serialize, de::DeserializeOwned},
+    std::{
+        fs::File,
+        io::{BufReader, BufWriter, Read, Write},
+        path::Path,
+    },
+};
+
+// `from_reader` and `to_writer` are required; `from_bytes` / `to_bytes` go
+// through them.
+pub trait BinaryFormat {
+    fn from_bytes<T>(bytes: &[u8]) -> Result<T, DeserializeError>
+    where
+        T: DeserializeOwned,
+    {
+        Self::from_reader(bytes).map_err(|e| match e {
+            CodecError::Deserialize(e) => e,
+            other => {
+                let diagnostic =
+                    Diagnostic::new(short_type_name::<Self>(), "", None, other.to_string());
+                DeserializeError::other(diagnostic, other)
+            }
+        })
+    }
+
+    fn to_bytes<T>(value: T) -> Result<Vec<u8>, SerializeError>
+    where
+        T: Serialize,
+    {
+        let mut buf = Vec::new();
+        Self::to_writer(&mut buf, value).map_err(|e| match e {
+            CodecError::Serialize(e) => e,
+            other => SerializeError::other(short_type_name::<Self>(), other),
+        })?;
+        Ok(buf)
+    }
+
+    fn from_reader<R, T>(reader: R) -> Result<T, CodecError>
+    where
+        R: Read,
+        T: DeserializeOwned;
+
+    fn to_writer<W, T>(writer: W, value: T) -> Result<(), CodecError>
+    where
+        W: Write,
+        T: Serialize;
+
+    fn load<T>(path: impl AsRef<Path>) -> Result<T, CodecError>
+    where
+        Self: Sized,
+        T: DeserializeOwned,
+    {
+        let path = path.as_ref();
+        let file = File::open(path).with_path_context(Operation::Read, path)?;
+
+        Self::from_reader(BufReader::new(file)).map_err(|e| match e {
+            CodecError::Deserialize(e) => CodecError::Deserialize(e.with_path(path)),
+            CodecError::Io(e) => CodecError::Io(FsError::new(Operation::Read, path, e).into()),
+            other => other,
+        })
+    }
+
+    fn save<T>(path: impl AsRef<Path>, value: T) -> Result<(), CodecError>
+    where
+        Self: Sized,
+        T: Serialize,
+    {
+        file::atomic::overwrite(path, |file| {
+            let mut writer = BufWriter::new(file);
+            Self::to_writer(&mut writer, value)?;
+            writer.flush().map_err(CodecError::Io)
+        })
+        .map_err(CodecError::from_io)
+    }
+}
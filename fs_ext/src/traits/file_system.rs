@@ -0,0 +1,100 @@
+use {
+    crate::VfsMetadata,
+    std::{
+        io,
+        path::{Path, PathBuf},
+    },
+};
+
+// Whole-buffer, path-based primitives: small enough to implement in memory or
+// to wrap for fault injection, while the `vfs` helpers build the rest on top.
+// Implementations attach path context to their errors like the std wrappers do.
+pub trait FileSystem: Send + Sync {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()>;
+    fn append(&self, path: &Path, contents: &[u8]) -> io::Result<()>;
+    fn create_new(&self, path: &Path, contents: &[u8]) -> io::Result<()>;
+    fn metadata(&self, path: &Path) -> io::Result<VfsMetadata>;
+    // Like `metadata`, but a symlink reports `PathKind::SymLink` instead of
+    // its target. Tree walks use it so a link never leads them anywhere.
+    fn symlink_metadata(&self, path: &Path) -> io::Result<VfsMetadata>;
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+    fn create_dir(&self, path: &Path) -> io::Result<()>;
+    fn create_dir_all(&self, path: &Path) -> io::Result<()>;
+    fn remove_file(&self, path: &Path) -> io::Result<()>;
+    fn remove_dir(&self, path: &Path) -> io::Result<()>;
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()>;
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()>;
+    fn copy(&self, from: &Path, to: &Path) -> io::Result<u64>;
+    fn sync(&self, path: &Path) -> io::Result<()>;
+
+    fn exists(&self, path: &Path) -> bool {
+        self.metadata(path).is_ok()
+    }
+}
+
+impl<S: FileSystem + ?Sized> FileSystem for &S {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        (**self).read(path)
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        (**self).write(path, contents)
+    }
+
+    fn append(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        (**self).append(path, contents)
+    }
+
+    fn create_new(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        (**self).create_new(path, contents)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<VfsMetadata> {
+        (**self).metadata(path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<VfsMetadata> {
+        (**self).symlink_metadata(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        (**self).read_dir(path)
+    }
+
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        (**self).create_dir(path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        (**self).create_dir_all(path)
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        (**self).remove_file(path)
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        (**self).remove_dir(path)
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        (**self).remove_dir_all(path)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        (**self).rename(from, to)
+    }
+
+    fn copy(&self, from: &Path, to: &Path) -> io::Result<u64> {
+        (**self).copy(from, to)
+    }
+
+    fn sync(&self, path: &Path) -> io::Result<()> {
+        (**self).sync(path)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        (**self).exists(path)
+    }
+}
@@ -1,9 +1,15 @@
 use {
-    crate::{CodecError, DeserializeError, SerializeError, file},
+    crate::{CodecError, DeserializeError, FsError, IoResultExt, Operation, SerializeError, file},
     serde::{Serialize, de::DeserializeOwned},
-    std::{io::Write, path::Path},
+    std::{
+        fs::File,
+        io::{BufRead, BufReader, BufWriter, Read, Write},
+        path::Path,
+    },
 };
 
+// `parse_str` and `to_string` are required. `from_reader` / `to_writer` default
+// to them; override those to stream, or to read and write non-UTF-8 bytes.
 pub trait Format {
     fn parse_str<T>(s: &str) -> Result<T, DeserializeError>
     where
@@ -13,13 +19,39 @@ pub trait Format {
     where
         T: Serialize;
 
+    fn from_reader<R, T>(mut reader: R) -> Result<T, CodecError>
+    where
+        R: Read,
+        T: DeserializeOwned,
+    {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        Ok(Self::parse_str(&s)?)
+    }
+
+    fn to_writer<W, T>(mut writer: W, value: T) -> Result<(), CodecError>
+    where
+        W: Write,
+        T: Serialize,
+    {
+        let s = Self::to_string(value)?;
+        writer.write_all(s.as_bytes())?;
+        Ok(())
+    }
+
     fn load<T>(path: impl AsRef<Path>) -> Result<T, CodecError>
     where
         Self: Sized,
-        T: serde::de::DeserializeOwned,
+        T: DeserializeOwned,
     {
-        let s = file::read_string(path)?;
-        Self::parse_str(&s).map_err(CodecError::Deserialize)
+        let path = path.as_ref();
+        let file = File::open(path).with_path_context(Operation::Read, path)?;
+
+        Self::from_reader(BufReader::new(file)).map_err(|e| match e {
+            CodecError::Deserialize(e) => CodecError::Deserialize(with_file_context(e, path)),
+            CodecError::Io(e) => CodecError::Io(FsError::new(Operation::Read, path, e).into()),
+            other => other,
+        })
     }
 
     fn save<T>(path: impl AsRef<Path>, value: T) -> Result<(), CodecError>
@@ -27,9 +59,167 @@ pub trait Format {
         Self: Sized,
         T: Serialize,
     {
-        let s = Self::to_string(value)?;
-        file::atomic::overwrite(path, |file| file.write_all(s.as_bytes()))?;
+        file::atomic::overwrite(path, |file| {
+            let mut writer = BufWriter::new(file);
+            Self::to_writer(&mut writer, value)?;
+            writer.flush().map_err(CodecError::Io)
+        })
+        .map_err(CodecError::from_io)
+    }
+}
 
-        Ok(())
+// Streaming parsers don't keep the input around, so the offending line is
+// fetched from the file only once an error needs rendering.
+pub(crate) fn with_file_context(e: DeserializeError, path: &Path) -> DeserializeError {
+    let mut e = e.with_path(path);
+    let diagnostic = e.diagnostic_mut();
+    if let (Some(line), None) = (diagnostic.line, &diagnostic.snippet) {
+        diagnostic.snippet = File::open(path)
+            .ok()
+            .and_then(|f| BufReader::new(f).lines().nth(line.checked_sub(1)?)?.ok());
+    }
+    e
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            Diagnostic,
+            formats::{Json, Yaml},
+        },
+        serde::Deserialize,
+        std::{collections::BTreeMap, fs},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+    }
+
+    // Latin-1 `key=value` lines: the string methods work on decoded text and
+    // the reader/writer pair converts bytes, so files need not be UTF-8.
+    enum Latin1 {}
+    impl Format for Latin1 {
+        fn parse_str<T>(s: &str) -> Result<T, DeserializeError>
+        where
+            T: DeserializeOwned,
+        {
+            let value: serde_json::Map<_, _> = s
+                .lines()
+                .filter_map(|l| l.split_once('='))
+                .map(|(k, v)| {
+                    let v = v.parse::<u64>().map_or_else(|_| v.into(), Into::into);
+                    (k.to_owned(), v)
+                })
+                .collect();
+            serde_json::from_value(value.into()).map_err(|e| {
+                DeserializeError::other(Diagnostic::new("Latin1", s, None, e.to_string()), e)
+            })
+        }
+
+        fn to_string<T>(value: T) -> Result<String, SerializeError>
+        where
+            T: Serialize,
+        {
+            let value = serde_json::to_value(value).map_err(SerializeError::Json)?;
+            let mut out = String::new();
+            for (k, v) in value.as_object().into_iter().flatten() {
+                let v = v.as_str().map_or_else(|| v.to_string(), str::to_owned);
+                out.push_str(&format!("{k}={v}\n"));
+            }
+            Ok(out)
+        }
+
+        fn from_reader<R, T>(mut reader: R) -> Result<T, CodecError>
+        where
+            R: Read,
+            T: DeserializeOwned,
+        {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes)?;
+            let text: String = bytes.iter().map(|&b| b as char).collect();
+            Ok(Self::parse_str(&text)?)
+        }
+
+        fn to_writer<W, T>(mut writer: W, value: T) -> Result<(), CodecError>
+        where
+            W: Write,
+            T: Serialize,
+        {
+            let bytes: Vec<u8> = Self::to_string(value)?.chars().map(|c| c as u8).collect();
+            writer.write_all(&bytes)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn byte_oriented_format_roundtrips_non_utf8() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.latin1");
+        let v = Demo { id: 1, name: "caf\u{e9}".into() };
+
+        Latin1::save(&path, &v).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"id=1\nname=caf\xe9\n");
+        assert_eq!(Latin1::load::<Demo>(&path).unwrap(), v);
+
+        assert_eq!(Latin1::to_string(&v).unwrap(), "id=1\nname=caf\u{e9}\n");
+        assert_eq!(Latin1::parse_str::<Demo>("id=2\nname=x\n").unwrap().id, 2);
+    }
+
+    #[test]
+    fn streamed_load_error_still_shows_the_offending_line() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("broken.json");
+        fs::write(&path, "{\n  \"id\": 1,\n  \"name\": oops\n}").unwrap();
+
+        let Err(CodecError::Deserialize(err)) = Json::load::<Demo>(&path) else {
+            panic!("expected deserialize error")
+        };
+        let diag = err.diagnostic();
+        assert_eq!(diag.line, Some(3));
+        assert_eq!(diag.snippet.as_deref(), Some("  \"name\": oops"));
+        assert_eq!(diag.path.as_deref(), Some(path.as_path()));
+    }
+
+    #[test]
+    fn load_missing_file_is_a_read_error() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("missing.yaml");
+
+        let Err(CodecError::Io(err)) = Yaml::load::<Demo>(&path) else {
+            panic!("expected io error")
+        };
+        let fs_err = FsError::downcast_ref(&err).expect("FsError");
+        assert_eq!(fs_err.operation(), Operation::Read);
+        assert_eq!(fs_err.kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn failed_save_keeps_the_serialize_error_and_the_old_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.json");
+        fs::write(&path, "old").unwrap();
+
+        // JSON maps need string keys.
+        let bad: BTreeMap<(u8, u8), u8> = [((1, 2), 3)].into();
+        let err = Json::save(&path, &bad).unwrap_err();
+
+        assert!(matches!(err, CodecError::Serialize(SerializeError::Json(_))), "{err:?}");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old");
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn reader_and_writer_roundtrip() {
+        let v = Demo { id: 3, name: "gamma".into() };
+        let mut buf = Vec::new();
+
+        Yaml::to_writer(&mut buf, &v).unwrap();
+        assert_eq!(buf, Yaml::to_string(&v).unwrap().into_bytes());
+        assert_eq!(Yaml::from_reader::<_, Demo>(buf.as_slice()).unwrap(), v);
     }
 }
@@ -1,31 +1,25 @@
-use std::{fmt::Display, io, path::Path};
+use {
+    crate::{FsError, Operation},
+    std::{io, path::Path},
+};
 
 pub trait IoResultExt<T> {
-    fn with_path_context(self, action: impl Display, path: impl AsRef<Path>) -> io::Result<T>;
+    fn with_path_context(self, operation: Operation, path: impl AsRef<Path>) -> io::Result<T>;
     fn with_paths_context(
-        self, action: impl Display, src: impl AsRef<Path>, dst: impl AsRef<Path>,
+        self, operation: Operation, src: impl AsRef<Path>, dst: impl AsRef<Path>,
     ) -> io::Result<T>;
 }
 
 impl<T> IoResultExt<T> for io::Result<T> {
-    fn with_path_context(self, action: impl Display, path: impl AsRef<Path>) -> io::Result<T> {
-        self.map_err(|e| {
-            io::Error::new(e.kind(), format!("{action} '{}': {e}", path.as_ref().display()))
-        })
+    fn with_path_context(self, operation: Operation, path: impl AsRef<Path>) -> io::Result<T> {
+        self.map_err(|e| FsError::new(operation, path.as_ref(), e).into())
     }
 
     fn with_paths_context(
-        self, action: impl Display, src: impl AsRef<Path>, dst: impl AsRef<Path>,
+        self, operation: Operation, src: impl AsRef<Path>, dst: impl AsRef<Path>,
     ) -> io::Result<T> {
         self.map_err(|e| {
-            io::Error::new(
-                e.kind(),
-                format!(
-                    "{action} '{}' -> '{}': {e}",
-                    src.as_ref().display(),
-                    dst.as_ref().display()
-                ),
-            )
+            FsError::new(operation, src.as_ref(), e).with_other_path(dst.as_ref()).into()
         })
     }
 }
@@ -1,5 +1,11 @@
+mod binary_format;
+mod file_system;
 mod format;
 mod io_result_ext;
 mod path_ext;
+mod versioned;
 
-pub use {format::Format, io_result_ext::IoResultExt, path_ext::PathExt};
+pub use {
+    binary_format::BinaryFormat, file_system::FileSystem, format::Format,
+    io_result_ext::IoResultExt, path_ext::PathExt, versioned::Versioned,
+};
@@ -1,8 +1,10 @@
 use {
-    crate::{IoResultExt, PathKind},
+    crate::{IoResultExt, Operation, PathKind},
     std::{
+        env,
+        ffi::OsStr,
         fs, io,
-        path::{Path, PathBuf},
+        path::{Component, MAIN_SEPARATOR, Path, PathBuf},
     },
 };
 
@@ -15,11 +17,18 @@ pub trait PathExt {
     fn parent_or_current(&self) -> PathBuf;
     fn utf8_stem(&self) -> io::Result<&str>;
     fn utf8_extension(&self) -> io::Result<Option<&str>>;
+    fn normalize(&self) -> PathBuf;
+    fn relative_to(&self, base: impl AsRef<Path>) -> io::Result<PathBuf>;
+    fn is_within(&self, base: impl AsRef<Path>) -> bool;
+    fn with_multi_extension(&self, extension: &str) -> PathBuf;
+    fn strip_all_extensions(&self) -> PathBuf;
+    fn expand_home(&self) -> io::Result<PathBuf>;
+    fn to_slash(&self) -> io::Result<String>;
 }
 
 impl PathExt for Path {
     fn is_dir_strict(&self) -> io::Result<bool> {
-        let meta = fs::metadata(self).with_path_context("could not read metadata", self)?;
+        let meta = fs::metadata(self).with_path_context(Operation::Metadata, self)?;
         Ok(meta.is_dir())
     }
 
@@ -35,7 +44,7 @@ impl PathExt for Path {
     }
 
     fn is_file_strict(&self) -> io::Result<bool> {
-        let meta = fs::metadata(self).with_path_context("could not read metadata", self)?;
+        let meta = fs::metadata(self).with_path_context(Operation::Metadata, self)?;
         Ok(meta.is_file())
     }
 
@@ -86,6 +95,144 @@ impl PathExt for Path {
             }),
         }
     }
+
+    // Purely lexical: symlinks are not resolved, so `link/..` may name a
+    // different directory on disk than the result.
+    fn normalize(&self) -> PathBuf {
+        let normalized = lexical(self);
+        if normalized.as_os_str().is_empty() { PathBuf::from(".") } else { normalized }
+    }
+
+    fn relative_to(&self, base: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let base = base.as_ref();
+        let (path, base_norm) = (lexical(self), lexical(base));
+        let unrelated = || {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "'{}' cannot be expressed relative to '{}'",
+                    self.display(),
+                    base.display()
+                ),
+            )
+        };
+
+        let mut path_parts = path.components().peekable();
+        let mut base_parts = base_norm.components().peekable();
+        if path.has_root() != base_norm.has_root() {
+            return Err(unrelated());
+        }
+        while let (Some(a), Some(b)) = (path_parts.peek(), base_parts.peek()) {
+            if a != b {
+                break;
+            }
+            path_parts.next();
+            base_parts.next();
+        }
+
+        let mut relative = PathBuf::new();
+        for part in base_parts {
+            match part {
+                Component::Normal(_) => relative.push(".."),
+                // A `..` or prefix left in the base names a directory we
+                // cannot climb back down from without touching the disk.
+                _ => return Err(unrelated()),
+            }
+        }
+        relative.extend(path_parts);
+        Ok(if relative.as_os_str().is_empty() { PathBuf::from(".") } else { relative })
+    }
+
+    // Lexical like `normalize`; use `Root` where symlinks must be accounted for.
+    fn is_within(&self, base: impl AsRef<Path>) -> bool {
+        let (path, base) = (lexical(self), lexical(base.as_ref()));
+        path.has_root() == base.has_root()
+            && path
+                .strip_prefix(&base)
+                .is_ok_and(|rest| rest.components().next() != Some(Component::ParentDir))
+    }
+
+    fn with_multi_extension(&self, extension: &str) -> PathBuf {
+        let stripped = self.strip_all_extensions();
+        let extension = extension.trim_start_matches('.');
+        if extension.is_empty() || stripped.file_name().is_none() {
+            return stripped;
+        }
+        let mut name = stripped.file_name().unwrap_or_default().to_os_string();
+        name.push(".");
+        name.push(extension);
+        stripped.with_file_name(name)
+    }
+
+    // Everything from the first dot after the leading one is an extension, so
+    // `archive.tar.gz` becomes `archive` and `.config.json` becomes `.config`.
+    fn strip_all_extensions(&self) -> PathBuf {
+        let Some(name) = self.file_name() else {
+            return self.to_path_buf();
+        };
+        let bytes = name.as_encoded_bytes();
+        let leading = bytes.iter().take_while(|&&b| b == b'.').count();
+        match bytes[leading..].iter().position(|&b| b == b'.') {
+            Some(dot) => {
+                // SAFETY: the split is right before an ASCII `.`, which is a
+                // valid boundary in the OS string encoding.
+                let stem = unsafe { OsStr::from_encoded_bytes_unchecked(&bytes[..leading + dot]) };
+                self.with_file_name(stem)
+            }
+            None => self.to_path_buf(),
+        }
+    }
+
+    // Only a bare `~` or a leading `~/` is expanded; `~user` is left alone.
+    fn expand_home(&self) -> io::Result<PathBuf> {
+        let mut components = self.components();
+        if components.next() != Some(Component::Normal(OsStr::new("~"))) {
+            return Ok(self.to_path_buf());
+        }
+        let home = home_dir().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("cannot expand '{}': home directory is not set", self.display()),
+            )
+        })?;
+        Ok(home.join(components.as_path()))
+    }
+
+    fn to_slash(&self) -> io::Result<String> {
+        let s = self.to_str().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("non-UTF-8 path '{}'", self.display()),
+            )
+        })?;
+        Ok(if MAIN_SEPARATOR == '/' { s.to_owned() } else { s.replace(MAIN_SEPARATOR, "/") })
+    }
+}
+
+// `normalize` without the `.` for an empty result, so callers can compare
+// component lists directly. Leading `..` is kept on relative paths and dropped
+// after a root, where it has nowhere to go.
+fn lexical(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match out.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    out.pop();
+                }
+                Some(Component::RootDir | Component::Prefix(_)) => {}
+                _ => out.push(".."),
+            },
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+fn home_dir() -> Option<PathBuf> {
+    let var = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+    env::var_os(var).filter(|home| !home.is_empty()).map(PathBuf::from)
 }
 
 #[cfg(test)]
@@ -223,4 +370,217 @@ mod tests {
         assert_eq!(p.utf8_extension()?, Some("txt"));
         Ok(())
     }
+
+    // ---------- normalize ----------
+
+    #[test]
+    fn normalize_resolves_dots_lexically() {
+        assert_eq!(Path::new("a/./b/../c").normalize(), PathBuf::from("a/c"));
+        assert_eq!(Path::new("a/b/../../c/").normalize(), PathBuf::from("c"));
+        assert_eq!(Path::new("./a").normalize(), PathBuf::from("a"));
+    }
+
+    #[test]
+    fn normalize_empty_results_become_current_dir() {
+        assert_eq!(Path::new("").normalize(), PathBuf::from("."));
+        assert_eq!(Path::new(".").normalize(), PathBuf::from("."));
+        assert_eq!(Path::new("a/..").normalize(), PathBuf::from("."));
+    }
+
+    #[test]
+    fn normalize_keeps_leading_parent_dirs_on_relative_paths() {
+        assert_eq!(Path::new("../a").normalize(), PathBuf::from("../a"));
+        assert_eq!(Path::new("a/../../b").normalize(), PathBuf::from("../b"));
+        assert_eq!(Path::new("../../").normalize(), PathBuf::from("../.."));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn normalize_cannot_climb_above_root() {
+        assert_eq!(Path::new("/../a").normalize(), PathBuf::from("/a"));
+        assert_eq!(Path::new("/a/../..").normalize(), PathBuf::from("/"));
+        assert_eq!(Path::new("//a//b/").normalize(), PathBuf::from("/a/b"));
+    }
+
+    // ---------- relative_to ----------
+
+    #[test]
+    fn relative_to_descendant_and_sibling() -> io::Result<()> {
+        assert_eq!(Path::new("a/b/c").relative_to("a")?, PathBuf::from("b/c"));
+        assert_eq!(Path::new("a/x").relative_to("a/b/c")?, PathBuf::from("../../x"));
+        assert_eq!(Path::new("x").relative_to("a")?, PathBuf::from("../x"));
+        Ok(())
+    }
+
+    #[test]
+    fn relative_to_same_path_is_current_dir() -> io::Result<()> {
+        assert_eq!(Path::new("a/b").relative_to("a/./b/")?, PathBuf::from("."));
+        assert_eq!(Path::new("").relative_to(".")?, PathBuf::from("."));
+        Ok(())
+    }
+
+    #[test]
+    fn relative_to_from_current_dir_and_with_parent_dirs() -> io::Result<()> {
+        assert_eq!(Path::new("a/b").relative_to(".")?, PathBuf::from("a/b"));
+        assert_eq!(Path::new("../x").relative_to("../y")?, PathBuf::from("../x"));
+        assert_eq!(Path::new("../x").relative_to("")?, PathBuf::from("../x"));
+        Ok(())
+    }
+
+    #[test]
+    fn relative_to_rejects_bases_it_cannot_climb_out_of() {
+        let err = Path::new("a").relative_to("..").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(err.to_string().contains("'a'"), "{err}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn relative_to_absolute_paths() -> io::Result<()> {
+        assert_eq!(
+            Path::new("/srv/app/log").relative_to("/srv/data")?,
+            PathBuf::from("../app/log")
+        );
+        assert_eq!(Path::new("/a").relative_to("/")?, PathBuf::from("a"));
+        let err = Path::new("/a").relative_to("a").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        Ok(())
+    }
+
+    // ---------- is_within ----------
+
+    #[test]
+    fn is_within_descendants_and_self() {
+        assert!(Path::new("a/b").is_within("a"));
+        assert!(Path::new("a").is_within("a/"));
+        assert!(Path::new("a/b").is_within("."));
+        assert!(Path::new("a/b/../c").is_within("a"));
+    }
+
+    #[test]
+    fn is_within_compares_components_not_strings() {
+        assert!(!Path::new("ab").is_within("a"));
+        assert!(!Path::new("a-b/c").is_within("a"));
+    }
+
+    #[test]
+    fn is_within_rejects_lexical_escapes() {
+        assert!(!Path::new("a/../../b").is_within("."));
+        assert!(!Path::new("a/../b").is_within("a"));
+        assert!(!Path::new("..").is_within(""));
+        assert!(Path::new("../x/y").is_within("../x"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn is_within_absolute_and_relative_never_mix() {
+        assert!(Path::new("/srv/a").is_within("/srv"));
+        assert!(Path::new("/srv/a").is_within("/"));
+        assert!(!Path::new("/srv/a").is_within("srv"));
+        assert!(!Path::new("srv/a").is_within("/srv"));
+        assert!(!Path::new("/srv/../etc").is_within("/srv"));
+    }
+
+    // ---------- strip_all_extensions ----------
+
+    #[test]
+    fn strip_all_extensions_multi_and_single() {
+        assert_eq!(Path::new("archive.tar.gz").strip_all_extensions(), PathBuf::from("archive"));
+        assert_eq!(Path::new("dir/file.txt").strip_all_extensions(), PathBuf::from("dir/file"));
+        assert_eq!(Path::new("README").strip_all_extensions(), PathBuf::from("README"));
+    }
+
+    #[test]
+    fn strip_all_extensions_dotfiles_keep_leading_dot() {
+        assert_eq!(Path::new(".bashrc").strip_all_extensions(), PathBuf::from(".bashrc"));
+        assert_eq!(Path::new(".config.json").strip_all_extensions(), PathBuf::from(".config"));
+        assert_eq!(Path::new("..hidden.txt").strip_all_extensions(), PathBuf::from("..hidden"));
+    }
+
+    #[test]
+    fn strip_all_extensions_trailing_dot_and_dotted_dirs() {
+        assert_eq!(Path::new("name.").strip_all_extensions(), PathBuf::from("name"));
+        assert_eq!(Path::new("v1.2/file").strip_all_extensions(), PathBuf::from("v1.2/file"));
+    }
+
+    #[test]
+    fn strip_all_extensions_without_file_name_is_unchanged() {
+        assert_eq!(Path::new("..").strip_all_extensions(), PathBuf::from(".."));
+        assert_eq!(Path::new("").strip_all_extensions(), PathBuf::from(""));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn strip_all_extensions_non_utf8_name() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        let p = Path::new(OsStr::from_bytes(b"fo\x80o.tar.gz"));
+        assert_eq!(p.strip_all_extensions(), Path::new(OsStr::from_bytes(b"fo\x80o")));
+    }
+
+    // ---------- with_multi_extension ----------
+
+    #[test]
+    fn with_multi_extension_replaces_every_extension() {
+        assert_eq!(Path::new("a.tar.gz").with_multi_extension("zip"), PathBuf::from("a.zip"));
+        assert_eq!(Path::new("a.txt").with_multi_extension("tar.gz"), PathBuf::from("a.tar.gz"));
+        assert_eq!(Path::new("d/a").with_multi_extension(".tar.zst"), PathBuf::from("d/a.tar.zst"));
+    }
+
+    #[test]
+    fn with_multi_extension_empty_strips() {
+        assert_eq!(Path::new("a.tar.gz").with_multi_extension(""), PathBuf::from("a"));
+        assert_eq!(Path::new("a.tar.gz").with_multi_extension("."), PathBuf::from("a"));
+    }
+
+    #[test]
+    fn with_multi_extension_dotfiles_and_missing_names() {
+        assert_eq!(Path::new(".env").with_multi_extension("bak"), PathBuf::from(".env.bak"));
+        assert_eq!(Path::new("..").with_multi_extension("txt"), PathBuf::from(".."));
+    }
+
+    // ---------- expand_home ----------
+
+    #[test]
+    fn expand_home_leaves_other_paths_alone() -> io::Result<()> {
+        assert_eq!(Path::new("a/~/b").expand_home()?, PathBuf::from("a/~/b"));
+        assert_eq!(Path::new("~user/x").expand_home()?, PathBuf::from("~user/x"));
+        assert_eq!(Path::new("~~").expand_home()?, PathBuf::from("~~"));
+        Ok(())
+    }
+
+    #[test]
+    fn expand_home_replaces_leading_tilde() -> io::Result<()> {
+        let Some(home) = super::home_dir() else { return Ok(()) };
+        assert_eq!(Path::new("~").expand_home()?, home);
+        assert_eq!(Path::new("~/.config/app").expand_home()?, home.join(".config/app"));
+        Ok(())
+    }
+
+    // ---------- to_slash ----------
+
+    #[test]
+    fn to_slash_uses_forward_slashes() -> io::Result<()> {
+        assert_eq!(Path::new("a/b/c.txt").to_slash()?, "a/b/c.txt");
+        assert_eq!(Path::new("a").join("b").to_slash()?, "a/b");
+        assert_eq!(Path::new("").to_slash()?, "");
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn to_slash_converts_backslashes() -> io::Result<()> {
+        assert_eq!(Path::new(r"dir\sub\f.txt").to_slash()?, "dir/sub/f.txt");
+        assert_eq!(Path::new(r"C:\data").to_slash()?, "C:/data");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn to_slash_rejects_non_utf8() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        let err = Path::new(OsStr::from_bytes(b"a/\xff")).to_slash().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
 }
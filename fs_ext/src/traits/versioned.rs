@@ -0,0 +1,15 @@
+use {
+    crate::Migrations,
+    serde::{Serialize, de::DeserializeOwned},
+};
+
+// `VERSION` is written next to the model's own fields under `VERSION_KEY`;
+// files without the key are treated as version 0.
+pub trait Versioned: Serialize + DeserializeOwned {
+    const VERSION: u32;
+    const VERSION_KEY: &'static str = "version";
+
+    fn migrations() -> Migrations {
+        Migrations::new()
+    }
+}
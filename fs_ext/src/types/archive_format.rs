@@ -0,0 +1,62 @@
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ArchiveFormat {
+    Tar,
+    TarGz,
+    Zip,
+}
+
+impl ArchiveFormat {
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let name = path.as_ref().file_name()?.to_str()?.to_ascii_lowercase();
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveFormat::TarGz)
+        } else if name.ends_with(".tar") {
+            Some(ArchiveFormat::Tar)
+        } else if name.ends_with(".zip") {
+            Some(ArchiveFormat::Zip)
+        } else {
+            None
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ArchiveFormat::Tar => "tar",
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::Zip => "zip",
+        }
+    }
+
+    pub fn is_enabled(self) -> bool {
+        match self {
+            ArchiveFormat::Tar | ArchiveFormat::TarGz => cfg!(feature = "tar"),
+            ArchiveFormat::Zip => cfg!(feature = "zip"),
+        }
+    }
+
+    #[cfg(any(feature = "tar", feature = "zip"))]
+    pub(crate) fn unsupported(self) -> std::io::Error {
+        let feature = if self == ArchiveFormat::Zip { "zip" } else { "tar" };
+        std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            format!("{} archives require the `{feature}` feature", self.extension()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_by_file_name() {
+        assert_eq!(ArchiveFormat::from_path("out/snap.tar"), Some(ArchiveFormat::Tar));
+        assert_eq!(ArchiveFormat::from_path("snap.TAR.GZ"), Some(ArchiveFormat::TarGz));
+        assert_eq!(ArchiveFormat::from_path("snap.tgz"), Some(ArchiveFormat::TarGz));
+        assert_eq!(ArchiveFormat::from_path("artifact.zip"), Some(ArchiveFormat::Zip));
+        assert_eq!(ArchiveFormat::from_path("snap.gz"), None);
+        assert_eq!(ArchiveFormat::from_path("tar"), None);
+    }
+}
@@ -0,0 +1,72 @@
+use {crate::ExtensionFilter, std::time::Duration};
+
+#[derive(Debug, Clone, Default)]
+pub struct ClearOptions {
+    pub extension_filter: Option<ExtensionFilter>,
+    pub globs: Vec<String>,
+    pub older_than: Option<Duration>,
+    pub keep: Vec<String>,
+    pub recursive: bool,
+    pub dry_run: bool,
+    pub trash: bool,
+}
+
+impl AsRef<ClearOptions> for ClearOptions {
+    fn as_ref(&self) -> &ClearOptions {
+        self
+    }
+}
+
+impl ClearOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn allow_extensions<I, S>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.extension_filter = Some(ExtensionFilter::allow(extensions));
+        self
+    }
+
+    pub fn deny_extensions<I, S>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.extension_filter = Some(ExtensionFilter::deny(extensions));
+        self
+    }
+
+    pub fn glob(mut self, pattern: impl Into<String>) -> Self {
+        self.globs.push(pattern.into());
+        self
+    }
+
+    pub fn older_than(mut self, age: Duration) -> Self {
+        self.older_than = Some(age);
+        self
+    }
+
+    pub fn keep(mut self, pattern: impl Into<String>) -> Self {
+        self.keep.push(pattern.into());
+        self
+    }
+
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn trash(mut self, trash: bool) -> Self {
+        self.trash = trash;
+        self
+    }
+}
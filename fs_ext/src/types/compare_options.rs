@@ -0,0 +1,36 @@
+use {
+    crate::{DirQueryOptions, SyncCompare},
+    std::path::PathBuf,
+};
+
+#[derive(Clone, Debug)]
+pub struct CompareOptions {
+    pub query: DirQueryOptions,
+    pub compare: SyncCompare,
+}
+
+// Comparing against goldens cares about bytes, not timestamps.
+impl Default for CompareOptions {
+    fn default() -> Self {
+        Self { query: DirQueryOptions::default(), compare: SyncCompare::Content }
+    }
+}
+
+impl AsRef<CompareOptions> for CompareOptions {
+    fn as_ref(&self) -> &CompareOptions {
+        self
+    }
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct DirDiff {
+    pub only_in_a: Vec<PathBuf>,
+    pub only_in_b: Vec<PathBuf>,
+    pub differing: Vec<PathBuf>,
+}
+
+impl DirDiff {
+    pub fn is_empty(&self) -> bool {
+        self.only_in_a.is_empty() && self.only_in_b.is_empty() && self.differing.is_empty()
+    }
+}
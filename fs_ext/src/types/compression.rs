@@ -0,0 +1,67 @@
+use std::path::Path;
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Compression {
+    #[default]
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        let ext = path.as_ref().extension().and_then(|e| e.to_str()).map(str::to_ascii_lowercase);
+        match ext.as_deref() {
+            Some("gz" | "gzip") => Compression::Gzip,
+            Some("zst" | "zstd") => Compression::Zstd,
+            _ => Compression::None,
+        }
+    }
+
+    pub fn from_magic(bytes: &[u8]) -> Self {
+        if bytes.starts_with(&[0x1f, 0x8b]) {
+            Compression::Gzip
+        } else if bytes.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            Compression::Zstd
+        } else {
+            Compression::None
+        }
+    }
+
+    pub fn extension(self) -> Option<&'static str> {
+        match self {
+            Compression::None => None,
+            Compression::Gzip => Some("gz"),
+            Compression::Zstd => Some("zst"),
+        }
+    }
+
+    pub fn is_enabled(self) -> bool {
+        match self {
+            Compression::None => true,
+            Compression::Gzip => cfg!(feature = "gzip"),
+            Compression::Zstd => cfg!(feature = "zstd"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_by_extension() {
+        assert_eq!(Compression::from_path("logs/app.log.gz"), Compression::Gzip);
+        assert_eq!(Compression::from_path("cache.json.ZST"), Compression::Zstd);
+        assert_eq!(Compression::from_path("data.json"), Compression::None);
+        assert_eq!(Compression::from_path("gz"), Compression::None);
+    }
+
+    #[test]
+    fn detects_by_magic_bytes() {
+        assert_eq!(Compression::from_magic(&[0x1f, 0x8b, 0x08]), Compression::Gzip);
+        assert_eq!(Compression::from_magic(&[0x28, 0xb5, 0x2f, 0xfd, 0]), Compression::Zstd);
+        assert_eq!(Compression::from_magic(b"{}"), Compression::None);
+        assert_eq!(Compression::from_magic(&[0x1f]), Compression::None);
+    }
+}
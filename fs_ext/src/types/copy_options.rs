@@ -0,0 +1,25 @@
+#[derive(Clone, Debug, PartialEq, Copy, Eq)]
+pub struct CopyOptions {
+    pub reflink: Reflink,
+    pub sparse: bool,
+}
+
+impl Default for CopyOptions {
+    fn default() -> Self {
+        Self { reflink: Reflink::Auto, sparse: true }
+    }
+}
+
+impl AsRef<CopyOptions> for CopyOptions {
+    fn as_ref(&self) -> &CopyOptions {
+        self
+    }
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Copy, Eq)]
+pub enum Reflink {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct CopyProgress {
+    pub files_copied: u64,
+    pub files_skipped: u64,
+    pub files_total: u64,
+    pub bytes_copied: u64,
+    pub bytes_skipped: u64,
+    pub bytes_total: u64,
+    pub current: PathBuf,
+}
+
+impl CopyProgress {
+    pub fn files_done(&self) -> u64 {
+        self.files_copied + self.files_skipped
+    }
+
+    pub fn bytes_done(&self) -> u64 {
+        self.bytes_copied + self.bytes_skipped
+    }
+}
@@ -0,0 +1,36 @@
+use {crate::Digest, std::path::PathBuf};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateGroup {
+    pub size: u64,
+    pub digest: Digest,
+    pub files: Vec<PathBuf>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Copy, Eq)]
+pub struct DedupeOptions {
+    pub action: DedupeAction,
+    pub dry_run: bool,
+}
+
+impl AsRef<DedupeOptions> for DedupeOptions {
+    fn as_ref(&self) -> &DedupeOptions {
+        self
+    }
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Copy, Eq)]
+pub enum DedupeAction {
+    #[default]
+    HardLink,
+    Reflink,
+    Trash,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct DedupeReport {
+    pub kept: Vec<PathBuf>,
+    pub replaced: Vec<PathBuf>,
+    pub skipped: Vec<PathBuf>,
+    pub reclaimed_bytes: u64,
+}
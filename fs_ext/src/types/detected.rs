@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Detected<T> {
+    pub value: T,
+    pub format: &'static str,
+    pub by: DetectedBy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectedBy {
+    Extension,
+    Content,
+}
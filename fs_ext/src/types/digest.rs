@@ -0,0 +1,60 @@
+use {
+    crate::HashAlgorithm,
+    std::{fmt, io},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Digest {
+    pub algorithm: HashAlgorithm,
+    pub bytes: Vec<u8>,
+}
+
+impl Digest {
+    pub fn from_hex(algorithm: HashAlgorithm, hex: &str) -> io::Result<Self> {
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("'{hex}' is not a valid {} digest", algorithm.name()),
+            )
+        };
+        if hex.len() != algorithm.digest_len() * 2 {
+            return Err(invalid());
+        }
+
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| hex.get(i..i + 2).and_then(|b| u8::from_str_radix(b, 16).ok()))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(invalid)?;
+        Ok(Digest { algorithm, bytes })
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
+
+impl fmt::Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_roundtrip() {
+        let digest = Digest::from_hex(HashAlgorithm::Xxh3, "00ff10AB7f000001").unwrap();
+        assert_eq!(digest.bytes, [0x00, 0xff, 0x10, 0xab, 0x7f, 0x00, 0x00, 0x01]);
+        assert_eq!(digest.to_string(), "00ff10ab7f000001");
+    }
+
+    #[test]
+    fn rejects_bad_hex() {
+        assert!(Digest::from_hex(HashAlgorithm::Xxh3, "00ff").is_err());
+        assert!(Digest::from_hex(HashAlgorithm::Xxh3, "zzff10ab7f000001").is_err());
+        assert!(Digest::from_hex(HashAlgorithm::Sha256, "00ff10ab7f000001").is_err());
+    }
+}
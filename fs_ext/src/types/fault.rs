@@ -0,0 +1,84 @@
+use {
+    crate::Operation,
+    std::{
+        io,
+        path::{Path, PathBuf},
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fault {
+    pub operation: Operation,
+    pub path: Option<PathBuf>,
+    pub kind: io::ErrorKind,
+    pub raw_os_error: Option<i32>,
+    pub skip: usize,
+    pub times: Option<usize>,
+    pub crash: bool,
+}
+
+impl Fault {
+    pub fn new(operation: Operation, kind: io::ErrorKind) -> Self {
+        Self { operation, path: None, kind, raw_os_error: None, skip: 0, times: None, crash: false }
+    }
+
+    // Fails with the exact errno, e.g. `libc::ENOSPC`; the kind follows from it.
+    pub fn os_error(operation: Operation, code: i32) -> Self {
+        let kind = io::Error::from_raw_os_error(code).kind();
+        Self { raw_os_error: Some(code), ..Self::new(operation, kind) }
+    }
+
+    // Only calls on `path` or below it, compared as written.
+    pub fn at(mut self, path: impl AsRef<Path>) -> Self {
+        self.path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn skip(mut self, calls: usize) -> Self {
+        self.skip = calls;
+        self
+    }
+
+    pub fn times(mut self, calls: usize) -> Self {
+        self.times = Some(calls);
+        self
+    }
+
+    pub fn once(self) -> Self {
+        self.times(1)
+    }
+
+    // Once fired, every later call fails too, as if the process died there.
+    pub fn crash(mut self) -> Self {
+        self.crash = true;
+        self
+    }
+
+    // `Open` stands for any call that opens a file and `Persist` for renames,
+    // so faults can be phrased the way the atomic helpers use them.
+    pub(crate) fn covers(&self, operation: Operation) -> bool {
+        match self.operation {
+            Operation::Open => matches!(
+                operation,
+                Operation::Read
+                    | Operation::Write
+                    | Operation::Create
+                    | Operation::Sync
+                    | Operation::Open
+            ),
+            Operation::Persist => matches!(operation, Operation::Move | Operation::Persist),
+            expected => expected == operation,
+        }
+    }
+
+    pub(crate) fn matches_path(&self, path: &Path) -> bool {
+        self.path.as_deref().is_none_or(|prefix| path.starts_with(prefix))
+    }
+
+    pub(crate) fn error(&self) -> io::Error {
+        match self.raw_os_error {
+            Some(code) => io::Error::from_raw_os_error(code),
+            None => io::Error::new(self.kind, "injected fault"),
+        }
+    }
+}
@@ -0,0 +1,535 @@
+use {
+    crate::{
+        BinaryFormat, CodecError, DeserializeError, Diagnostic, Format, formats,
+        utils::{normalize_ext, short_type_name},
+    },
+    serde::{Serialize, de::DeserializeOwned},
+    std::{
+        any::TypeId,
+        fmt, io,
+        marker::PhantomData,
+        path::Path,
+        sync::{Arc, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard},
+    },
+};
+
+static GLOBAL: OnceLock<RwLock<FormatRegistry>> = OnceLock::new();
+
+#[derive(Clone)]
+pub struct FormatRegistry {
+    entries: Vec<(String, Handler)>,
+}
+
+impl FormatRegistry {
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry.register::<formats::Toml>(["toml"]);
+        registry.register::<formats::Json>(["json"]);
+        registry.register::<formats::Yaml>(["yaml", "yml"]);
+        #[cfg(feature = "ron")]
+        registry.register::<formats::Ron>(["ron"]);
+        #[cfg(feature = "json5")]
+        registry.register::<formats::Json5>(["json5", "jsonc"]);
+        #[cfg(feature = "ini")]
+        registry.register::<formats::Ini>(["ini"]);
+        #[cfg(feature = "dotenv")]
+        registry.register::<formats::Dotenv>(["env"]);
+        #[cfg(feature = "msgpack")]
+        registry.register_binary::<formats::MessagePack>(["msgpack", "mpk"]);
+        #[cfg(feature = "cbor")]
+        registry.register_binary::<formats::Cbor>(["cbor"]);
+        #[cfg(feature = "bincode")]
+        registry.register_binary::<formats::Bincode>(["bincode"]);
+        registry
+    }
+
+    pub fn empty() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    pub fn global() -> RwLockReadGuard<'static, FormatRegistry> {
+        global_lock().read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn global_mut() -> RwLockWriteGuard<'static, FormatRegistry> {
+        global_lock().write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn register_global<F>(extensions: impl IntoIterator<Item = impl AsRef<str>>)
+    where
+        F: Format + 'static,
+    {
+        Self::global_mut().register::<F>(extensions);
+    }
+
+    pub fn register_global_binary<F>(extensions: impl IntoIterator<Item = impl AsRef<str>>)
+    where
+        F: BinaryFormat + 'static,
+    {
+        Self::global_mut().register_binary::<F>(extensions);
+    }
+
+    pub fn register<F>(
+        &mut self, extensions: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Self
+    where
+        F: Format + 'static,
+    {
+        let handler = Handler::builtin(TypeId::of::<F>())
+            .unwrap_or_else(|| Handler::Custom(Arc::new(Erased::<F>(PhantomData))));
+        self.insert(handler, extensions)
+    }
+
+    pub fn register_binary<F>(
+        &mut self, extensions: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Self
+    where
+        F: BinaryFormat + 'static,
+    {
+        let handler = Handler::builtin(TypeId::of::<F>())
+            .unwrap_or_else(|| Handler::Custom(Arc::new(ErasedBinary::<F>(PhantomData))));
+        self.insert(handler, extensions)
+    }
+
+    fn insert(
+        &mut self, handler: Handler, extensions: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Self {
+        for ext in extensions {
+            let ext = normalize_ext(ext.as_ref());
+            if ext.is_empty() {
+                continue;
+            }
+            match self.entries.iter_mut().find(|(e, _)| *e == ext) {
+                Some((_, h)) => *h = handler.clone(),
+                None => self.entries.push((ext, handler.clone())),
+            }
+        }
+        self
+    }
+
+    pub fn unregister(&mut self, extension: impl AsRef<str>) -> bool {
+        let ext = normalize_ext(extension.as_ref());
+        let before = self.entries.len();
+        self.entries.retain(|(e, _)| *e != ext);
+        self.entries.len() != before
+    }
+
+    pub fn extensions(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(e, _)| e.as_str())
+    }
+
+    pub fn supports(&self, path: impl AsRef<Path>) -> bool {
+        self.lookup(path.as_ref()).is_some()
+    }
+
+    pub fn load<T>(&self, path: impl AsRef<Path>) -> Result<T, CodecError>
+    where
+        T: DeserializeOwned,
+    {
+        let path = path.as_ref();
+        self.resolve(path)?.load(path)
+    }
+
+    pub fn save<T>(&self, path: impl AsRef<Path>, model: &T) -> Result<(), CodecError>
+    where
+        T: Serialize,
+    {
+        let path = path.as_ref();
+        self.resolve(path)?.save(path, model)
+    }
+
+    pub(crate) fn resolve(&self, path: &Path) -> io::Result<Handler> {
+        if let Some(handler) = self.lookup(path) {
+            return Ok(handler.clone());
+        }
+
+        let Some(ext) = path.extension().and_then(|s| s.to_str()) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("path '{}' has no extension", path.display()),
+            ));
+        };
+
+        let expected = self.extensions().collect::<Vec<_>>().join(", ");
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "unsupported extension '{}' for '{}'; expected one of: {expected}",
+                ext.to_ascii_lowercase(),
+                path.display()
+            ),
+        ))
+    }
+
+    // Longest registered suffix wins, so `app.config.json` can be claimed by
+    // a `config.json` handler while `other.json` still falls back to `json`.
+    // Dotfiles such as `.env` match their own name.
+    fn lookup(&self, path: &Path) -> Option<&Handler> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+
+        self.entries
+            .iter()
+            .filter(|(ext, _)| {
+                name.len() > ext.len()
+                    && name.ends_with(ext.as_str())
+                    && name[..name.len() - ext.len()].ends_with('.')
+            })
+            .max_by_key(|(ext, _)| ext.len())
+            .map(|(_, handler)| handler)
+    }
+}
+
+impl Default for FormatRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for FormatRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.entries.iter().map(|(e, h)| (e, h.name()))).finish()
+    }
+}
+
+fn global_lock() -> &'static RwLock<FormatRegistry> {
+    GLOBAL.get_or_init(|| RwLock::new(FormatRegistry::new()))
+}
+
+macro_rules! builtin_handlers {
+    ($($(#[$cfg:meta])* $format:ident => $name:literal,)*) => {
+        #[derive(Clone)]
+        pub(crate) enum Handler {
+            $($(#[$cfg])* $format,)*
+            Custom(Arc<dyn ErasedFormat>),
+        }
+
+        impl Handler {
+            fn builtin(id: TypeId) -> Option<Self> {
+                $(
+                    $(#[$cfg])*
+                    if id == TypeId::of::<formats::$format>() {
+                        return Some(Handler::$format);
+                    }
+                )*
+                None
+            }
+
+            pub(crate) fn name(&self) -> &'static str {
+                match self {
+                    $($(#[$cfg])* Handler::$format => $name,)*
+                    Handler::Custom(f) => f.name(),
+                }
+            }
+
+            pub(crate) fn load<T>(&self, path: &Path) -> Result<T, CodecError>
+            where
+                T: DeserializeOwned,
+            {
+                match self {
+                    $($(#[$cfg])* Handler::$format => formats::$format::load(path),)*
+                    Handler::Custom(f) => load_erased(f.as_ref(), path),
+                }
+            }
+
+            pub(crate) fn save<T>(&self, path: &Path, model: &T) -> Result<(), CodecError>
+            where
+                T: Serialize,
+            {
+                match self {
+                    $($(#[$cfg])* Handler::$format => formats::$format::save(path, model),)*
+                    Handler::Custom(f) => f.save(path, model),
+                }
+            }
+
+            pub(crate) fn read<T>(&self, reader: &mut dyn io::Read) -> Result<T, CodecError>
+            where
+                T: DeserializeOwned,
+            {
+                match self {
+                    $($(#[$cfg])* Handler::$format => formats::$format::from_reader(reader),)*
+                    Handler::Custom(f) => from_erased(f.as_ref(), f.read(reader)?),
+                }
+            }
+
+            pub(crate) fn write<T>(&self, writer: &mut dyn io::Write, model: &T) -> Result<(), CodecError>
+            where
+                T: Serialize,
+            {
+                match self {
+                    $($(#[$cfg])* Handler::$format => formats::$format::to_writer(writer, model),)*
+                    Handler::Custom(f) => f.write(writer, model),
+                }
+            }
+        }
+    };
+}
+
+// Built-in formats dispatch statically so `T` is deserialized directly; this
+// also keeps non-self-describing formats like bincode usable by extension.
+builtin_handlers! {
+    Toml => "TOML",
+    Json => "JSON",
+    Yaml => "YAML",
+    #[cfg(feature = "ron")]
+    Ron => "RON",
+    #[cfg(feature = "json5")]
+    Json5 => "JSON5",
+    #[cfg(feature = "ini")]
+    Ini => "INI",
+    #[cfg(feature = "dotenv")]
+    Dotenv => "dotenv",
+    #[cfg(feature = "msgpack")]
+    MessagePack => "MessagePack",
+    #[cfg(feature = "cbor")]
+    Cbor => "CBOR",
+    #[cfg(feature = "bincode")]
+    Bincode => "bincode",
+}
+
+// Custom formats are type-erased, so they parse into a self-describing value
+// first and `T` is built from that.
+fn load_erased<T>(format: &dyn ErasedFormat, path: &Path) -> Result<T, CodecError>
+where
+    T: DeserializeOwned,
+{
+    from_erased(format, format.load(path)?).map_err(|e| match e {
+        CodecError::Deserialize(e) => e.with_path(path).into(),
+        other => other,
+    })
+}
+
+fn from_erased<T>(format: &dyn ErasedFormat, value: serde_json::Value) -> Result<T, CodecError>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_value(value).map_err(|e| {
+        let diagnostic = Diagnostic::new(format.name(), "", None, e.to_string());
+        DeserializeError::other(diagnostic, e).into()
+    })
+}
+
+pub(crate) trait ErasedFormat: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn load(&self, path: &Path) -> Result<serde_json::Value, CodecError>;
+    fn save(&self, path: &Path, model: &dyn erased_serde::Serialize) -> Result<(), CodecError>;
+    fn read(&self, reader: &mut dyn io::Read) -> Result<serde_json::Value, CodecError>;
+    fn write(
+        &self, writer: &mut dyn io::Write, model: &dyn erased_serde::Serialize,
+    ) -> Result<(), CodecError>;
+}
+
+struct Erased<F>(PhantomData<fn() -> F>);
+
+impl<F: Format + 'static> ErasedFormat for Erased<F> {
+    fn name(&self) -> &'static str {
+        short_type_name::<F>()
+    }
+
+    fn load(&self, path: &Path) -> Result<serde_json::Value, CodecError> {
+        F::load(path)
+    }
+
+    fn save(&self, path: &Path, model: &dyn erased_serde::Serialize) -> Result<(), CodecError> {
+        F::save(path, model)
+    }
+
+    fn read(&self, reader: &mut dyn io::Read) -> Result<serde_json::Value, CodecError> {
+        F::from_reader(reader)
+    }
+
+    fn write(
+        &self, writer: &mut dyn io::Write, model: &dyn erased_serde::Serialize,
+    ) -> Result<(), CodecError> {
+        F::to_writer(writer, model)
+    }
+}
+
+struct ErasedBinary<F>(PhantomData<fn() -> F>);
+
+impl<F: BinaryFormat + 'static> ErasedFormat for ErasedBinary<F> {
+    fn name(&self) -> &'static str {
+        short_type_name::<F>()
+    }
+
+    fn load(&self, path: &Path) -> Result<serde_json::Value, CodecError> {
+        F::load(path)
+    }
+
+    fn save(&self, path: &Path, model: &dyn erased_serde::Serialize) -> Result<(), CodecError> {
+        F::save(path, model)
+    }
+
+    fn read(&self, reader: &mut dyn io::Read) -> Result<serde_json::Value, CodecError> {
+        F::from_reader(reader)
+    }
+
+    fn write(
+        &self, writer: &mut dyn io::Write, model: &dyn erased_serde::Serialize,
+    ) -> Result<(), CodecError> {
+        F::to_writer(writer, model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{SerializeError, file, formats::Json},
+        serde::Deserialize,
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+    }
+
+    // Line-based `key=value` format used to exercise custom registration.
+    enum KeyValue {}
+    impl Format for KeyValue {
+        fn parse_str<T>(s: &str) -> Result<T, DeserializeError>
+        where
+            T: DeserializeOwned,
+        {
+            let mut map = serde_json::Map::new();
+            for (i, line) in s.lines().enumerate() {
+                let Some((k, v)) = line.split_once('=') else {
+                    let diagnostic =
+                        Diagnostic::new("KeyValue", s, Some((i + 1, 1)), "expected '='");
+                    return Err(DeserializeError::other(diagnostic, "missing separator"));
+                };
+                let v = v.parse::<u64>().map(Into::into).unwrap_or_else(|_| v.into());
+                map.insert(k.to_owned(), v);
+            }
+            serde_json::from_value(map.into()).map_err(|e| {
+                DeserializeError::other(Diagnostic::new("KeyValue", s, None, e.to_string()), e)
+            })
+        }
+
+        fn to_string<T>(value: T) -> Result<String, SerializeError>
+        where
+            T: Serialize,
+        {
+            let value = serde_json::to_value(value).map_err(SerializeError::Json)?;
+            let serde_json::Value::Object(map) = value else {
+                return Err(SerializeError::other("KeyValue", "expected a map"));
+            };
+            Ok(map
+                .iter()
+                .map(|(k, v)| match v {
+                    serde_json::Value::String(s) => format!("{k}={s}\n"),
+                    other => format!("{k}={other}\n"),
+                })
+                .collect())
+        }
+    }
+
+    #[test]
+    fn default_registry_knows_builtins() {
+        let registry = FormatRegistry::new();
+        let extensions = registry.extensions().collect::<Vec<_>>();
+        assert_eq!(extensions[..4], ["toml", "json", "yaml", "yml"]);
+        assert!(registry.supports("a.JSON"));
+        assert!(!registry.supports("a.unknown"));
+        assert!(!FormatRegistry::empty().supports("a.json"));
+    }
+
+    #[test]
+    fn custom_format_roundtrips() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("demo.kv");
+        let mut registry = FormatRegistry::new();
+        registry.register::<KeyValue>([".KV"]);
+
+        let v = Demo { id: 1, name: "alpha".into() };
+        registry.save(&p, &v).unwrap();
+        assert_eq!(fs::read_to_string(&p).unwrap(), "id=1\nname=alpha\n");
+        assert_eq!(registry.load::<Demo>(&p).unwrap(), v);
+    }
+
+    #[test]
+    fn custom_format_errors_carry_path() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("bad.kv");
+        fs::write(&p, "id=1\noops\n").unwrap();
+        let mut registry = FormatRegistry::empty();
+        registry.register::<KeyValue>(["kv"]);
+
+        let Err(CodecError::Deserialize(e)) = registry.load::<Demo>(&p) else {
+            panic!("expected a deserialize error");
+        };
+        let d = e.diagnostic();
+        assert_eq!((d.format, d.line, d.path.as_deref()), ("KeyValue", Some(2), Some(p.as_path())));
+    }
+
+    #[test]
+    fn longest_multi_part_extension_wins() {
+        let dir = tempdir().unwrap();
+        let mut registry = FormatRegistry::new();
+        registry.register::<KeyValue>(["config.json"]);
+
+        let kv = dir.path().join("app.config.json");
+        let json = dir.path().join("app.json");
+        let v = Demo { id: 2, name: "beta".into() };
+        registry.save(&kv, &v).unwrap();
+        registry.save(&json, &v).unwrap();
+
+        assert_eq!(fs::read_to_string(&kv).unwrap(), "id=2\nname=beta\n");
+        assert_eq!(file::load::<Demo, Json>(&json).unwrap(), v);
+
+        // A bare `config.json` has no `config` part, so it stays plain JSON.
+        let bare = dir.path().join("config.json");
+        registry.save(&bare, &v).unwrap();
+        assert_eq!(file::load::<Demo, Json>(&bare).unwrap(), v);
+    }
+
+    #[test]
+    fn later_registration_replaces_and_unregister_removes() {
+        let mut registry = FormatRegistry::new();
+        registry.register::<KeyValue>(["json"]);
+        let debug = format!("{registry:?}");
+        assert!(
+            debug.starts_with(r#"{"toml": "TOML", "json": "KeyValue", "yaml": "YAML""#),
+            "{debug}"
+        );
+
+        assert!(registry.unregister(".yml"));
+        assert!(!registry.unregister("yml"));
+        assert!(!registry.supports("a.yml"));
+    }
+
+    #[cfg(feature = "dotenv")]
+    #[test]
+    fn dotfile_matches_its_own_name() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join(".env");
+        fs::write(&p, "id=3\nname=gamma\n").unwrap();
+
+        let got: Demo = FormatRegistry::new().load(&p).unwrap();
+        assert_eq!(got, Demo { id: 3, name: "gamma".into() });
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn builtin_binary_format_dispatches_by_extension() {
+        let dir = tempdir().unwrap();
+        let p = dir.path().join("demo.bincode");
+        let v = Demo { id: 4, name: "delta".into() };
+
+        FormatRegistry::new().save(&p, &v).unwrap();
+        assert_eq!(FormatRegistry::new().load::<Demo>(&p).unwrap(), v);
+    }
+
+    #[test]
+    fn unsupported_extension_lists_registered_ones() {
+        let err = FormatRegistry::new().load::<Demo>("a.unknown").unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("unsupported extension 'unknown'"), "{msg}");
+        assert!(msg.contains("expected one of: toml, json, yaml, yml"), "{msg}");
+
+        let err = FormatRegistry::new().load::<Demo>("noext").unwrap_err();
+        assert!(err.to_string().contains("has no extension"));
+    }
+}
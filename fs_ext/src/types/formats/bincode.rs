@@ -0,0 +1,76 @@
+use {
+    crate::{BinaryFormat, BoxError, CodecError, DeserializeError, Diagnostic, SerializeError},
+    bincode::config,
+    serde::{Serialize, de::DeserializeOwned},
+    std::io::{self, Read, Write},
+};
+
+// bincode is not self-describing: files are only readable as the exact type
+// they were written from.
+pub enum Bincode {}
+impl BinaryFormat for Bincode {
+    fn from_reader<R, T>(mut reader: R) -> Result<T, CodecError>
+    where
+        R: Read,
+        T: DeserializeOwned,
+    {
+        let parse_error = |message: String, source: BoxError| {
+            DeserializeError::other(Diagnostic::new("bincode", "", None, message), source)
+        };
+
+        let value = bincode::serde::decode_from_std_read(&mut reader, config::standard())
+            .map_err(|e| parse_error(e.to_string(), e.into()))?;
+
+        let trailing = io::copy(&mut reader, &mut io::sink())?;
+        if trailing != 0 {
+            let message = format!("{trailing} trailing bytes after value");
+            return Err(parse_error(message.clone(), message.into()).into());
+        }
+        Ok(value)
+    }
+
+    fn to_writer<W, T>(mut writer: W, value: T) -> Result<(), CodecError>
+    where
+        W: Write,
+        T: Serialize,
+    {
+        bincode::serde::encode_into_std_write(value, &mut writer, config::standard())
+            .map(drop)
+            .map_err(|e| SerializeError::other("bincode", e).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+    }
+
+    #[test]
+    fn bincode_save_and_load_roundtrip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.bincode");
+        let v = Demo { id: 1, name: "alpha".into() };
+
+        Bincode::save(&path, &v).expect("save bincode");
+        let loaded: Demo = Bincode::load(&path).expect("load bincode");
+        assert_eq!(v, loaded);
+    }
+
+    #[test]
+    fn bincode_rejects_trailing_bytes() {
+        let mut bytes = Bincode::to_bytes(Demo { id: 1, name: "a".into() }).unwrap();
+        bytes.push(0);
+
+        let err = Bincode::from_bytes::<Demo>(&bytes).unwrap_err();
+        assert!(err.to_string().contains("1 trailing bytes"), "{err}");
+    }
+}
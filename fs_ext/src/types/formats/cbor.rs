@@ -0,0 +1,71 @@
+use {
+    crate::{BinaryFormat, CodecError, DeserializeError, Diagnostic, SerializeError},
+    ciborium::{de, ser},
+    serde::{Serialize, de::DeserializeOwned},
+    std::io::{self, Read, Write},
+};
+
+pub enum Cbor {}
+impl BinaryFormat for Cbor {
+    fn from_reader<R, T>(reader: R) -> Result<T, CodecError>
+    where
+        R: Read,
+        T: DeserializeOwned,
+    {
+        ciborium::from_reader(reader).map_err(|e| {
+            let message = match e {
+                de::Error::Io(e) if e.kind() != io::ErrorKind::UnexpectedEof => {
+                    return CodecError::Io(e);
+                }
+                de::Error::Io(_) => "unexpected end of input".to_owned(),
+                de::Error::Syntax(offset) => format!("syntax error at byte {offset}"),
+                de::Error::Semantic(Some(offset), ref msg) => format!("{msg} at byte {offset}"),
+                ref other => other.to_string(),
+            };
+            DeserializeError::other(Diagnostic::new("CBOR", "", None, message), e).into()
+        })
+    }
+
+    fn to_writer<W, T>(writer: W, value: T) -> Result<(), CodecError>
+    where
+        W: Write,
+        T: Serialize,
+    {
+        ciborium::into_writer(&value, writer).map_err(|e| match e {
+            ser::Error::Io(e) => CodecError::Io(e),
+            other => SerializeError::other("CBOR", other).into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+    }
+
+    #[test]
+    fn cbor_save_and_load_roundtrip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.cbor");
+        let v = Demo { id: 1, name: "alpha".into() };
+
+        Cbor::save(&path, &v).expect("save cbor");
+        let loaded: Demo = Cbor::load(&path).expect("load cbor");
+        assert_eq!(v, loaded);
+    }
+
+    #[test]
+    fn cbor_invalid_bytes() {
+        let err = Cbor::from_bytes::<Demo>(&[0xa2, 0x62]).unwrap_err();
+        assert_eq!(err.diagnostic().format, "CBOR");
+    }
+}
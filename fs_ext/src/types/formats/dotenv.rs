@@ -0,0 +1,204 @@
+use {
+    super::key_value::{self, Entry},
+    crate::{DeserializeError, Diagnostic, Format, SerializeError},
+    serde::{Serialize, de::DeserializeOwned},
+};
+
+// `KEY=value` lines with optional `export`, `#` comments and single/double
+// quoting. Nothing is read from or written to the process environment.
+pub enum Dotenv {}
+impl Format for Dotenv {
+    fn parse_str<T>(s: &str) -> Result<T, DeserializeError>
+    where
+        T: DeserializeOwned,
+    {
+        let mut entries = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let err = |column: usize, message: &str| {
+                let diagnostic = Diagnostic::new("dotenv", s, Some((i + 1, column)), message);
+                DeserializeError::other(diagnostic, message.to_owned())
+            };
+
+            let trimmed = line.trim_start();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let body = trimmed.strip_prefix("export ").map_or(trimmed, str::trim_start);
+            let offset = line.len() - body.len();
+
+            let Some((key, raw)) = body.split_once('=') else {
+                return Err(err(offset + 1, "expected KEY=VALUE"));
+            };
+            let key = key.trim_end();
+            if key.is_empty()
+                || !key.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+            {
+                return Err(err(offset + 1, "invalid key"));
+            }
+
+            let value_start = offset + body.len() - raw.len();
+            let value = parse_value(raw).map_err(|(at, msg)| err(value_start + at + 1, msg))?;
+            entries.push((key.to_owned(), Entry::Value(value)));
+        }
+
+        key_value::from_entries(entries).map_err(|e| {
+            DeserializeError::other(Diagnostic::new("dotenv", s, None, e.to_string()), e)
+        })
+    }
+
+    fn to_string<T>(value: T) -> Result<String, SerializeError>
+    where
+        T: Serialize,
+    {
+        let mut out = String::new();
+        for (key, entry) in key_value::to_entries("dotenv", value, false)? {
+            let Entry::Value(value) = entry else { unreachable!("sections are disabled") };
+            out.push_str(&key);
+            out.push('=');
+            out.push_str(&quote(&value));
+            out.push('\n');
+        }
+        Ok(out)
+    }
+}
+
+// Returns the value, or the byte offset within `raw` and a message on error.
+fn parse_value(raw: &str) -> Result<String, (usize, &'static str)> {
+    let value = raw.trim_start();
+    let lead = raw.len() - value.len();
+
+    let (parsed, rest) = match value.chars().next() {
+        Some(q @ ('"' | '\'')) => {
+            let mut out = String::new();
+            let mut chars = value.char_indices().skip(1);
+            let end = loop {
+                match chars.next() {
+                    None => return Err((lead, "unterminated quoted value")),
+                    Some((i, c)) if c == q => break i + 1,
+                    Some((_, '\\')) if q == '"' => match chars.next() {
+                        Some((_, 'n')) => out.push('\n'),
+                        Some((_, 't')) => out.push('\t'),
+                        Some((_, 'r')) => out.push('\r'),
+                        Some((_, c)) => out.push(c),
+                        None => return Err((lead, "unterminated quoted value")),
+                    },
+                    Some((_, c)) => out.push(c),
+                }
+            };
+            (out, &value[end..])
+        }
+        _ => {
+            let end = value.find(" #").unwrap_or(value.len());
+            (value[..end].trim_end().to_owned(), &value[end..])
+        }
+    };
+
+    let rest_trimmed = rest.trim_start();
+    if !rest_trimmed.is_empty() && !rest_trimmed.starts_with('#') {
+        return Err((raw.len() - rest_trimmed.len(), "unexpected characters after value"));
+    }
+    Ok(parsed)
+}
+
+fn quote(value: &str) -> String {
+    let plain = value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | ':' | ',' | '@'));
+    if plain {
+        return value.to_owned();
+    }
+
+    let mut out = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[allow(non_snake_case)]
+    struct Demo {
+        APP_NAME: String,
+        PORT: u16,
+        DEBUG: bool,
+        GREETING: String,
+        HOSTS: Vec<String>,
+    }
+
+    fn demo() -> Demo {
+        Demo {
+            APP_NAME: "alpha".into(),
+            PORT: 8080,
+            DEBUG: false,
+            GREETING: "hello \"world\"\n# not a comment".into(),
+            HOSTS: vec!["a".into(), "b".into()],
+        }
+    }
+
+    #[test]
+    fn dotenv_parses_quotes_comments_and_export() {
+        let s = "# config\nexport APP_NAME=alpha # trailing\nPORT = 8080\nDEBUG='false'\n\
+                 GREETING=\"hello \\\"world\\\"\\n# not a comment\"\nHOSTS=a,b\n";
+        let got: Demo = Dotenv::parse_str(s).expect("dotenv parse_str");
+        assert_eq!(got, demo());
+    }
+
+    #[test]
+    fn dotenv_to_string_and_parse_roundtrip() {
+        let v = demo();
+        let s = Dotenv::to_string(&v).expect("dotenv to_string");
+        assert!(s.starts_with("APP_NAME=alpha\nPORT=8080\n"), "{s}");
+        let back: Demo = Dotenv::parse_str(&s).expect("dotenv parse_str");
+        assert_eq!(v, back);
+    }
+
+    #[test]
+    fn dotenv_parse_invalid_reports_position() {
+        let err = Dotenv::parse_str::<Demo>("APP_NAME=alpha\nPORT=\"8080\n").unwrap_err();
+        let diag = err.diagnostic();
+
+        assert_eq!(diag.format, "dotenv");
+        assert_eq!((diag.line, diag.column), (Some(2), Some(6)));
+        assert_eq!(diag.message, "unterminated quoted value");
+
+        let err = Dotenv::parse_str::<Demo>("APP_NAME alpha\n").unwrap_err();
+        assert_eq!((err.diagnostic().line, err.diagnostic().column), (Some(1), Some(1)));
+    }
+
+    #[test]
+    fn dotenv_rejects_nested_values() {
+        #[derive(Serialize)]
+        struct Nested {
+            inner: std::collections::BTreeMap<String, u32>,
+        }
+        let err = Dotenv::to_string(Nested { inner: [("a".into(), 1)].into() }).unwrap_err();
+        assert!(matches!(err, SerializeError::Other { format: "dotenv", .. }));
+    }
+
+    #[test]
+    fn dotenv_save_and_load_roundtrip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join(".env");
+        let v = demo();
+
+        Dotenv::save(&path, &v).expect("save dotenv");
+        let loaded: Demo = Dotenv::load(&path).expect("load dotenv");
+        assert_eq!(v, loaded);
+    }
+}
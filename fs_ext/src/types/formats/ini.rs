@@ -0,0 +1,143 @@
+use {
+    super::key_value::{self, Entry},
+    crate::{DeserializeError, Diagnostic, Format, SerializeError},
+    serde::{Serialize, de::DeserializeOwned},
+};
+
+// Keys before the first `[section]` map to top-level fields; each section maps
+// to a nested struct or map. Values are parsed into the requested type.
+pub enum Ini {}
+impl Format for Ini {
+    fn parse_str<T>(s: &str) -> Result<T, DeserializeError>
+    where
+        T: DeserializeOwned,
+    {
+        let ini = ini::Ini::load_from_str(s).map_err(|e| {
+            let diagnostic = Diagnostic::new("INI", s, Some((e.line, e.col)), e.msg.clone());
+            DeserializeError::other(diagnostic, e)
+        })?;
+
+        let mut entries = Vec::new();
+        for (section, props) in ini.iter() {
+            let props = props.iter().map(|(k, v)| (k.to_owned(), v.to_owned()));
+            match section {
+                None => entries.extend(props.map(|(k, v)| (k, Entry::Value(v)))),
+                Some(name) => entries.push((name.to_owned(), Entry::Section(props.collect()))),
+            }
+        }
+
+        key_value::from_entries(entries)
+            .map_err(|e| DeserializeError::other(Diagnostic::new("INI", s, None, e.to_string()), e))
+    }
+
+    fn to_string<T>(value: T) -> Result<String, SerializeError>
+    where
+        T: Serialize,
+    {
+        let mut ini = ini::Ini::new();
+        let (globals, sections): (Vec<_>, Vec<_>) = key_value::to_entries("INI", value, true)?
+            .into_iter()
+            .partition(|(_, entry)| matches!(entry, Entry::Value(_)));
+
+        for (key, entry) in globals.into_iter().chain(sections) {
+            match entry {
+                Entry::Value(v) => {
+                    ini.with_general_section().set(key, v);
+                }
+                Entry::Section(props) => {
+                    let mut section = ini.with_section(Some(key));
+                    for (k, v) in props {
+                        section.set(k, v);
+                    }
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+        ini.write_to(&mut out).map_err(|e| SerializeError::other("INI", e))?;
+        String::from_utf8(out).map_err(|e| SerializeError::other("INI", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Server {
+        host: String,
+        port: u16,
+        tags: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        name: String,
+        debug: bool,
+        timeout: Option<f64>,
+        server: Server,
+    }
+
+    fn demo() -> Demo {
+        Demo {
+            name: "alpha".into(),
+            debug: true,
+            timeout: None,
+            server: Server {
+                host: "localhost".into(),
+                port: 8080,
+                tags: vec!["a".into(), "b".into()],
+            },
+        }
+    }
+
+    #[test]
+    fn ini_parses_typed_values_and_sections() {
+        let s =
+            "name = alpha\ndebug = true\n\n[server]\nhost = localhost\nport = 8080\ntags = a, b\n";
+        let got: Demo = Ini::parse_str(s).expect("ini parse_str");
+        assert_eq!(got, demo());
+    }
+
+    #[test]
+    fn ini_to_string_and_parse_roundtrip() {
+        let v = demo();
+        let s = Ini::to_string(&v).expect("ini to_string");
+        assert!(s.starts_with("name=alpha\n"), "{s}");
+        assert!(s.contains("[server]\nhost=localhost\nport=8080\ntags=a,b\n"), "{s}");
+        let back: Demo = Ini::parse_str(&s).expect("ini parse_str");
+        assert_eq!(v, back);
+    }
+
+    #[test]
+    fn ini_parse_invalid_reports_position() {
+        let err = Ini::parse_str::<Demo>("name = alpha\n= 1\n").unwrap_err();
+        let diag = err.diagnostic();
+
+        assert_eq!(diag.format, "INI");
+        assert_eq!(diag.line, Some(2));
+        assert_eq!(diag.message, "missing key");
+    }
+
+    #[test]
+    fn ini_type_mismatch_is_reported() {
+        let s = "name = alpha\ndebug = maybe\n[server]\nhost = h\nport = 1\ntags =\n";
+        let err = Ini::parse_str::<Demo>(s).unwrap_err();
+        assert!(err.to_string().contains("maybe"), "{err}");
+    }
+
+    #[test]
+    fn ini_save_and_load_roundtrip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.ini");
+        let v = demo();
+
+        Ini::save(&path, &v).expect("save ini");
+        let loaded: Demo = Ini::load(&path).expect("load ini");
+        assert_eq!(v, loaded);
+    }
+}
@@ -1,6 +1,7 @@
 use {
-    crate::{DeserializeError, Format, SerializeError},
+    crate::{CodecError, DeserializeError, Diagnostic, Format, ParseError, SerializeError},
     serde::{Serialize, de::DeserializeOwned},
+    std::io::{Read, Write},
 };
 
 pub enum Json {}
@@ -9,7 +10,7 @@ impl Format for Json {
     where
         T: DeserializeOwned,
     {
-        serde_json::from_str(s).map_err(DeserializeError::Json)
+        serde_json::from_str(s).map_err(|e| parse_error(e, s))
     }
 
     fn to_string<T>(value: T) -> Result<String, SerializeError>
@@ -18,6 +19,38 @@ impl Format for Json {
     {
         serde_json::to_string_pretty(&value).map_err(SerializeError::Json)
     }
+
+    fn from_reader<R, T>(reader: R) -> Result<T, CodecError>
+    where
+        R: Read,
+        T: DeserializeOwned,
+    {
+        serde_json::from_reader(reader).map_err(|e| match e.is_io() {
+            true => CodecError::Io(e.into()),
+            false => parse_error(e, "").into(),
+        })
+    }
+
+    fn to_writer<W, T>(writer: W, value: T) -> Result<(), CodecError>
+    where
+        W: Write,
+        T: Serialize,
+    {
+        serde_json::to_writer_pretty(writer, &value).map_err(|e| match e.is_io() {
+            true => CodecError::Io(e.into()),
+            false => SerializeError::Json(e).into(),
+        })
+    }
+}
+
+fn parse_error(e: serde_json::Error, src: &str) -> DeserializeError {
+    let position = (e.line() > 0).then(|| (e.line(), e.column()));
+    let message = e.to_string();
+    let suffix = format!(" at line {} column {}", e.line(), e.column());
+    let message = message.strip_suffix(&suffix).unwrap_or(&message);
+
+    let diagnostic = Diagnostic::new("JSON", src, position, message);
+    DeserializeError::Json(ParseError::new(diagnostic, e))
 }
 
 #[cfg(test)]
@@ -57,6 +90,31 @@ mod tests {
         assert!(matches!(err, DeserializeError::Json(_)));
     }
 
+    #[test]
+    fn json_parse_invalid_reports_position() {
+        let err = Json::parse_str::<Demo>("{\n  \"id\": 1,\n  \"name\": oops\n}").unwrap_err();
+        let diag = err.diagnostic();
+
+        assert_eq!(diag.format, "JSON");
+        assert_eq!(diag.line, Some(3));
+        assert_eq!(diag.column, Some(11));
+        assert_eq!(diag.snippet.as_deref(), Some("  \"name\": oops"));
+        assert!(err.to_string().contains('^'), "{err}");
+    }
+
+    #[test]
+    fn json_load_invalid_names_the_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("broken.json");
+        fs::write(&path, "{ \"id\": 1 ").unwrap();
+
+        let err = Json::load::<Demo>(&path).unwrap_err();
+        let CodecError::Deserialize(err) = err else { panic!("expected deserialize error") };
+
+        assert_eq!(err.diagnostic().path.as_deref(), Some(path.as_path()));
+        assert!(err.to_string().contains(&path.display().to_string()), "{err}");
+    }
+
     #[test]
     fn json_save_and_load_roundtrip() {
         let dir = tempdir().unwrap();
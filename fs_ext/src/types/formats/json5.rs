@@ -0,0 +1,80 @@
+use {
+    super::Json,
+    crate::{CodecError, DeserializeError, Diagnostic, Format, SerializeError},
+    serde::{Serialize, de::DeserializeOwned},
+    std::io::Write,
+};
+
+// JSON5 is a superset of JSON (and of JSONC), so output is plain pretty JSON.
+pub enum Json5 {}
+impl Format for Json5 {
+    fn parse_str<T>(s: &str) -> Result<T, DeserializeError>
+    where
+        T: DeserializeOwned,
+    {
+        json5::from_str(s).map_err(|e| {
+            let json5::Error::Message { msg, location } = &e;
+            let position = location.as_ref().map(|l| (l.line, l.column));
+            let diagnostic = Diagnostic::new("JSON5", s, position, msg.clone());
+            DeserializeError::other(diagnostic, e)
+        })
+    }
+
+    fn to_string<T>(value: T) -> Result<String, SerializeError>
+    where
+        T: Serialize,
+    {
+        serde_json::to_string_pretty(&value).map_err(SerializeError::Json)
+    }
+
+    fn to_writer<W, T>(writer: W, value: T) -> Result<(), CodecError>
+    where
+        W: Write,
+        T: Serialize,
+    {
+        Json::to_writer(writer, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+    }
+
+    #[test]
+    fn json5_parses_comments_and_trailing_commas() {
+        let s = "{\n  // the id\n  id: 1,\n  /* name */ name: 'alpha',\n}";
+        let got: Demo = Json5::parse_str(s).expect("json5 parse_str");
+        assert_eq!(got, Demo { id: 1, name: "alpha".into() });
+    }
+
+    #[test]
+    fn json5_parse_invalid_reports_position() {
+        let err = Json5::parse_str::<Demo>("{\n  id: 1,\n  name: ,\n}").unwrap_err();
+        let diag = err.diagnostic();
+
+        assert_eq!(diag.format, "JSON5");
+        assert_eq!(diag.line, Some(3));
+        assert_eq!(diag.snippet.as_deref(), Some("  name: ,"));
+    }
+
+    #[test]
+    fn json5_save_and_load_roundtrip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.jsonc");
+        let v = Demo { id: 2, name: "beta".into() };
+
+        Json5::save(&path, &v).expect("save json5");
+        let loaded: Demo = Json5::load(&path).expect("load json5");
+        assert_eq!(v, loaded);
+    }
+}
@@ -0,0 +1,163 @@
+use {
+    crate::SerializeError,
+    serde::{
+        Serialize,
+        de::{
+            self, DeserializeOwned, Deserializer, IntoDeserializer, Unexpected, Visitor,
+            value::{Error, MapDeserializer, SeqDeserializer},
+        },
+    },
+    serde_yaml::Value,
+};
+
+// Shared model for the string-typed key/value formats (INI, dotenv): every
+// value is text, and is parsed into the requested type on demand.
+pub(crate) enum Entry {
+    Value(String),
+    Section(Vec<(String, String)>),
+}
+
+pub(crate) fn from_entries<T>(entries: Vec<(String, Entry)>) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(MapDeserializer::new(entries.into_iter()))
+}
+
+// Serializes through `serde_yaml::Value` because its mapping keeps field order.
+pub(crate) fn to_entries<T>(
+    format: &'static str, value: T, sections: bool,
+) -> Result<Vec<(String, Entry)>, SerializeError>
+where
+    T: Serialize,
+{
+    let Value::Mapping(map) = serde_yaml::to_value(value).map_err(SerializeError::Yaml)? else {
+        return Err(SerializeError::other(format, "top-level value must be a map or struct"));
+    };
+
+    map.into_iter()
+        .map(|(k, v)| {
+            let key = scalar(format, &k)?;
+            let entry = match v {
+                Value::Mapping(section) if sections => Entry::Section(
+                    section
+                        .into_iter()
+                        .map(|(k, v)| Ok((scalar(format, &k)?, scalar(format, &v)?)))
+                        .collect::<Result<_, SerializeError>>()?,
+                ),
+                other => Entry::Value(scalar(format, &other)?),
+            };
+            Ok((key, entry))
+        })
+        .collect()
+}
+
+fn scalar(format: &'static str, value: &Value) -> Result<String, SerializeError> {
+    match value {
+        Value::Null => Ok(String::new()),
+        Value::Bool(b) => Ok(b.to_string()),
+        Value::Number(n) => Ok(n.to_string()),
+        Value::String(s) => Ok(s.clone()),
+        Value::Sequence(items) => {
+            Ok(items.iter().map(|v| scalar(format, v)).collect::<Result<Vec<_>, _>>()?.join(","))
+        }
+        Value::Mapping(_) | Value::Tagged(_) => {
+            Err(SerializeError::other(format, "nested values are not supported"))
+        }
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for Entry {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! parse_scalar {
+    ($($method:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                match self {
+                    Entry::Value(s) => match s.trim().parse() {
+                        Ok(v) => visitor.$visit(v),
+                        Err(_) => Err(de::Error::invalid_value(Unexpected::Str(&s), &visitor)),
+                    },
+                    section => section.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Entry {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            Entry::Value(s) => visitor.visit_string(s),
+            Entry::Section(props) => visitor.visit_map(MapDeserializer::new(
+                props.into_iter().map(|(k, v)| (k, Entry::Value(v))),
+            )),
+        }
+    }
+
+    parse_scalar! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            Entry::Value(s) if s.is_empty() => visitor.visit_none(),
+            entry => visitor.visit_some(entry),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            Entry::Value(s) => {
+                let items = s.split(',').map(str::trim).filter(|s| !s.is_empty());
+                visitor.visit_seq(SeqDeserializer::new(items.map(|s| Entry::Value(s.to_owned()))))
+            }
+            section => section.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self, _name: &'static str, visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self, _name: &'static str, _variants: &'static [&'static str], visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self {
+            Entry::Value(s) => visitor.visit_enum(s.into_deserializer()),
+            section => section.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        str string bytes byte_buf unit_struct tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
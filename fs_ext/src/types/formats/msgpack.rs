@@ -0,0 +1,64 @@
+use {
+    crate::{BinaryFormat, CodecError, DeserializeError, Diagnostic, SerializeError},
+    serde::{Serialize, de::DeserializeOwned},
+    std::io::{Read, Write},
+};
+
+// Structs are written as maps (field names included) so files stay readable by
+// other MessagePack tooling and tolerate field reordering.
+pub enum MessagePack {}
+impl BinaryFormat for MessagePack {
+    fn from_reader<R, T>(reader: R) -> Result<T, CodecError>
+    where
+        R: Read,
+        T: DeserializeOwned,
+    {
+        rmp_serde::from_read(reader).map_err(|e| {
+            let diagnostic = Diagnostic::new("MessagePack", "", None, e.to_string());
+            DeserializeError::other(diagnostic, e).into()
+        })
+    }
+
+    fn to_writer<W, T>(mut writer: W, value: T) -> Result<(), CodecError>
+    where
+        W: Write,
+        T: Serialize,
+    {
+        rmp_serde::encode::write_named(&mut writer, &value)
+            .map_err(|e| SerializeError::other("MessagePack", e).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde::{Deserialize, Serialize},
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+        data: Vec<u8>,
+    }
+
+    #[test]
+    fn msgpack_save_and_load_roundtrip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.msgpack");
+        let v = Demo { id: 1, name: "alpha".into(), data: vec![0, 255] };
+
+        MessagePack::save(&path, &v).expect("save msgpack");
+        let loaded: Demo = MessagePack::load(&path).expect("load msgpack");
+        assert_eq!(v, loaded);
+    }
+
+    #[test]
+    fn msgpack_invalid_bytes() {
+        let err = MessagePack::from_bytes::<Demo>(&[0xc1]).unwrap_err();
+        assert_eq!(err.diagnostic().format, "MessagePack");
+        assert_eq!(err.diagnostic().line, None);
+    }
+}
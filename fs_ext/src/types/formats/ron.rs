@@ -0,0 +1,91 @@
+use {
+    crate::{DeserializeError, Diagnostic, Format, SerializeError},
+    ron::ser::PrettyConfig,
+    serde::{Serialize, de::DeserializeOwned},
+};
+
+pub enum Ron {}
+impl Format for Ron {
+    fn parse_str<T>(s: &str) -> Result<T, DeserializeError>
+    where
+        T: DeserializeOwned,
+    {
+        ron::from_str(s).map_err(|e| {
+            let position = (e.span.start.line > 0).then_some((e.span.start.line, e.span.start.col));
+            let diagnostic = Diagnostic::new("RON", s, position, e.code.to_string());
+            DeserializeError::other(diagnostic, e)
+        })
+    }
+
+    fn to_string<T>(value: T) -> Result<String, SerializeError>
+    where
+        T: Serialize,
+    {
+        ron::ser::to_string_pretty(&value, PrettyConfig::default())
+            .map_err(|e| SerializeError::other("RON", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::CodecError,
+        serde::{Deserialize, Serialize},
+        std::fs,
+        tempfile::tempdir,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum Mode {
+        Fast,
+        Limit(u32),
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Demo {
+        id: u32,
+        name: String,
+        mode: Mode,
+    }
+
+    fn demo() -> Demo {
+        Demo { id: 7, name: "beta".into(), mode: Mode::Limit(3) }
+    }
+
+    #[test]
+    fn ron_to_string_and_parse_roundtrip() {
+        let v = demo();
+        let s = Ron::to_string(&v).expect("ron to_string");
+        assert!(s.contains("mode: Limit(3)"), "{s}");
+        let back: Demo = Ron::parse_str(&s).expect("ron parse_str");
+        assert_eq!(v, back);
+    }
+
+    #[test]
+    fn ron_parse_invalid_reports_position() {
+        let err = Ron::parse_str::<Demo>("(\n  id: 1,\n  name: oops,\n)").unwrap_err();
+        let diag = err.diagnostic();
+
+        assert_eq!(diag.format, "RON");
+        assert_eq!(diag.line, Some(3));
+        assert_eq!(diag.snippet.as_deref(), Some("  name: oops,"));
+    }
+
+    #[test]
+    fn ron_save_and_load_roundtrip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("demo.ron");
+        let v = demo();
+
+        Ron::save(&path, &v).expect("save ron");
+        let loaded: Demo = Ron::load(&path).expect("load ron");
+        assert_eq!(v, loaded);
+
+        fs::write(&path, "(id: )").unwrap();
+        let Err(CodecError::Deserialize(err)) = Ron::load::<Demo>(&path) else {
+            panic!("expected deserialize error")
+        };
+        assert_eq!(err.diagnostic().path.as_deref(), Some(path.as_path()));
+    }
+}
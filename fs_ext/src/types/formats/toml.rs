@@ -1,5 +1,5 @@
 use {
-    crate::{DeserializeError, Format, SerializeError},
+    crate::{DeserializeError, Diagnostic, Format, ParseError, SerializeError},
     serde::{Serialize, de::DeserializeOwned},
 };
 
@@ -9,7 +9,13 @@ impl Format for Toml {
     where
         T: DeserializeOwned,
     {
-        toml::from_str(s).map_err(DeserializeError::Toml)
+        toml::from_str(s).map_err(|e| {
+            let diagnostic = match e.span() {
+                Some(span) => Diagnostic::from_offset("TOML", s, span.start, e.message()),
+                None => Diagnostic::new("TOML", s, None, e.message()),
+            };
+            DeserializeError::Toml(ParseError::new(diagnostic, e))
+        })
     }
 
     fn to_string<T>(value: T) -> Result<String, SerializeError>
@@ -24,6 +30,7 @@ impl Format for Toml {
 mod tests {
     use {
         super::*,
+        crate::CodecError,
         serde::{Deserialize, Serialize},
         std::fs,
         tempfile::tempdir,
@@ -58,6 +65,31 @@ mod tests {
         assert!(matches!(err, DeserializeError::Toml(_)));
     }
 
+    #[test]
+    fn toml_parse_invalid_reports_position() {
+        let err = Toml::parse_str::<Demo>("id = 1\nname = oops\n").unwrap_err();
+        let diag = err.diagnostic();
+
+        assert_eq!(diag.format, "TOML");
+        assert_eq!(diag.line, Some(2));
+        assert_eq!(diag.column, Some(8));
+        assert_eq!(diag.snippet.as_deref(), Some("name = oops"));
+        assert!(err.to_string().contains('^'), "{err}");
+    }
+
+    #[test]
+    fn toml_load_invalid_names_the_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("broken.toml");
+        fs::write(&path, "id = ").unwrap();
+
+        let err = Toml::load::<Demo>(&path).unwrap_err();
+        let CodecError::Deserialize(err) = err else { panic!("expected deserialize error") };
+
+        assert_eq!(err.diagnostic().path.as_deref(), Some(path.as_path()));
+        assert!(err.to_string().contains(&path.display().to_string()), "{err}");
+    }
+
     #[test]
     fn toml_save_and_load_roundtrip() {
         let dir = tempdir().unwrap();
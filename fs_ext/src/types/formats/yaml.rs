@@ -1,6 +1,7 @@
 use {
-    crate::{DeserializeError, Format, SerializeError},
+    crate::{CodecError, DeserializeError, Diagnostic, Format, ParseError, SerializeError},
     serde::{Serialize, de::DeserializeOwned},
+    std::io::Write,
 };
 
 pub enum Yaml {}
@@ -9,7 +10,19 @@ impl Format for Yaml {
     where
         T: DeserializeOwned,
     {
-        serde_yaml::from_str(s).map_err(DeserializeError::Yaml)
+        serde_yaml::from_str(s).map_err(|e| {
+            let position = e.location().map(|loc| (loc.line(), loc.column()));
+            let message = e.to_string();
+            let message = match position {
+                Some((line, column)) => message
+                    .strip_suffix(&format!(" at line {line} column {column}"))
+                    .unwrap_or(&message),
+                None => &message,
+            };
+
+            let diagnostic = Diagnostic::new("YAML", s, position, message);
+            DeserializeError::Yaml(ParseError::new(diagnostic, e))
+        })
     }
 
     fn to_string<T>(value: T) -> Result<String, SerializeError>
@@ -18,6 +31,14 @@ impl Format for Yaml {
     {
         serde_yaml::to_string(&value).map_err(SerializeError::Yaml)
     }
+
+    fn to_writer<W, T>(writer: W, value: T) -> Result<(), CodecError>
+    where
+        W: Write,
+        T: Serialize,
+    {
+        serde_yaml::to_writer(writer, &value).map_err(|e| SerializeError::Yaml(e).into())
+    }
 }
 
 #[cfg(test)]
@@ -58,6 +79,29 @@ mod tests {
         assert!(matches!(err, DeserializeError::Yaml(_)));
     }
 
+    #[test]
+    fn yaml_parse_invalid_reports_position() {
+        let err = Yaml::parse_str::<Demo>("id: 1\nname: [unclosed\n").unwrap_err();
+        let diag = err.diagnostic();
+
+        assert_eq!(diag.format, "YAML");
+        assert!(diag.line.is_some() && diag.column.is_some());
+        assert!(err.to_string().contains('^'), "{err}");
+    }
+
+    #[test]
+    fn yaml_load_invalid_names_the_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("broken.yaml");
+        fs::write(&path, "id: [1").unwrap();
+
+        let err = Yaml::load::<Demo>(&path).unwrap_err();
+        let CodecError::Deserialize(err) = err else { panic!("expected deserialize error") };
+
+        assert_eq!(err.diagnostic().path.as_deref(), Some(path.as_path()));
+        assert!(err.to_string().contains(&path.display().to_string()), "{err}");
+    }
+
     #[test]
     fn yaml_save_and_load_roundtrip() {
         let dir = tempdir().unwrap();
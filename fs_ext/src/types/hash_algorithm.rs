@@ -0,0 +1,40 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Sha256,
+    Blake3,
+    Xxh3,
+}
+
+impl HashAlgorithm {
+    pub fn name(self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Blake3 => "blake3",
+            HashAlgorithm::Xxh3 => "xxh3",
+        }
+    }
+
+    pub fn digest_len(self) -> usize {
+        match self {
+            HashAlgorithm::Sha256 | HashAlgorithm::Blake3 => 32,
+            HashAlgorithm::Xxh3 => 8,
+        }
+    }
+
+    pub fn is_enabled(self) -> bool {
+        match self {
+            HashAlgorithm::Sha256 => cfg!(feature = "sha256"),
+            HashAlgorithm::Blake3 => cfg!(feature = "blake3"),
+            HashAlgorithm::Xxh3 => cfg!(feature = "xxhash"),
+        }
+    }
+
+    #[cfg(any(feature = "sha256", feature = "blake3", feature = "xxhash"))]
+    pub(crate) fn unsupported(self) -> std::io::Error {
+        let feature = if self == HashAlgorithm::Xxh3 { "xxhash" } else { self.name() };
+        std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            format!("{} hashing requires the `{feature}` feature", self.name()),
+        )
+    }
+}
@@ -0,0 +1,80 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layer {
+    pub path: PathBuf,
+    pub optional: bool,
+}
+
+impl Layer {
+    pub fn required(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), optional: false }
+    }
+
+    pub fn optional(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), optional: true }
+    }
+}
+
+impl From<&str> for Layer {
+    fn from(path: &str) -> Self {
+        Layer::required(path)
+    }
+}
+
+impl From<String> for Layer {
+    fn from(path: String) -> Self {
+        Layer::required(path)
+    }
+}
+
+impl From<&Path> for Layer {
+    fn from(path: &Path) -> Self {
+        Layer::required(path)
+    }
+}
+
+impl From<PathBuf> for Layer {
+    fn from(path: PathBuf) -> Self {
+        Layer::required(path)
+    }
+}
+
+impl From<&PathBuf> for Layer {
+    fn from(path: &PathBuf) -> Self {
+        Layer::required(path)
+    }
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct LayeredOptions {
+    pub env_prefix: Option<String>,
+}
+
+impl AsRef<LayeredOptions> for LayeredOptions {
+    fn as_ref(&self) -> &LayeredOptions {
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LayerSource {
+    File(PathBuf),
+    Env(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layered<T> {
+    pub value: T,
+    pub sources: BTreeMap<String, LayerSource>,
+    pub skipped: Vec<PathBuf>,
+}
+
+impl<T> Layered<T> {
+    pub fn source(&self, key: &str) -> Option<&LayerSource> {
+        self.sources.get(key)
+    }
+}
@@ -0,0 +1,54 @@
+use {
+    crate::{BoxError, MigrationError},
+    serde_json::Value,
+    std::{collections::BTreeMap, fmt, path::Path},
+};
+
+type Step = Box<dyn Fn(&mut Value) -> Result<(), BoxError> + Send + Sync>;
+
+#[derive(Default)]
+pub struct Migrations {
+    steps: BTreeMap<u32, Step>,
+}
+
+impl Migrations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Registers the step that upgrades a document from `from` to `from + 1`.
+    pub fn step<F>(mut self, from: u32, f: F) -> Self
+    where
+        F: Fn(&mut Value) -> Result<(), BoxError> + Send + Sync + 'static,
+    {
+        self.steps.insert(from, Box::new(f));
+        self
+    }
+
+    pub fn versions(&self) -> impl Iterator<Item = u32> + '_ {
+        self.steps.keys().copied()
+    }
+
+    pub(crate) fn apply(
+        &self, path: &Path, doc: &mut Value, from: u32, to: u32,
+    ) -> Result<(), MigrationError> {
+        for version in from..to {
+            let step = self.steps.get(&version).ok_or_else(|| MigrationError::MissingStep {
+                path: path.to_owned(),
+                from: version,
+            })?;
+            step(doc).map_err(|source| MigrationError::Failed {
+                path: path.to_owned(),
+                from: version,
+                source,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Migrations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Migrations").field("steps", &self.steps.keys()).finish()
+    }
+}
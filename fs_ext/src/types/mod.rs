@@ -1,10 +1,77 @@
 pub mod formats {
+    #[cfg(feature = "bincode")]
+    mod bincode;
+    #[cfg(feature = "cbor")]
+    mod cbor;
+    #[cfg(feature = "dotenv")]
+    mod dotenv;
+    #[cfg(feature = "ini")]
+    mod ini;
     mod json;
+    #[cfg(feature = "json5")]
+    mod json5;
+    #[cfg(any(feature = "ini", feature = "dotenv"))]
+    mod key_value;
+    #[cfg(feature = "msgpack")]
+    mod msgpack;
+    #[cfg(feature = "ron")]
+    mod ron;
     mod toml;
     mod yaml;
+
     pub use {json::Json, toml::Toml, yaml::Yaml};
+
+    #[cfg(feature = "bincode")]
+    pub use bincode::Bincode;
+    #[cfg(feature = "cbor")]
+    pub use cbor::Cbor;
+    #[cfg(feature = "dotenv")]
+    pub use dotenv::Dotenv;
+    #[cfg(feature = "ini")]
+    pub use ini::Ini;
+    #[cfg(feature = "json5")]
+    pub use json5::Json5;
+    #[cfg(feature = "msgpack")]
+    pub use msgpack::MessagePack;
+    #[cfg(feature = "ron")]
+    pub use ron::Ron;
 }
+mod archive_format;
+mod clear_options;
+mod compare_options;
+mod compression;
+mod copy_options;
+mod copy_progress;
+mod dedupe_options;
+mod detected;
+mod digest;
+mod fault;
+mod format_registry;
+mod hash_algorithm;
+mod layered;
+mod migrations;
 mod path_kind;
+mod sync_options;
+mod vfs_metadata;
 mod write_options;
 
-pub use {path_kind::PathKind, write_options::*};
+pub use {
+    archive_format::ArchiveFormat,
+    clear_options::ClearOptions,
+    compare_options::{CompareOptions, DirDiff},
+    compression::Compression,
+    copy_options::*,
+    copy_progress::CopyProgress,
+    dedupe_options::*,
+    detected::{Detected, DetectedBy},
+    digest::Digest,
+    fault::Fault,
+    format_registry::FormatRegistry,
+    hash_algorithm::HashAlgorithm,
+    layered::{Layer, LayerSource, Layered, LayeredOptions},
+    migrations::Migrations,
+    path_kind::PathKind,
+    sync_options::*,
+    vfs_metadata::VfsMetadata,
+    write_options::*,
+};
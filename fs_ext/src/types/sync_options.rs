@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+#[derive(Default, Clone, Debug, PartialEq, Copy, Eq)]
+pub struct SyncOptions {
+    pub compare: SyncCompare,
+    pub delete_extraneous: bool,
+}
+
+impl AsRef<SyncOptions> for SyncOptions {
+    fn as_ref(&self) -> &SyncOptions {
+        self
+    }
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Copy, Eq)]
+pub enum SyncCompare {
+    #[default]
+    SizeAndMtime,
+    Content,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct SyncReport {
+    pub copied: Vec<PathBuf>,
+    pub skipped: Vec<PathBuf>,
+    pub deleted: Vec<PathBuf>,
+    pub created_dirs: Vec<PathBuf>,
+    pub unsupported: Vec<PathBuf>,
+}
@@ -0,0 +1,36 @@
+use {
+    crate::PathKind,
+    std::{fs, time::SystemTime},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VfsMetadata {
+    pub kind: PathKind,
+    pub len: u64,
+    pub modified: Option<SystemTime>,
+}
+
+impl VfsMetadata {
+    pub fn is_file(&self) -> bool {
+        self.kind == PathKind::File
+    }
+
+    pub fn is_dir(&self) -> bool {
+        self.kind == PathKind::Dir
+    }
+}
+
+impl From<fs::Metadata> for VfsMetadata {
+    fn from(meta: fs::Metadata) -> Self {
+        let kind = if meta.file_type().is_symlink() {
+            PathKind::SymLink
+        } else if meta.is_file() {
+            PathKind::File
+        } else if meta.is_dir() {
+            PathKind::Dir
+        } else {
+            PathKind::Other
+        };
+        VfsMetadata { kind, len: meta.len(), modified: meta.modified().ok() }
+    }
+}
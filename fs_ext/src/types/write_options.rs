@@ -68,6 +68,14 @@ pub enum RenameOptions {
 
 impl RenameOptions {
     pub fn generate_unique_path(&self, path: &Path) -> io::Result<PathBuf> {
+        self.unique_path_by(path, Path::exists)
+    }
+
+    // Same naming scheme, with the free-name check supplied by the caller so a
+    // virtual filesystem can answer it.
+    pub(crate) fn unique_path_by(
+        &self, path: &Path, exists: impl Fn(&Path) -> bool,
+    ) -> io::Result<PathBuf> {
         let stem = path.utf8_stem()?;
         let ext = path.utf8_extension()?.map(|s| format!(".{}", s)).unwrap_or_default();
         let parent = path.parent_or_current();
@@ -85,7 +93,7 @@ impl RenameOptions {
                 for _ in 0..8 {
                     let id = Uuid::new_v4();
                     let cand = parent.join(format!("{stem}_{id}{ext}"));
-                    if !cand.exists() {
+                    if !exists(&cand) {
                         return Ok(cand);
                     }
                 }
@@ -99,7 +107,7 @@ impl RenameOptions {
                     let new_name = format!("{}_{}{}", stem, i, ext);
                     let new_path = parent.join(new_name);
 
-                    if !new_path.exists() {
+                    if !exists(&new_path) {
                         return Ok(new_path);
                     }
                 }
@@ -2,9 +2,37 @@ pub fn normalize_ext(s: &str) -> String {
     s.trim().trim_start_matches('.').to_ascii_lowercase()
 }
 
+pub fn short_type_name<T: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+// Minimal glob matcher over '/'-separated paths: `?` and `*` stay within one
+// component, `**` spans components and `**/` may match zero of them.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    fn go(p: &[u8], t: &[u8]) -> bool {
+        match p {
+            [] => t.is_empty(),
+            [b'*', b'*', b'/', rest @ ..] => {
+                go(rest, t)
+                    || t.iter().enumerate().any(|(i, &c)| c == b'/' && go(rest, &t[i + 1..]))
+            }
+            [b'*', b'*', rest @ ..] => (0..=t.len()).any(|i| go(rest, &t[i..])),
+            [b'*', rest @ ..] => {
+                let seg = t.iter().position(|&c| c == b'/').unwrap_or(t.len());
+                (0..=seg).any(|i| go(rest, &t[i..]))
+            }
+            [b'?', rest @ ..] => matches!(t, [c, ..] if *c != b'/') && go(rest, &t[1..]),
+            [c, rest @ ..] => matches!(t, [d, ..] if d == c) && go(rest, &t[1..]),
+        }
+    }
+
+    go(pattern.as_bytes(), text.as_bytes())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::normalize_ext;
+    use super::{glob_match, normalize_ext};
 
     #[test]
     fn normalize_ext_cases() {
@@ -25,4 +53,29 @@ mod tests {
             assert_eq!(normalize_ext(input), expected, "input: {:?}", input);
         }
     }
+
+    #[test]
+    fn glob_match_cases() {
+        let cases = [
+            ("*.log", "app.log", true),
+            ("*.log", "app.txt", false),
+            ("*.log", "logs/app.log", false), // `*` stays within a component
+            ("cache-?", "cache-1", true),
+            ("cache-?", "cache-12", false),
+            ("a/*/c", "a/b/c", true),
+            ("a/*/c", "a/b/x/c", false),
+            ("a/**/c", "a/c", true),
+            ("a/**/c", "a/b/x/c", true),
+            ("**/*.tmp", "x.tmp", true),
+            ("**/*.tmp", "deep/er/x.tmp", true),
+            ("build/**", "build/out/a.o", true),
+            ("exact.txt", "exact.txt", true),
+            ("exact.txt", "exact.txt2", false),
+            ("", "", true),
+        ];
+
+        for (pattern, text, expected) in cases {
+            assert_eq!(glob_match(pattern, text), expected, "{pattern:?} vs {text:?}");
+        }
+    }
 }
@@ -5,3 +5,6 @@ mod macros {
     mod save;
     mod temp;
 }
+
+#[cfg(feature = "testing")]
+mod testing;
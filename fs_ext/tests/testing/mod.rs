@@ -0,0 +1,45 @@
+use {
+    fs_ext::{
+        file,
+        testing::{assert_dirs_eq, assert_matches_golden, fs_test},
+    },
+    std::{fs, io, path::Path},
+};
+
+#[fs_test(rejects_missing_path, rejects_dir, existing_file_ok)]
+fn checked_size(path: &Path) -> io::Result<u64> {
+    file::size(path)
+}
+
+#[fs_test(new_dir_ok, existing_dir_ok, rejects_file)]
+fn ensure_dir(path: &Path) -> io::Result<()> {
+    fs_ext::dir::ensure(path)
+}
+
+#[test]
+fn golden_and_tree_asserts_are_public() {
+    let td = tempfile::tempdir().unwrap();
+    let golden = td.path().join("expected.txt");
+    fs::write(&golden, "hello\n").unwrap();
+    assert_matches_golden(&golden, "hello\n");
+
+    fs::create_dir_all(td.path().join("a")).unwrap();
+    fs::create_dir_all(td.path().join("b")).unwrap();
+    fs::write(td.path().join("a/x.txt"), "x").unwrap();
+    fs::write(td.path().join("b/x.txt"), "x").unwrap();
+    assert_dirs_eq(td.path().join("a"), td.path().join("b"));
+}
+
+#[test]
+fn fixture_macro_and_builder_are_public() {
+    let tree = fs_ext::fixture! {
+        "config/app.toml" => "name = \"demo\"",
+        "cache/" => dir,
+    }
+    .unwrap();
+
+    fs_ext::testing::Fixture::new()
+        .file("config/app.toml", "name = \"demo\"")
+        .dir("cache")
+        .assert_matches(tree.path());
+}
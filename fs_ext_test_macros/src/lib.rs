@@ -38,7 +38,7 @@ pub fn fs_test(attr: TokenStream, item: TokenStream) -> TokenStream {
         (
             "rejects_missing_path",
             quote! {
-                crate::test_utils::assert_fn_rejects_missing_path(|path| {
+                ::fs_ext::testing::assert_fn_rejects_missing_path(|path| {
                     super::#fn_name(path)
                 })
             },
@@ -46,7 +46,7 @@ pub fn fs_test(attr: TokenStream, item: TokenStream) -> TokenStream {
         (
             "rejects_file",
             quote! {
-                crate::test_utils::assert_fn_rejects_file_path(|path| {
+                ::fs_ext::testing::assert_fn_rejects_file_path(|path| {
                     super::#fn_name(path)
                 })
             },
@@ -54,7 +54,7 @@ pub fn fs_test(attr: TokenStream, item: TokenStream) -> TokenStream {
         (
             "rejects_existing_file",
             quote! {
-                crate::test_utils::assert_fn_rejects_existing_file(|path| {
+                ::fs_ext::testing::assert_fn_rejects_existing_file(|path| {
                     super::#fn_name(path)
                 })
             },
@@ -62,7 +62,7 @@ pub fn fs_test(attr: TokenStream, item: TokenStream) -> TokenStream {
         (
             "rejects_dir",
             quote! {
-                crate::test_utils::assert_fn_rejects_dir_path(|path| {
+                ::fs_ext::testing::assert_fn_rejects_dir_path(|path| {
                     super::#fn_name(path)
                 })
             },
@@ -70,7 +70,7 @@ pub fn fs_test(attr: TokenStream, item: TokenStream) -> TokenStream {
         (
             "rejects_existing_dir",
             quote! {
-                crate::test_utils::assert_fn_rejects_existing_dir(|path| {
+                ::fs_ext::testing::assert_fn_rejects_existing_dir(|path| {
                     super::#fn_name(path)
                 })
             },
@@ -78,7 +78,7 @@ pub fn fs_test(attr: TokenStream, item: TokenStream) -> TokenStream {
         (
             "existing_dir_ok",
             quote! {
-                crate::test_utils::existing_dir_ok(|path| {
+                ::fs_ext::testing::existing_dir_ok(|path| {
                     super::#fn_name(path)
                 })
             },
@@ -86,7 +86,7 @@ pub fn fs_test(attr: TokenStream, item: TokenStream) -> TokenStream {
         (
             "existing_file_ok",
             quote! {
-                crate::test_utils::existing_file_ok(|path| {
+                ::fs_ext::testing::existing_file_ok(|path| {
                     super::#fn_name(path)
                 })
             },
@@ -94,7 +94,7 @@ pub fn fs_test(attr: TokenStream, item: TokenStream) -> TokenStream {
         (
             "new_dir_ok",
             quote! {
-                crate::test_utils::new_dir_ok(|path| {
+                ::fs_ext::testing::new_dir_ok(|path| {
                     super::#fn_name(path)
                 })
             },
@@ -102,7 +102,7 @@ pub fn fs_test(attr: TokenStream, item: TokenStream) -> TokenStream {
         (
             "new_file_ok",
             quote! {
-                crate::test_utils::new_file_ok(|path| {
+                ::fs_ext::testing::new_file_ok(|path| {
                     super::#fn_name(path)
                 })
             },
@@ -133,8 +133,9 @@ pub fn fs_test(attr: TokenStream, item: TokenStream) -> TokenStream {
         // If no tests to generate, just return the original function
         quote! { #func }
     } else {
-        // Generate the test module with the requested tests
-        let mod_name = Ident::new("__macro_tests", proc_macro2::Span::call_site());
+        // Generate the test module with the requested tests; the name is
+        // per-function so several annotated fns can share a module.
+        let mod_name = Ident::new(&format!("__fs_test_{fn_name}"), proc_macro2::Span::call_site());
 
         quote! {
             #func